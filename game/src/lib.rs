@@ -0,0 +1,53 @@
+// `hot-reload` özelliğiyle derlenen uygulama bu kütüphaneyi çalışma anında
+// yükler. `cargo build -p game` ile yeniden derlendiğinde değişiklikler
+// pencere kapanmadan görünür; `GameState` düzeni değişirse STATE_VERSION artırılmalı.
+use game_api::{GameApi, GameCube, GameFrame, GameInput};
+use std::ffi::c_void;
+
+const STATE_VERSION: u32 = 1;
+
+struct GameState {
+    angle: f32,
+}
+
+extern "C" fn create() -> *mut c_void {
+    Box::into_raw(Box::new(GameState { angle: 0.0 })) as *mut c_void
+}
+
+extern "C" fn destroy(state: *mut c_void) {
+    // SAFETY: işaretçi `create` tarafından Box::into_raw ile üretildi
+    drop(unsafe { Box::from_raw(state as *mut GameState) });
+}
+
+fn state<'a>(state: *mut c_void) -> &'a mut GameState {
+    // SAFETY: host yalnızca aynı `state_version` ile oluşturulmuş işaretçiyi geçirir
+    unsafe { &mut *(state as *mut GameState) }
+}
+
+extern "C" fn update(game: *mut c_void, input: &GameInput) {
+    let game = state(game);
+    game.angle += input.dt * 0.8;
+}
+
+extern "C" fn render(game: *mut c_void, frame: &mut GameFrame) {
+    let game = state(game);
+    let count = 8;
+    for i in 0..count {
+        let angle = game.angle + i as f32 * std::f32::consts::TAU / count as f32;
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        frame.push_cube(GameCube {
+            position: [angle.cos() * 4.0, 1.5, angle.sin() * 4.0],
+            rotation: [0.0, sin, 0.0, cos],
+            color: [1.0, 0.55, 0.1, 1.0],
+        });
+    }
+}
+
+#[unsafe(no_mangle)]
+pub static GAME_API: GameApi = GameApi {
+    state_version: STATE_VERSION,
+    create,
+    destroy,
+    update,
+    render,
+};
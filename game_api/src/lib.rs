@@ -0,0 +1,52 @@
+// Ana uygulama ile yeniden yüklenebilen `game` kütüphanesi arasındaki C ABI.
+// Buradaki tipler değişirse iki taraf da yeniden derlenmelidir.
+use std::ffi::c_void;
+
+pub const API_SYMBOL: &[u8] = b"GAME_API\0";
+pub const MAX_CUBES: usize = 256;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GameInput {
+    pub dt: f32,
+    pub time: f32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GameCube {
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub color: [f32; 4],
+}
+
+// `render` çağrısından önce host tarafından doldurulur; kütüphane sadece
+// değiştirmek istediği alanlara dokunur.
+#[repr(C)]
+pub struct GameFrame {
+    pub clear_color: [f32; 4],
+    pub cube_count: u32,
+    pub cubes: [GameCube; MAX_CUBES],
+}
+
+impl GameFrame {
+    pub fn push_cube(&mut self, cube: GameCube) {
+        if (self.cube_count as usize) < MAX_CUBES {
+            self.cubes[self.cube_count as usize] = cube;
+            self.cube_count += 1;
+        }
+    }
+}
+
+// Durum (state) host tarafında opak bir işaretçi olarak tutulur. Yeni
+// kütüphanenin `state_version` değeri eskisiyle aynıysa işaretçi korunur,
+// değilse eski durum yok edilip yenisi oluşturulur.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct GameApi {
+    pub state_version: u32,
+    pub create: extern "C" fn() -> *mut c_void,
+    pub destroy: extern "C" fn(*mut c_void),
+    pub update: extern "C" fn(*mut c_void, &GameInput),
+    pub render: extern "C" fn(*mut c_void, &mut GameFrame),
+}
@@ -0,0 +1,141 @@
+use crate::AppEvent;
+use crate::ui::Ui;
+use accesskit::{Action, ActionData, ActionRequest, Node, NodeId, Rect, Role, Tree, TreeUpdate};
+use accesskit_winit::Adapter;
+use winit::event::WindowEvent;
+use winit::event_loop::{ActiveEventLoop, EventLoopProxy};
+use winit::window::Window;
+
+// Pencerenin kök düğümü; `ui` düğümleri indeks + 1 ile numaralanır
+const WINDOW: NodeId = NodeId(0);
+
+fn node_id(id: crate::ui::NodeId) -> NodeId {
+    NodeId(id.index() as u64 + 1)
+}
+
+// `Ui` ağacını AccessKit üzerinden işletim sisteminin erişilebilirlik
+// arayüzüne (AT-SPI, UIA, NSAccessibility) aynalar. Butonlar Button,
+// kaydırıcılar Slider, metinler Label olur; ekran okuyucunun istekleri
+// (tıklama, odak, değer) olay döngüsüne `AppEvent::AccessKit` olarak gelir.
+pub struct Accessibility {
+    adapter: Adapter,
+    title: String,
+    // Son gönderilen ağaç: arayüz yoksa `Some(None)`, hiç gönderilmediyse `None`
+    sent: Option<Option<u64>>,
+}
+
+impl Accessibility {
+    // Pencere henüz gösterilmemiş olmalı; AccessKit bunu şart koşar
+    pub fn new(event_loop: &ActiveEventLoop, window: &Window, proxy: EventLoopProxy<AppEvent>) -> Self {
+        Self {
+            adapter: Adapter::with_event_loop_proxy(event_loop, window, proxy),
+            title: window.title(),
+            sent: None,
+        }
+    }
+
+    pub fn process_event(&mut self, window: &Window, event: &WindowEvent) {
+        self.adapter.process_event(window, event);
+    }
+
+    // Ekran okuyucu yeni bağlandı; bir sonraki `update` tam ağacı gönderir
+    pub fn invalidate(&mut self) {
+        self.sent = None;
+    }
+
+    // Arayüz değiştiyse ağacı gönderir. Etkin bir ekran okuyucu yoksa
+    // AccessKit kapanışı çağırmaz ve hiçbir şey hesaplanmaz.
+    pub fn update(&mut self, ui: Option<&Ui>) {
+        let revision = ui.map(Ui::revision);
+        if self.sent == Some(revision) {
+            return;
+        }
+        let mut sent = false;
+        self.adapter.update_if_active(|| {
+            sent = true;
+            tree(&self.title, ui)
+        });
+        if sent {
+            self.sent = Some(revision);
+        }
+    }
+}
+
+fn tree(title: &str, ui: Option<&Ui>) -> TreeUpdate {
+    let mut window = Node::new(Role::Window);
+    window.set_label(title);
+    let mut nodes = Vec::new();
+    let mut focus = WINDOW;
+
+    if let Some(ui) = ui {
+        window.set_children(ui.roots().map(node_id).collect::<Vec<_>>());
+        focus = ui.focused().map_or(WINDOW, node_id);
+        let mut stack: Vec<_> = ui.roots().collect();
+        while let Some(id) = stack.pop() {
+            let source = ui.node(id);
+            let role = match (&source.slider, &source.command, &source.text) {
+                (Some(_), _, _) => Role::Slider,
+                (None, Some(_), _) => Role::Button,
+                (None, None, Some(_)) => Role::Label,
+                _ if source.background.is_some() => Role::Group,
+                _ => Role::GenericContainer,
+            };
+            let mut node = Node::new(role);
+            let [x, y, w, h] = source.rect().map(f64::from);
+            node.set_bounds(Rect::new(x, y, x + w, y + h));
+            match (&source.text, role) {
+                (Some((text, _)), Role::Label) => node.set_value(text.as_str()),
+                (Some((text, _)), _) => node.set_label(text.as_str()),
+                _ => {}
+            }
+            if let Some(slider) = &source.slider {
+                node.set_numeric_value(slider.value.into());
+                node.set_min_numeric_value(slider.min.into());
+                node.set_max_numeric_value(slider.max.into());
+                node.set_numeric_value_step(slider.step.into());
+                node.add_action(Action::Increment);
+                node.add_action(Action::Decrement);
+                node.add_action(Action::SetValue);
+            }
+            if source.command.is_some() {
+                node.add_action(Action::Focus);
+                node.add_action(Action::Click);
+            }
+            node.set_children(source.children().iter().copied().map(node_id).collect::<Vec<_>>());
+            stack.extend_from_slice(source.children());
+            nodes.push((node_id(id), node));
+        }
+    }
+
+    nodes.push((WINDOW, window));
+    TreeUpdate {
+        nodes,
+        tree: Some(Tree::new(WINDOW)),
+        focus,
+    }
+}
+
+// Ekran okuyucunun isteğini arayüze uygular; çalıştırılacak komut satırını döndürür
+pub fn perform(ui: &mut Ui, request: &ActionRequest) -> Option<String> {
+    let id = ui.id((request.target.0 as usize).checked_sub(1)?)?;
+    let slider = ui.node(id).slider;
+    match (request.action, slider) {
+        (Action::Focus, _) => {
+            ui.focus(Some(id));
+            None
+        }
+        (Action::Blur, _) => {
+            ui.focus(None);
+            None
+        }
+        (Action::Click, _) => ui.activate(id, None),
+        (Action::Increment, Some(slider)) => ui.set_value(id, slider.value + slider.step),
+        (Action::Decrement, Some(slider)) => ui.set_value(id, slider.value - slider.step),
+        (Action::SetValue, Some(_)) => match &request.data {
+            Some(ActionData::NumericValue(value)) => ui.set_value(id, *value as f32),
+            Some(ActionData::Value(text)) => ui.set_value(id, text.parse().ok()?),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,79 @@
+use winit::keyboard::KeyCode;
+
+// Klavye kısayollarının, menü çubuğunun ve bildirim alanı simgesinin tetiklediği uygulama eylemleri
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Action {
+    ToggleConsole,
+    #[cfg(feature = "debug-ui")]
+    ToggleDebugUi,
+    #[cfg(feature = "debug-ui")]
+    ToggleEventLog,
+    #[cfg(feature = "debug-ui")]
+    ToggleTargetViewer,
+    ToggleProfiler,
+    ToggleStats,
+    ToggleDebugDraw,
+    ToggleFullscreen,
+    Screenshot,
+    TogglePause,
+    // Pencereyi bildirim alanına gizler; geri getirmek için simge gerektiği için sadece oradan tetiklenir
+    #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+    ToggleWindow,
+    // Yerel dosya seçiciyle varlık açar; sadece menü çubuğundan tetiklenir
+    #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+    OpenFile,
+    // Eklentilerin kısayolları; konsol komutunu çalıştırır (ör. F7 → `inputs`)
+    Command(&'static str),
+    Quit,
+}
+
+pub struct KeyBindings {
+    bindings: Vec<(KeyCode, Action)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (KeyCode::Backquote, Action::ToggleConsole),
+                #[cfg(feature = "debug-ui")]
+                (KeyCode::F1, Action::ToggleDebugUi),
+                (KeyCode::F2, Action::ToggleProfiler),
+                (KeyCode::F3, Action::ToggleStats),
+                (KeyCode::F5, Action::ToggleDebugDraw),
+                #[cfg(feature = "debug-ui")]
+                (KeyCode::F4, Action::ToggleEventLog),
+                #[cfg(feature = "debug-ui")]
+                (KeyCode::F6, Action::ToggleTargetViewer),
+                (KeyCode::F11, Action::ToggleFullscreen),
+                (KeyCode::F12, Action::Screenshot),
+                (KeyCode::Pause, Action::TogglePause),
+                (KeyCode::Escape, Action::Quit),
+            ],
+        }
+    }
+}
+
+impl KeyBindings {
+    // Tuş zaten bağlıysa eski eylemin yerini alır
+    pub fn bind(&mut self, key: KeyCode, action: Action) {
+        self.bindings.retain(|(bound, _)| *bound != key);
+        self.bindings.push((key, action));
+    }
+
+    pub fn action(&self, key: KeyCode) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == key)
+            .map(|(_, action)| *action)
+    }
+
+    // Menüde kısayolu göstermek için eyleme bağlı ilk tuş
+    #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+    pub fn key(&self, action: Action) -> Option<KeyCode> {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| *bound == action)
+            .map(|(key, _)| *key)
+    }
+}
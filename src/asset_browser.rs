@@ -0,0 +1,109 @@
+use crate::assets::{AssetManager, AssetRef};
+
+const THUMBNAIL_SIZE: f32 = 64.0;
+
+pub enum AssetAction {
+    // Sahneye bırakılan varlık; konum fiziksel piksel cinsinden
+    Drop { asset: AssetRef, position: [f32; 2] },
+    Reload(AssetRef),
+    Unload(AssetRef),
+    ReloadShaders,
+}
+
+fn asset_buttons(ui: &mut egui::Ui, asset: AssetRef, has_path: bool, actions: &mut Vec<AssetAction>) {
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(has_path, egui::Button::new("⟳").small())
+            .on_hover_text("Diskten yeniden yükle")
+            .clicked()
+        {
+            actions.push(AssetAction::Reload(asset));
+        }
+        if ui
+            .add_enabled(has_path, egui::Button::new("✖").small())
+            .on_hover_text("Kaldır")
+            .clicked()
+        {
+            actions.push(AssetAction::Unload(asset));
+        }
+    });
+}
+
+pub fn show(context: &egui::Context, assets: &AssetManager, actions: &mut Vec<AssetAction>) {
+    egui::TopBottomPanel::bottom("asset_browser")
+        .resizable(true)
+        .default_height(170.0)
+        .show(context, |ui| {
+            ui.heading("Varlıklar");
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.label("Dokular");
+                ui.horizontal_wrapped(|ui| {
+                    for (handle, texture) in assets.textures() {
+                        let asset = AssetRef::Texture(handle);
+                        ui.vertical(|ui| {
+                            ui.set_width(THUMBNAIL_SIZE + 8.0);
+                            let id = egui::Id::new(("texture", handle));
+                            ui.dnd_drag_source(id, asset, |ui| match texture.thumbnail {
+                                Some(thumbnail) => {
+                                    ui.image(egui::load::SizedTexture::new(
+                                        thumbnail,
+                                        [THUMBNAIL_SIZE, THUMBNAIL_SIZE],
+                                    ));
+                                }
+                                None => {
+                                    ui.label("…");
+                                }
+                            })
+                            .response
+                            .on_hover_text(format!("{} ({}x{})", texture.name, texture.size[0], texture.size[1]));
+                            ui.add(egui::Label::new(&texture.name).truncate());
+                            asset_buttons(ui, asset, texture.path.is_some(), actions);
+                        });
+                    }
+                });
+
+                ui.separator();
+                ui.label("Mesh'ler");
+                ui.horizontal_wrapped(|ui| {
+                    for (handle, mesh) in assets.meshes() {
+                        let asset = AssetRef::Mesh(handle);
+                        ui.group(|ui| {
+                            let id = egui::Id::new(("mesh", handle));
+                            ui.dnd_drag_source(id, asset, |ui| {
+                                ui.label(format!("▣ {}", mesh.name));
+                            })
+                            .response
+                            .on_hover_text(format!("{} üçgen", mesh.index_count / 3));
+                            asset_buttons(ui, asset, mesh.path.is_some(), actions);
+                        });
+                    }
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label("Shader'lar");
+                    if ui.small_button("⟳ Hepsini yeniden yükle").clicked() {
+                        actions.push(AssetAction::ReloadShaders);
+                    }
+                });
+                for name in crate::shaders::names() {
+                    ui.label(name)
+                        .on_hover_text(crate::shaders::path(name).display().to_string());
+                }
+            });
+        });
+
+    // Herhangi bir panelin üzerinde olmayan bırakma işlemi sahneye yapılmıştır
+    let released = context.input(|input| input.pointer.any_released());
+    if released
+        && !context.is_pointer_over_area()
+        && let Some(asset) = egui::DragAndDrop::take_payload::<AssetRef>(context)
+        && let Some(position) = context.input(|input| input.pointer.interact_pos())
+    {
+        let scale = context.pixels_per_point();
+        actions.push(AssetAction::Drop {
+            asset: *asset,
+            position: [position.x * scale, position.y * scale],
+        });
+    }
+}
@@ -0,0 +1,598 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use wgpu::util::DeviceExt;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct MeshVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+impl MeshVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+        wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3, 2 => Float32x2];
+
+    pub fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<MeshVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MeshHandle(usize);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TextureHandle(usize);
+
+impl MeshHandle {
+    pub const CUBE: MeshHandle = MeshHandle(0);
+}
+
+impl TextureHandle {
+    pub const WHITE: TextureHandle = TextureHandle(0);
+    pub const PANEL: TextureHandle = TextureHandle(2);
+    pub const PANEL_LIGHT: TextureHandle = TextureHandle(3);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AssetRef {
+    Texture(TextureHandle),
+    Mesh(MeshHandle),
+}
+
+pub struct MeshAsset {
+    pub name: String,
+    // Dosyadan yüklenmediyse (yerleşik) None; yeniden yüklenemez/kaldırılamaz
+    pub path: Option<PathBuf>,
+    pub vertex_buffer: wgpu::Buffer,
+    pub index_buffer: wgpu::Buffer,
+    pub index_count: u32,
+    // Yerel uzay sınır kutusu (seçim için)
+    pub bounds: (glam::Vec3, glam::Vec3),
+}
+
+pub struct TextureAsset {
+    pub name: String,
+    pub path: Option<PathBuf>,
+    pub size: [u32; 2],
+    #[cfg(feature = "debug-ui")]
+    pub view: wgpu::TextureView,
+    pub bind_group: wgpu::BindGroup,
+    #[cfg(feature = "debug-ui")]
+    pub thumbnail: Option<egui::TextureId>,
+}
+
+pub fn cube_mesh() -> (Vec<MeshVertex>, Vec<u32>) {
+    // Her yüz için ayrı köşeler, böylece normaller keskin kalır
+    let faces: [([f32; 3], [f32; 3], [f32; 3]); 6] = [
+        ([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]),
+        ([-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]),
+        ([0.0, 1.0, 0.0], [0.0, 0.0, -1.0], [1.0, 0.0, 0.0]),
+        ([0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]),
+        ([0.0, 0.0, 1.0], [0.0, 1.0, 0.0], [1.0, 0.0, 0.0]),
+        ([0.0, 0.0, -1.0], [0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]),
+    ];
+
+    let mut vertices = Vec::with_capacity(24);
+    let mut indices = Vec::with_capacity(36);
+    for (normal, up, right) in faces {
+        let n = glam::Vec3::from(normal);
+        let u = glam::Vec3::from(up);
+        let r = glam::Vec3::from(right);
+        let base = vertices.len() as u32;
+        for (su, sr) in [(-1.0, -1.0), (-1.0, 1.0), (1.0, 1.0), (1.0, -1.0)] {
+            let position = (n + u * su + r * sr) * 0.5;
+            vertices.push(MeshVertex {
+                position: position.into(),
+                normal,
+                uv: [(sr + 1.0) * 0.5, (1.0 - su) * 0.5],
+            });
+        }
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+    (vertices, indices)
+}
+
+fn load_obj(path: &Path) -> Result<(Vec<MeshVertex>, Vec<u32>), Box<dyn Error>> {
+    let (models, _) = tobj::load_obj(path, &tobj::GPU_LOAD_OPTIONS)?;
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for model in models {
+        let mesh = model.mesh;
+        let base = vertices.len() as u32;
+        let count = mesh.positions.len() / 3;
+        for i in 0..count {
+            let position = [mesh.positions[i * 3], mesh.positions[i * 3 + 1], mesh.positions[i * 3 + 2]];
+            let normal = if mesh.normals.len() >= (i + 1) * 3 {
+                [mesh.normals[i * 3], mesh.normals[i * 3 + 1], mesh.normals[i * 3 + 2]]
+            } else {
+                [0.0; 3]
+            };
+            let uv = if mesh.texcoords.len() >= (i + 1) * 2 {
+                [mesh.texcoords[i * 2], 1.0 - mesh.texcoords[i * 2 + 1]]
+            } else {
+                [0.0; 2]
+            };
+            vertices.push(MeshVertex { position, normal, uv });
+        }
+        indices.extend(mesh.indices.iter().map(|index| base + index));
+
+        // Normal içermeyen dosyalar için yüz normallerinin ortalaması
+        if mesh.normals.is_empty() {
+            let vertices = &mut vertices[base as usize..];
+            for triangle in mesh.indices.chunks_exact(3) {
+                let [a, b, c] = [0, 1, 2].map(|k| glam::Vec3::from(vertices[triangle[k] as usize].position));
+                let normal = (b - a).cross(c - a);
+                for k in triangle {
+                    let n = &mut vertices[*k as usize].normal;
+                    *n = (glam::Vec3::from(*n) + normal).into();
+                }
+            }
+            for vertex in vertices.iter_mut() {
+                vertex.normal = glam::Vec3::from(vertex.normal).normalize_or_zero().into();
+            }
+        }
+    }
+
+    if indices.is_empty() {
+        return Err("OBJ dosyasında üçgen yok".into());
+    }
+    Ok((vertices, indices))
+}
+
+fn checker_pixels(size: u32, cell: u32) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let value = if (x / cell + y / cell).is_multiple_of(2) { 230 } else { 60 };
+            pixels.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+    pixels
+}
+
+// Dokuz parçalı UI paneli: yuvarlak köşeli, kenarlıklı yarı saydam dolgu (koyu
+// ve açık tema için birer tane). Köşe yarıçapı ve kenarlık `PANEL_INSET` içinde kalır.
+pub const PANEL_INSET: f32 = 6.0;
+
+// `fill` ve `line`: sRGB, 0-255 aralığında RGBA
+pub fn panel_pixels(size: u32, fill: [f32; 4], line: [f32; 4]) -> Vec<u8> {
+    let (radius, border) = (5.0, 1.5);
+    let half = size as f32 * 0.5;
+    let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            // Yuvarlak köşeli kutunun işaretli uzaklığı (piksel merkezinden)
+            let p = glam::Vec2::new(x as f32 + 0.5 - half, y as f32 + 0.5 - half).abs();
+            let q = p - glam::Vec2::splat(half - radius);
+            let distance = q.max(glam::Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius;
+            let coverage = (0.5 - distance).clamp(0.0, 1.0);
+            let edge = (distance + border + 0.5).clamp(0.0, 1.0);
+            let [r, g, b, a]: [f32; 4] = std::array::from_fn(|i| fill[i] + (line[i] - fill[i]) * edge);
+            pixels.extend_from_slice(&[r as u8, g as u8, b as u8, (a * coverage) as u8]);
+        }
+    }
+    pixels
+}
+
+// Dokuları ve mesh'leri tutar. Tutamaçlar (handle)
+// kaldırılan varlıklar için geçersiz kalır; sorgular bu durumda None döner.
+pub struct AssetManager {
+    meshes: Vec<Option<MeshAsset>>,
+    textures: Vec<Option<TextureAsset>>,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    root: PathBuf,
+}
+
+impl AssetManager {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Texture Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Texture Sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let mut assets = Self {
+            meshes: Vec::new(),
+            textures: Vec::new(),
+            texture_layout,
+            sampler,
+            root: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("assets"),
+        };
+
+        // Yerleşik varlıklar sabit tutamaçlara (CUBE, WHITE, PANEL, PANEL_LIGHT) karşılık gelir
+        let (vertices, indices) = cube_mesh();
+        assets.add_mesh(device, "küp", None, &vertices, &indices);
+        assets.add_texture(device, queue, "beyaz", None, [1, 1], &[255; 4]);
+        assets.add_texture(device, queue, "dama", None, [64, 64], &checker_pixels(64, 8));
+        let panel = panel_pixels(16, [10.0, 12.0, 18.0, 215.0], [150.0, 180.0, 240.0, 255.0]);
+        assets.add_texture(device, queue, "panel", None, [16, 16], &panel);
+        let panel_light = panel_pixels(16, [244.0, 245.0, 248.0, 230.0], [40.0, 100.0, 215.0, 255.0]);
+        assets.add_texture(device, queue, "panel_light", None, [16, 16], &panel_light);
+
+        assets.scan(device, queue);
+        assets
+    }
+
+    // `assets/` altındaki png/jpg ve obj dosyalarını yükler
+    fn scan(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        let Ok(entries) = walk(&self.root) else {
+            log::info!("Varlık klasörü bulunamadı: {}", self.root.display());
+            return;
+        };
+        for path in entries {
+            let extension = path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_lowercase());
+            let result = match extension.as_deref() {
+                Some("png" | "jpg" | "jpeg") => self.load_texture(device, queue, &path).map(|_| ()),
+                Some("obj") => self.load_mesh(device, &path).map(|_| ()),
+                _ => continue,
+            };
+            if let Err(e) = result {
+                log::warn!("{} yüklenemedi: {e}", path.display());
+            }
+        }
+    }
+
+    pub fn texture_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.texture_layout
+    }
+
+    fn create_mesh(
+        device: &wgpu::Device,
+        name: &str,
+        path: Option<PathBuf>,
+        vertices: &[MeshVertex],
+        indices: &[u32],
+        vertex_usage: wgpu::BufferUsages,
+    ) -> MeshAsset {
+        MeshAsset {
+            name: name.to_string(),
+            path,
+            vertex_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(name),
+                contents: bytemuck::cast_slice(vertices),
+                usage: vertex_usage,
+            }),
+            index_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some(name),
+                contents: bytemuck::cast_slice(indices),
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            }),
+            index_count: indices.len() as u32,
+            bounds: mesh_bounds(vertices),
+        }
+    }
+
+    pub fn add_mesh(
+        &mut self,
+        device: &wgpu::Device,
+        name: &str,
+        path: Option<PathBuf>,
+        vertices: &[MeshVertex],
+        indices: &[u32],
+    ) -> MeshHandle {
+        let usage = wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST;
+        let asset = Self::create_mesh(device, name, path, vertices, indices, usage);
+        self.meshes.push(Some(asset));
+        MeshHandle(self.meshes.len() - 1)
+    }
+
+    // Köşeleri GPU'da bir compute pass'in yazdığı mesh (ör. skinning). Vertex
+    // buffer storage olarak da bağlanabilir; başlangıç içeriği `vertices`.
+    pub fn add_storage_mesh(
+        &mut self,
+        device: &wgpu::Device,
+        name: &str,
+        vertices: &[MeshVertex],
+        indices: &[u32],
+    ) -> MeshHandle {
+        let usage = wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST;
+        let asset = Self::create_mesh(device, name, None, vertices, indices, usage);
+        self.meshes.push(Some(asset));
+        MeshHandle(self.meshes.len() - 1)
+    }
+
+    // Her karede değişen geometri için (prosedürel mesh'ler). Tutamaç aynı
+    // kalır; buffer'lar sadece yeni veri sığmadığında büyütülür.
+    pub fn update_mesh(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        handle: MeshHandle,
+        vertices: &[MeshVertex],
+        indices: &[u32],
+    ) -> Result<(), String> {
+        let mesh = self
+            .meshes
+            .get_mut(handle.0)
+            .and_then(Option::as_mut)
+            .ok_or("mesh bulunamadı")?;
+        let upload = |buffer: &mut wgpu::Buffer, contents: &[u8]| {
+            if contents.len() as u64 > buffer.size() {
+                *buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some(&mesh.name),
+                    size: (contents.len() as u64).next_power_of_two(),
+                    usage: buffer.usage(),
+                    mapped_at_creation: false,
+                });
+            }
+            if !contents.is_empty() {
+                queue.write_buffer(buffer, 0, contents);
+            }
+        };
+        upload(&mut mesh.vertex_buffer, bytemuck::cast_slice(vertices));
+        upload(&mut mesh.index_buffer, bytemuck::cast_slice(indices));
+        mesh.index_count = indices.len() as u32;
+        mesh.bounds = mesh_bounds(vertices);
+        Ok(())
+    }
+
+    fn create_texture(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        name: &str,
+        path: Option<PathBuf>,
+        size: [u32; 2],
+        rgba: &[u8],
+    ) -> TextureAsset {
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some(name),
+                size: wgpu::Extent3d {
+                    width: size[0],
+                    height: size[1],
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            rgba,
+        );
+        self.bind_texture(device, name, path, size, &texture)
+    }
+
+    fn bind_texture(
+        &self,
+        device: &wgpu::Device,
+        name: &str,
+        path: Option<PathBuf>,
+        size: [u32; 2],
+        texture: &wgpu::Texture,
+    ) -> TextureAsset {
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(name),
+            layout: &self.texture_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&self.sampler),
+                },
+            ],
+        });
+
+        TextureAsset {
+            name: name.to_string(),
+            path,
+            size,
+            #[cfg(feature = "debug-ui")]
+            view,
+            bind_group,
+            #[cfg(feature = "debug-ui")]
+            thumbnail: None,
+        }
+    }
+
+    fn add_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        name: &str,
+        path: Option<PathBuf>,
+        size: [u32; 2],
+        rgba: &[u8],
+    ) -> TextureHandle {
+        let asset = self.create_texture(device, queue, name, path, size, rgba);
+        self.textures.push(Some(asset));
+        TextureHandle(self.textures.len() - 1)
+    }
+
+    // GPU'da üretilen dokuyu (ör. pişirilmiş gürültü) kaydeder. Aynı adlı
+    // üretilmiş doku varsa tutamacı korunarak yerine konur; dosyadan yüklenmiş
+    // dokulara dokunulmaz. Doku TEXTURE_BINDING ile oluşturulmuş olmalı.
+    pub fn set_generated_texture(&mut self, device: &wgpu::Device, name: &str, texture: &wgpu::Texture) -> TextureHandle {
+        let size = [texture.width(), texture.height()];
+        let asset = self.bind_texture(device, name, None, size, texture);
+        match self.generated_texture(name) {
+            Some(handle) => {
+                self.textures[handle.0] = Some(asset);
+                handle
+            }
+            None => {
+                self.textures.push(Some(asset));
+                TextureHandle(self.textures.len() - 1)
+            }
+        }
+    }
+
+    pub fn generated_texture(&self, name: &str) -> Option<TextureHandle> {
+        self.textures()
+            .find(|(handle, texture)| *handle != TextureHandle::WHITE && texture.path.is_none() && texture.name == name)
+            .map(|(handle, _)| handle)
+    }
+
+    fn file_name(path: &Path) -> String {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string())
+    }
+
+    pub fn load_texture(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        path: &Path,
+    ) -> Result<TextureHandle, Box<dyn Error>> {
+        let image = image::open(path)?.to_rgba8();
+        let size = [image.width(), image.height()];
+        let name = Self::file_name(path);
+        Ok(self.add_texture(device, queue, &name, Some(path.to_path_buf()), size, &image))
+    }
+
+    pub fn load_mesh(&mut self, device: &wgpu::Device, path: &Path) -> Result<MeshHandle, Box<dyn Error>> {
+        let (vertices, indices) = load_obj(path)?;
+        let name = Self::file_name(path);
+        Ok(self.add_mesh(device, &name, Some(path.to_path_buf()), &vertices, &indices))
+    }
+
+    // Dosyayı yeniden okur; tutamaç aynı kalır, böylece sahnedeki referanslar geçerli kalır
+    pub fn reload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, asset: AssetRef) -> Result<(), Box<dyn Error>> {
+        match asset {
+            AssetRef::Texture(handle) => {
+                let current = self.texture(handle).ok_or("doku bulunamadı")?;
+                let path = current.path.clone().ok_or("yerleşik doku yeniden yüklenemez")?;
+                let image = image::open(&path)?.to_rgba8();
+                let reloaded = self.create_texture(
+                    device,
+                    queue,
+                    &Self::file_name(&path),
+                    Some(path),
+                    [image.width(), image.height()],
+                    &image,
+                );
+                self.textures[handle.0] = Some(reloaded);
+            }
+            AssetRef::Mesh(handle) => {
+                let current = self.mesh(handle).ok_or("mesh bulunamadı")?;
+                let path = current.path.clone().ok_or("yerleşik mesh yeniden yüklenemez")?;
+                let (vertices, indices) = load_obj(&path)?;
+                let usage = wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST;
+                let reloaded =
+                    Self::create_mesh(device, &Self::file_name(&path), Some(path), &vertices, &indices, usage);
+                self.meshes[handle.0] = Some(reloaded);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "debug-ui")]
+    pub fn unload(&mut self, asset: AssetRef) -> Result<(), String> {
+        // Yerleşik dokular ilk dört tutamaçtadır (WHITE, dama, PANEL, PANEL_LIGHT)
+        let builtin = match asset {
+            AssetRef::Texture(handle) => handle <= TextureHandle::PANEL_LIGHT,
+            AssetRef::Mesh(handle) => handle == MeshHandle::CUBE,
+        };
+        if builtin {
+            return Err("varsayılan varlıklar kaldırılamaz".into());
+        }
+        let removed = match asset {
+            AssetRef::Texture(handle) => self.textures.get_mut(handle.0).and_then(|slot| slot.take().map(|_| ())),
+            AssetRef::Mesh(handle) => self.meshes.get_mut(handle.0).and_then(|slot| slot.take().map(|_| ())),
+        };
+        removed.ok_or_else(|| "varlık bulunamadı".to_string())
+    }
+
+    pub fn mesh(&self, handle: MeshHandle) -> Option<&MeshAsset> {
+        self.meshes.get(handle.0).and_then(Option::as_ref)
+    }
+
+    pub fn texture(&self, handle: TextureHandle) -> Option<&TextureAsset> {
+        self.textures.get(handle.0).and_then(Option::as_ref)
+    }
+
+    // Kaldırılmış dokular beyaz dokuya düşer
+    pub fn texture_or_white(&self, handle: Option<TextureHandle>) -> &TextureAsset {
+        handle
+            .and_then(|handle| self.texture(handle))
+            .or_else(|| self.texture(TextureHandle::WHITE))
+            .expect("beyaz doku her zaman yüklü")
+    }
+
+    pub fn meshes(&self) -> impl Iterator<Item = (MeshHandle, &MeshAsset)> {
+        self.meshes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, mesh)| mesh.as_ref().map(|mesh| (MeshHandle(index), mesh)))
+    }
+
+    pub fn textures(&self) -> impl Iterator<Item = (TextureHandle, &TextureAsset)> {
+        self.textures
+            .iter()
+            .enumerate()
+            .filter_map(|(index, texture)| texture.as_ref().map(|texture| (TextureHandle(index), texture)))
+    }
+
+    #[cfg(feature = "debug-ui")]
+    pub fn textures_mut(&mut self) -> impl Iterator<Item = &mut TextureAsset> {
+        self.textures.iter_mut().flatten()
+    }
+}
+
+// Yerel uzay sınır kutusu
+fn mesh_bounds(vertices: &[MeshVertex]) -> (glam::Vec3, glam::Vec3) {
+    vertices.iter().fold(
+        (glam::Vec3::splat(f32::MAX), glam::Vec3::splat(f32::MIN)),
+        |(min, max), vertex| {
+            let position = glam::Vec3::from(vertex.position);
+            (min.min(position), max.max(position))
+        },
+    )
+}
+
+fn walk(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(walk(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -0,0 +1,112 @@
+use crate::actions::Action;
+use crate::console::Command;
+use crate::layer::Layer;
+use crate::plugin::Plugin;
+use crate::settings::Settings;
+use crate::{App, AppEvent};
+use std::error::Error;
+use winit::dpi::LogicalSize;
+use winit::event_loop::{ControlFlow, EventLoop};
+use winit::keyboard::KeyCode;
+use winit::window::WindowAttributes;
+
+// Şablonu `resumed` ve `State::new` içine dokunmadan kodda yapılandırır.
+// Komut satırı argümanları burada seçilen ayarların üstüne yazılır.
+pub struct AppBuilder {
+    window: WindowAttributes,
+    settings: Settings,
+    layers: Vec<Box<dyn Layer>>,
+    plugins: Vec<Box<dyn Plugin>>,
+    // Eklentilerin konsol komutları; yerleşik komutlardan sonra kaydedilir
+    commands: Vec<(&'static str, Command)>,
+    // Varsayılan kısayolların üstüne yazılır
+    bindings: Vec<(KeyCode, Action)>,
+}
+
+impl Default for AppBuilder {
+    fn default() -> Self {
+        Self {
+            window: WindowAttributes::default().with_title("winit & wgpu"),
+            settings: Settings::default(),
+            layers: Vec::new(),
+            plugins: Vec::new(),
+            commands: Vec::new(),
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl AppBuilder {
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.window.title = title.into();
+        self
+    }
+
+    // Mantıksal piksel; ölçek faktörü pencere açılırken uygulanır
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.window.inner_size = Some(LogicalSize::new(width, height).into());
+        self
+    }
+
+    pub fn vsync(mut self, enabled: bool) -> Self {
+        self.settings.vsync = Some(enabled);
+        self
+    }
+
+    pub fn with_layer(mut self, layer: impl Layer + 'static) -> Self {
+        self.add_layer(layer);
+        self
+    }
+
+    pub fn with_plugin(mut self, plugin: impl Plugin) -> Self {
+        self.add_plugin(plugin);
+        self
+    }
+
+    // `Plugin::build` içinden çağrılabilen biçimleri
+    pub fn add_layer(&mut self, layer: impl Layer + 'static) {
+        self.layers.push(Box::new(layer));
+    }
+
+    pub fn add_plugin(&mut self, mut plugin: impl Plugin) {
+        plugin.build(self);
+        self.plugins.push(Box::new(plugin));
+    }
+
+    pub fn add_command(&mut self, name: &'static str, command: Command) {
+        self.commands.push((name, command));
+    }
+
+    pub fn add_binding(&mut self, key: KeyCode, action: Action) {
+        self.bindings.push((key, action));
+    }
+
+    pub fn run(self) -> Result<(), Box<dyn Error>> {
+        let settings = self.settings.with_args(std::env::args().skip(1))?;
+
+        log::info!("Olay döngüsü oluşturuluyor...");
+        let mut event_loop = EventLoop::<AppEvent>::with_user_event();
+        // muda'nın menü kısayolları Windows'ta mesajlar dağıtılmadan önce çevrilmeli
+        #[cfg(all(feature = "menu-bar", windows))]
+        {
+            use winit::platform::windows::EventLoopBuilderExtWindows;
+            event_loop.with_msg_hook(crate::menu::translate_accelerator);
+        }
+        let event_loop = event_loop.build()?;
+        event_loop.set_control_flow(ControlFlow::Poll);
+
+        let mut app = App::new(
+            event_loop.create_proxy(),
+            self.window,
+            settings,
+            self.layers,
+            self.plugins,
+            self.commands,
+            self.bindings,
+        );
+        event_loop.run_app(&mut app)?;
+
+        log::info!("Olay döngüsü tamamlandı.");
+        Ok(())
+    }
+}
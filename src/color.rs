@@ -0,0 +1,134 @@
+use crate::assets::TextureHandle;
+
+// sRGB kodlu, ön çarpılmamış alfalı renk. Renkler tasarlandıkları (sRGB)
+// uzayda saklanır; GPU'ya giderken hedef formata göre doğrusala çevrilir.
+// sRGB yüzeyler yazılan doğrusal değeri donanımda kodlar, bu yüzden kodlanmış
+// değeri doğrudan vermek renkleri soluk gösterir.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Color {
+    r: f32,
+    g: f32,
+    b: f32,
+    a: f32,
+}
+
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl Color {
+    pub const BLACK: Color = Color::srgb(0.0, 0.0, 0.0);
+    pub const WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
+
+    pub const fn srgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn srgb(r: f32, g: f32, b: f32) -> Self {
+        Self::srgba(r, g, b, 1.0)
+    }
+
+    pub fn from_linear([r, g, b, a]: [f32; 4]) -> Self {
+        Self::srgba(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), a)
+    }
+
+    pub fn to_srgb(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    pub fn to_linear(self) -> [f32; 4] {
+        [srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a]
+    }
+
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self::srgba(self.r, self.g, self.b, a)
+    }
+
+    // Karışım doğrusal uzayda yapılır
+    pub fn lerp(self, other: Color, t: f32) -> Self {
+        let (from, to) = (self.to_linear(), other.to_linear());
+        Self::from_linear(std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t))
+    }
+
+    // Shader çıktısı veya clear değeri olarak verilecek bileşenler. sRGB ve
+    // float hedefler doğrusal değer bekler, diğer unorm hedefler kodlanmış değer.
+    pub fn for_target(self, format: wgpu::TextureFormat) -> [f32; 4] {
+        let linear = matches!(
+            format,
+            wgpu::TextureFormat::Rgba16Float | wgpu::TextureFormat::Rgba32Float
+        );
+        if linear || format.is_srgb() {
+            self.to_linear()
+        } else {
+            self.to_srgb()
+        }
+    }
+
+    pub fn to_wgpu(self, format: wgpu::TextureFormat) -> wgpu::Color {
+        let [r, g, b, a] = self.for_target(format).map(f64::from);
+        wgpu::Color { r, g, b, a }
+    }
+}
+
+// Overlay panellerinin ortak renkleri; koyu ve açık tema için birer takım.
+// Geçerli takım `Overlay::palette` üzerinden okunur.
+#[derive(Debug)]
+pub struct Palette {
+    pub panel: Color,
+    pub accent: Color,
+    pub text: Color,
+    pub text_dim: Color,
+    pub input: Color,
+    pub output: Color,
+    pub error: Color,
+    pub good: Color,
+    pub warn: Color,
+    pub bad: Color,
+    // `ui` panelleri için dokuz parçalı doku
+    pub panel_texture: TextureHandle,
+}
+
+impl Palette {
+    pub const DARK: Palette = Palette {
+        panel: Color::srgba(0.02, 0.02, 0.03, 0.85),
+        accent: Color::srgb(0.4, 0.6, 1.0),
+        text: Color::WHITE,
+        text_dim: Color::srgb(0.7, 0.7, 0.7),
+        input: Color::srgb(0.6, 0.8, 1.0),
+        output: Color::srgb(0.85, 0.85, 0.85),
+        error: Color::srgb(1.0, 0.4, 0.35),
+        good: Color::srgba(0.3, 0.85, 0.4, 0.9),
+        warn: Color::srgba(0.95, 0.8, 0.2, 0.9),
+        bad: Color::srgba(1.0, 0.35, 0.3, 0.9),
+        panel_texture: TextureHandle::PANEL,
+    };
+
+    pub const LIGHT: Palette = Palette {
+        panel: Color::srgba(0.96, 0.96, 0.97, 0.9),
+        accent: Color::srgb(0.15, 0.4, 0.85),
+        text: Color::srgb(0.08, 0.08, 0.1),
+        text_dim: Color::srgb(0.38, 0.38, 0.42),
+        input: Color::srgb(0.1, 0.35, 0.75),
+        output: Color::srgb(0.2, 0.2, 0.22),
+        error: Color::srgb(0.8, 0.15, 0.1),
+        good: Color::srgba(0.1, 0.6, 0.2, 0.9),
+        warn: Color::srgba(0.8, 0.55, 0.0, 0.9),
+        bad: Color::srgba(0.85, 0.2, 0.15, 0.9),
+        panel_texture: TextureHandle::PANEL_LIGHT,
+    };
+}
+
+// Sahnedeki seçim vurgusu; temadan bağımsızdır
+pub const SELECTION: Color = Color::srgb(1.0, 0.9, 0.3);
@@ -0,0 +1,827 @@
+use crate::State;
+use crate::assets::{AssetRef, PANEL_INSET, TextureHandle};
+use crate::console::{Command, CommandRegistry};
+use crate::event_log::EventKind;
+use crate::noise_bake::NoiseParams;
+use crate::point_cloud;
+use crate::scene::{EntityId, Scene};
+use crate::sprites::NineSlice;
+use crate::ui::DemoUi;
+use crate::voxels::{Block, Voxels};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+pub fn register_builtins(registry: &mut CommandRegistry) {
+    registry.register(
+        "help",
+        Command {
+            help: "Komutları listeler",
+            args: &[],
+            run: help,
+        },
+    );
+    registry.register(
+        "clear",
+        Command {
+            help: "Konsol çıktısını temizler",
+            args: &[],
+            run: clear,
+        },
+    );
+    registry.register(
+        "set_vsync",
+        Command {
+            help: "set_vsync on|off - dikey senkronizasyonu açar/kapatır",
+            args: &["on", "off"],
+            run: set_vsync,
+        },
+    );
+    registry.register(
+        "redraw",
+        Command {
+            help: "redraw continuous|ondemand|fixed <hz> - kare çizim politikası",
+            args: &["continuous", "ondemand", "fixed"],
+            run: redraw,
+        },
+    );
+    registry.register(
+        "pacing",
+        Command {
+            help: "pacing [off|auto|fixed|vrr] - kare zamanlaması ve ölçülen yenileme hızı",
+            args: &["off", "auto", "fixed", "vrr"],
+            run: pacing,
+        },
+    );
+    registry.register(
+        "latency",
+        Command {
+            help: "latency [on|off|reset] - tıklamadan ekrana gecikme testi",
+            args: &["on", "off", "reset"],
+            run: latency,
+        },
+    );
+    registry.register(
+        "tonemap",
+        Command {
+            help: "tonemap [exposure|paper_white|max_white <değer>] - ton eşleme ayarları",
+            args: &["exposure", "paper_white", "max_white"],
+            run: tonemap,
+        },
+    );
+    registry.register(
+        "cursor",
+        Command {
+            help: "İmlecin fiziksel, mantıksal ve normalize konumunu yazar",
+            args: &[],
+            run: cursor,
+        },
+    );
+    registry.register(
+        "spawn",
+        Command {
+            help: "spawn cube|tentacle|blob|blob_gpu - sahneye yeni bir nesne ekler (tentacle: GPU skinning, blob: marching cubes)",
+            args: &["cube", "tentacle", "blob", "blob_gpu"],
+            run: spawn,
+        },
+    );
+    registry.register(
+        "entities",
+        Command {
+            help: "Sahne hiyerarşisini listeler",
+            args: &[],
+            run: entities,
+        },
+    );
+    registry.register(
+        "delete",
+        Command {
+            help: "Seçili varlığı çocuklarıyla birlikte siler",
+            args: &[],
+            run: delete,
+        },
+    );
+    registry.register(
+        "assets",
+        Command {
+            help: "Yüklü doku ve mesh'leri listeler",
+            args: &[],
+            run: assets,
+        },
+    );
+    registry.register(
+        "reload_assets",
+        Command {
+            help: "Dosyadan yüklenen tüm varlıkları yeniden okur",
+            args: &[],
+            run: reload_assets,
+        },
+    );
+    registry.register(
+        "open",
+        Command {
+            help: "open <dosya> - OBJ mesh ekler, PNG/JPEG dokuyu seçili nesneye atar, PLY/LAS nokta bulutu yükler",
+            args: &[],
+            run: open,
+        },
+    );
+    registry.register(
+        "profiler",
+        Command {
+            help: "profiler on|off - kare zaman çizelgesini gösterir (F2)",
+            args: &["on", "off"],
+            run: profiler,
+        },
+    );
+    registry.register(
+        "stats",
+        Command {
+            help: "stats on|off - çizim ve GPU nesne istatistiklerini gösterir (F3)",
+            args: &["on", "off"],
+            run: stats,
+        },
+    );
+    registry.register(
+        "events",
+        Command {
+            help: "events [clear|pencere|girdi|eylem|uygulama] [filtre] - son olayları yazar",
+            args: &["clear", "pencere", "girdi", "eylem", "uygulama"],
+            run: events,
+        },
+    );
+    registry.register(
+        "screenshot",
+        Command {
+            help: "Bir sonraki kareyi PNG olarak kaydeder",
+            args: &[],
+            run: screenshot,
+        },
+    );
+    registry.register(
+        "pause",
+        Command {
+            help: "pause on|off - oyunu duraklatır, kareler sadece değişiklik olunca çizilir (Pause tuşu)",
+            args: &["on", "off"],
+            run: pause,
+        },
+    );
+    registry.register(
+        "capture_frame",
+        Command {
+            help: "Bir sonraki karenin pass/pipeline/çizim dökümünü captures/ altına yazar",
+            args: &[],
+            run: capture_frame,
+        },
+    );
+    registry.register(
+        "sort_test",
+        Command {
+            help: "sort_test [adet] - GPU sıralamasını CPU referansıyla karşılaştırır",
+            args: &[],
+            run: sort_test,
+        },
+    );
+    registry.register(
+        "noise",
+        Command {
+            help: "noise perlin|simplex|worley [ölçek] [tohum] - gürültüyü GPU'da dokuya pişirir, seçili nesneye atar",
+            args: &["perlin", "simplex", "worley"],
+            run: noise,
+        },
+    );
+    registry.register(
+        "voxels",
+        Command {
+            help: "voxels generate [yarıçap] [tohum] | clear | dig [yarıçap] | set x y z blok - parçalı voksel arazisi",
+            args: &["generate", "clear", "dig", "set"],
+            run: voxels,
+        },
+    );
+    registry.register(
+        "points",
+        Command {
+            help: "points load <dosya.ply|las> | demo [sayı] [tohum] | mode quads|points | size <px> | budget <n> | clear - nokta bulutu",
+            args: &["load", "demo", "mode", "size", "budget", "clear"],
+            run: points,
+        },
+    );
+    registry.register(
+        "report",
+        Command {
+            help: "report - crashes/ altına log, adaptör, ayarlar, kare istatistikleri ve ekran görüntüsüyle rapor yazar",
+            args: &[],
+            run: report,
+        },
+    );
+    registry.register(
+        "seed",
+        Command {
+            help: "seed [sayı] - rastgele üretecin tohumunu gösterir ya da üreteci yeniden tohumlar",
+            args: &[],
+            run: seed,
+        },
+    );
+    registry.register(
+        "lines",
+        Command {
+            help: "lines on|off | width <px> | join miter|round | cap butt|square|round - hata ayıklama çizgileri (F5)",
+            args: &["on", "off", "width", "join", "cap"],
+            run: lines,
+        },
+    );
+    registry.register(
+        "ninepatch",
+        Command {
+            help: "ninepatch [kenar payı] [doku] | off - dokudan dokuz parçalı örnek paneller",
+            args: &["off"],
+            run: ninepatch,
+        },
+    );
+    registry.register(
+        "ui",
+        Command {
+            help: "ui on|off | menu <konum> - yerleşim sistemiyle örnek HUD ve menü",
+            args: &["on", "off", "menu"],
+            run: ui,
+        },
+    );
+    registry.register(
+        "theme",
+        Command {
+            help: "theme [system|dark|light] - arayüz teması; system pencerenin temasını izler",
+            args: &["system", "dark", "light"],
+            run: theme,
+        },
+    );
+    registry.register(
+        "reload_shaders",
+        Command {
+            help: "WGSL dosyalarını diskten yeniden yükler",
+            args: &[],
+            run: reload_shaders,
+        },
+    );
+    #[cfg(feature = "debug-ui")]
+    registry.register(
+        "targets",
+        Command {
+            help: "targets on|off - ara render hedeflerini (HDR renk, derinlik) gösterir (F6)",
+            args: &["on", "off"],
+            run: targets,
+        },
+    );
+    #[cfg(feature = "hot-reload")]
+    registry.register(
+        "reload_game",
+        Command {
+            help: "Oyun kütüphanesini diskten yeniden yükler",
+            args: &[],
+            run: reload_game,
+        },
+    );
+}
+
+fn help(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let lines: Vec<String> = state
+        .commands
+        .iter()
+        .map(|(name, command)| format!("{name:<16} {}", command.help))
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn clear(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    state.console.clear();
+    Ok(String::new())
+}
+
+fn set_vsync(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let enabled = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        _ => return Err("kullanım: set_vsync on|off".into()),
+    };
+    let mode = state.set_vsync(enabled);
+    Ok(format!("present mode: {mode:?}"))
+}
+
+fn redraw(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return Ok(format!("çizim politikası: {}", state.settings.redraw_policy));
+    }
+    state.settings.redraw_policy = args.join(" ").parse()?;
+    Ok(format!("çizim politikası: {}", state.settings.redraw_policy))
+}
+
+fn pacing(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if let Some(mode) = args.first() {
+        state.settings.pacing = mode.parse()?;
+    }
+    Ok(state.frame_pacer.summary(state.settings.pacing))
+}
+
+fn latency(state: &mut State, args: &[&str]) -> Result<String, String> {
+    match args.first() {
+        Some(&"on") => state.latency.enabled = true,
+        Some(&"off") => state.latency.enabled = false,
+        Some(&"reset") => state.latency.reset(),
+        Some(other) => return Err(format!("bilinmeyen seçenek: {other}")),
+        None => {}
+    }
+    let status = if state.latency.enabled { "açık" } else { "kapalı" };
+    Ok(format!("gecikme testi {status}\n{}", state.latency.summary()))
+}
+
+fn tonemap(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let tonemapper = &mut state.tonemapper;
+    if let [name, value] = args {
+        let value: f32 = value
+            .parse()
+            .ok()
+            .filter(|value: &f32| *value > 0.0 && value.is_finite())
+            .ok_or_else(|| format!("geçersiz değer: {value}"))?;
+        match *name {
+            "exposure" => tonemapper.exposure = value,
+            "paper_white" => tonemapper.paper_white = value,
+            "max_white" => tonemapper.max_white = value,
+            other => return Err(format!("bilinmeyen ayar: {other}")),
+        }
+    } else if !args.is_empty() {
+        return Err("kullanım: tonemap [exposure|paper_white|max_white <değer>]".into());
+    }
+    let mut text = format!("çıktı: {:?}  pozlama: {}", tonemapper.output, tonemapper.exposure);
+    if tonemapper.output.is_hdr() {
+        text += &format!("  kağıt beyazı: {} nit  tepe: {} nit", tonemapper.paper_white, tonemapper.max_white);
+    }
+    Ok(text)
+}
+
+fn cursor(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let input = &state.input_state;
+    let physical = input.cursor_physical().ok_or("imleç pencerenin dışında")?;
+    let logical = input.cursor_logical().ok_or("imleç pencerenin dışında")?;
+    let [x, y] = input.normalize(physical);
+    Ok(format!(
+        "fiziksel ({:.1}, {:.1})  mantıksal ({:.1}, {:.1})  normalize ({x:.3}, {y:.3})  ölçek {}",
+        physical.x,
+        physical.y,
+        logical.x,
+        logical.y,
+        input.scale_factor()
+    ))
+}
+
+fn spawn(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let id = match args.first() {
+        Some(&"cube") => state.scene.spawn_cube(),
+        Some(&"tentacle") => {
+            let mesh = state.skinning.tentacle(&state.device, &mut state.assets);
+            state.scene.spawn_on_spiral("Dokunaç", mesh)
+        }
+        Some(&"blob") => {
+            let mesh = state.marching_cubes.cpu_blob(&state.device, &mut state.assets);
+            state.scene.spawn_on_spiral("Kabarcık", mesh)
+        }
+        Some(&"blob_gpu") => {
+            let mesh = state.marching_cubes.gpu_blob(&state.device, &mut state.assets);
+            state.scene.spawn_on_spiral("Kabarcık (GPU)", mesh)
+        }
+        Some(other) => return Err(format!("bilinmeyen nesne: {other}")),
+        None => return Err("kullanım: spawn cube|tentacle|blob|blob_gpu".into()),
+    };
+    let entity = state.scene.get(id).ok_or("nesne oluşturulamadı")?;
+    Ok(format!("{} eklendi: {:.2}", entity.name, entity.transform.translation))
+}
+
+fn entities(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    fn visit(scene: &Scene, id: EntityId, depth: usize, lines: &mut Vec<String>) {
+        let Some(entity) = scene.get(id) else { return };
+        let marker = if scene.selected == Some(id) { "*" } else { " " };
+        lines.push(format!("{marker}{}{}", "  ".repeat(depth), entity.name));
+        for child in &entity.children {
+            visit(scene, *child, depth + 1, lines);
+        }
+    }
+
+    let mut lines = Vec::new();
+    for root in state.scene.roots() {
+        visit(&state.scene, root, 0, &mut lines);
+    }
+    Ok(lines.join("\n"))
+}
+
+fn delete(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let id = state.scene.selected.ok_or("seçili varlık yok")?;
+    let name = state.scene.get(id).map(|entity| entity.name.clone()).unwrap_or_default();
+    state.scene.despawn(id);
+    Ok(format!("silindi: {name}"))
+}
+
+fn assets(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let source = |path: Option<&std::path::PathBuf>| {
+        path.map_or("yerleşik".to_string(), |path| path.display().to_string())
+    };
+    let mut lines = Vec::new();
+    for (_, texture) in state.assets.textures() {
+        let [width, height] = texture.size;
+        lines.push(format!("doku {:<20} {width}x{height} {}", texture.name, source(texture.path.as_ref())));
+    }
+    for (_, mesh) in state.assets.meshes() {
+        lines.push(format!(
+            "mesh {:<20} {} üçgen {}",
+            mesh.name,
+            mesh.index_count / 3,
+            source(mesh.path.as_ref())
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn reload_assets(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let textures = state
+        .assets
+        .textures()
+        .filter(|(_, texture)| texture.path.is_some())
+        .map(|(handle, _)| AssetRef::Texture(handle));
+    let meshes = state
+        .assets
+        .meshes()
+        .filter(|(_, mesh)| mesh.path.is_some())
+        .map(|(handle, _)| AssetRef::Mesh(handle));
+    let refs: Vec<AssetRef> = textures.chain(meshes).collect();
+
+    let mut errors = Vec::new();
+    for asset in &refs {
+        if let Err(e) = state.reload_asset(*asset) {
+            errors.push(e);
+        }
+    }
+    if errors.is_empty() {
+        Ok(format!("{} varlık yeniden yüklendi", refs.len()))
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+fn open(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return Err("kullanım: open <dosya>".into());
+    }
+    // Boşluk içeren yollar birden çok argümana bölünmüş olabilir
+    state.open_file(std::path::Path::new(&args.join(" ")))
+}
+
+fn profiler(state: &mut State, args: &[&str]) -> Result<String, String> {
+    state.profiler.visible = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !state.profiler.visible,
+        _ => return Err("kullanım: profiler on|off".into()),
+    };
+    if state.profiler.visible && !state.profiler.has_gpu_timing() {
+        return Ok("profiler açık (GPU zaman damgaları desteklenmiyor, sadece CPU)".into());
+    }
+    Ok(format!("profiler {}", if state.profiler.visible { "açık" } else { "kapalı" }))
+}
+
+fn stats(state: &mut State, args: &[&str]) -> Result<String, String> {
+    state.stats.visible = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !state.stats.visible,
+        _ => return Err("kullanım: stats on|off".into()),
+    };
+    let total = state.stats.total();
+    Ok(format!(
+        "son kare: {} çizim, {} üçgen",
+        total.draw_calls, total.triangles
+    ))
+}
+
+fn events(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if args.first() == Some(&"clear") {
+        state.event_log.clear();
+        return Ok("olay günlüğü temizlendi".into());
+    }
+    let kind = args
+        .first()
+        .and_then(|arg| EventKind::ALL.into_iter().find(|kind| kind.label() == *arg));
+    let needle = args[kind.is_some() as usize..].join(" ").to_lowercase();
+    let mut lines: Vec<String> = state
+        .event_log
+        .iter()
+        .rev()
+        .filter(|entry| kind.is_none_or(|kind| entry.kind == kind))
+        .filter(|entry| !entry.frequent && entry.text.to_lowercase().contains(&needle))
+        .take(20)
+        .map(|entry| format!("{:>8.3} #{:<6} {:<8} {}", entry.time, entry.frame, entry.kind.label(), entry.text))
+        .collect();
+    lines.reverse();
+    Ok(lines.join("\n"))
+}
+
+fn screenshot(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    if !state.surface_config.usage.contains(wgpu::TextureUsages::COPY_SRC) {
+        return Err("bu yüzey kopyalamayı (COPY_SRC) desteklemiyor".into());
+    }
+    state.screenshot_requested = true;
+    Ok("ekran görüntüsü bir sonraki karede alınacak".into())
+}
+
+fn report(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    state.report_requested = true;
+    // Ekran görüntüsü alınamayan yüzeyde rapor görüntüsüz yazılır
+    state.screenshot_requested |= state.surface_config.usage.contains(wgpu::TextureUsages::COPY_SRC);
+    Ok("rapor bir sonraki karede yazılacak".into())
+}
+
+fn pause(state: &mut State, args: &[&str]) -> Result<String, String> {
+    state.paused = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !state.paused,
+        _ => return Err("kullanım: pause on|off".into()),
+    };
+    Ok(format!("oyun {}", if state.paused { "duraklatıldı" } else { "devam ediyor" }))
+}
+
+fn capture_frame(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    state.frame_capture_requested = true;
+    Ok("bir sonraki kare yakalanacak".into())
+}
+
+fn sort_test(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let count = match args.first() {
+        Some(text) => text.parse::<u32>().map_err(|_| format!("geçersiz adet: {text}"))?,
+        None => 65536,
+    };
+    if !(1..=1 << 24).contains(&count) {
+        return Err("adet 1 ile 16777216 arasında olmalı".into());
+    }
+    state.gpu_sorter.self_test(&state.device, &state.queue, &mut state.rng, count)
+}
+
+fn noise(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let usage = "kullanım: noise perlin|simplex|worley [ölçek] [tohum]";
+    let mut params = NoiseParams {
+        kind: args.first().ok_or(usage)?.parse()?,
+        ..Default::default()
+    };
+    if let Some(text) = args.get(1) {
+        params.scale = text
+            .parse()
+            .ok()
+            .filter(|scale: &f32| *scale > 0.0 && scale.is_finite())
+            .ok_or_else(|| format!("geçersiz ölçek: {text}"))?;
+    }
+    params.seed = seed_arg(args, 2, &mut state.rng)?;
+
+    let texture = state.noise_baker.bake(&state.device, &state.queue, &params);
+    let difference = state.noise_baker.compare(&state.device, &state.queue, &texture, &params)?;
+    #[cfg(feature = "debug-ui")]
+    if let Some(handle) = state.assets.generated_texture("gürültü") {
+        state.free_thumbnail(AssetRef::Texture(handle));
+    }
+    let handle = state.assets.set_generated_texture(&state.device, "gürültü", &texture);
+
+    let mut output = format!(
+        "{} {}x{} pişirildi (ölçek {}, tohum {}), CPU ile en büyük fark {difference}/255",
+        params.kind, params.size, params.size, params.scale, params.seed
+    );
+    if let Some(entity) = state.scene.selected.and_then(|id| state.scene.get_mut(id)) {
+        entity.material.get_or_insert_with(Default::default).texture = Some(handle);
+        output.push_str(&format!(", {} nesnesine atandı", entity.name));
+    }
+    Ok(output)
+}
+
+// Alt komut argümanı; yoksa varsayılan
+fn number<T: std::str::FromStr>(args: &[&str], index: usize, default: T) -> Result<T, String> {
+    match args.get(index) {
+        Some(text) => text.parse().map_err(|_| format!("geçersiz sayı: {text}")),
+        None => Ok(default),
+    }
+}
+
+// Tohum argümanı; yoksa ortak üreteçten çekilir, böylece `--seed` ile tekrarlanır
+fn seed_arg(args: &[&str], index: usize, rng: &mut SmallRng) -> Result<u32, String> {
+    match args.get(index) {
+        Some(text) => text.parse().map_err(|_| format!("geçersiz tohum: {text}")),
+        None => Ok(rng.random()),
+    }
+}
+
+fn seed(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if let Some(text) = args.first() {
+        state.seed = text.parse().map_err(|_| format!("geçersiz tohum: {text}"))?;
+        state.rng = SmallRng::seed_from_u64(state.seed);
+        return Ok(format!("üreteç {} tohumuyla yeniden başlatıldı", state.seed));
+    }
+    Ok(format!("tohum {} (aynı çıktı için --seed {})", state.seed, state.seed))
+}
+
+// `voxels set` koordinat sınırı; mesh üretimi komşu parçalara ofset eklediği
+// için i32 sınırından uzak durulur
+const VOXEL_LIMIT: i32 = 1 << 24;
+
+fn voxels(state: &mut State, args: &[&str]) -> Result<String, String> {
+    match args.first() {
+        Some(&"generate") => {
+            let radius: i32 = number(args, 1, 2)?;
+            if !(1..=8).contains(&radius) {
+                return Err("yarıçap 1 ile 8 arasında olmalı".into());
+            }
+            let seed = seed_arg(args, 2, &mut state.rng)?;
+            state.voxels.generate(radius, seed);
+            state.voxels.prepare(&state.device);
+            return Ok(format!("tohum {seed}\n{}", state.voxels.summary()));
+        }
+        Some(&"clear") => {
+            state.voxels.clear();
+            return Ok("voksel dünyası temizlendi".into());
+        }
+        // Kamera hedefinde küresel bir oyuk açar; parça sınırlarını aşan oyuklar
+        // komşu parçaların mesh'lerini de yeniler
+        Some(&"dig") => {
+            let radius: i32 = number(args, 1, 6)?;
+            if !(1..=16).contains(&radius) {
+                return Err("yarıçap 1 ile 16 arasında olmalı".into());
+            }
+            let center = Voxels::block_at(state.scene.camera.target);
+            for z in -radius..=radius {
+                for y in -radius..=radius {
+                    for x in -radius..=radius {
+                        let offset = glam::IVec3::new(x, y, z);
+                        if offset.length_squared() <= radius * radius {
+                            state.voxels.world.set_block(center + offset, Block::Air);
+                        }
+                    }
+                }
+            }
+        }
+        Some(&"set") => {
+            if args.len() != 5 {
+                return Err("kullanım: voxels set x y z blok".into());
+            }
+            let mut position = glam::IVec3::ZERO;
+            for axis in 0..3 {
+                position[axis] = number(args, axis + 1, 0)?;
+            }
+            if position.abs().max_element() >= VOXEL_LIMIT {
+                return Err(format!("koordinatlar ±{VOXEL_LIMIT} aralığında olmalı"));
+            }
+            state.voxels.world.set_block(position, args[4].parse()?);
+        }
+        _ => return Err("kullanım: voxels generate [yarıçap] [tohum] | clear | dig [yarıçap] | set x y z blok".into()),
+    }
+    // Mesh'ler normalde karede üretilir; özet güncel olsun diye burada hemen üretilir
+    state.voxels.prepare(&state.device);
+    Ok(state.voxels.summary())
+}
+
+fn points(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let renderer = &mut state.point_cloud;
+    match args.first() {
+        Some(&"load") => {
+            if args.len() < 2 {
+                return Err("kullanım: points load <dosya.ply|dosya.las>".into());
+            }
+            // `open` gibi: boşluk içeren yollar birden çok argümana bölünmüş olabilir
+            return renderer.load(&state.device, std::path::Path::new(&args[1..].join(" ")));
+        }
+        Some(&"demo") => {
+            let count: usize = number(args, 1, 2_000_000)?;
+            if !(1..=20_000_000).contains(&count) {
+                return Err("nokta sayısı 1 ile 20000000 arasında olmalı".into());
+            }
+            let seed = seed_arg(args, 2, &mut state.rng)?;
+            let points = point_cloud::demo_points(count, seed);
+            return Ok(format!("{} (tohum {seed})", renderer.upload(&state.device, "demo", points)));
+        }
+        Some(&"mode") => renderer.mode = args.get(1).ok_or("kullanım: points mode quads|points")?.parse()?,
+        Some(&"size") => {
+            let size: f32 = number(args, 1, 3.0)?;
+            if !(1.0..=64.0).contains(&size) {
+                return Err("boyut 1 ile 64 piksel arasında olmalı".into());
+            }
+            renderer.point_size = size;
+        }
+        Some(&"budget") => renderer.budget = number::<u32>(args, 1, 2_000_000)?.max(1),
+        Some(&"clear") => {
+            renderer.cloud = None;
+            return Ok("nokta bulutu kaldırıldı".into());
+        }
+        None => {}
+        _ => {
+            return Err(
+                "kullanım: points load <dosya> | demo [sayı] [tohum] | mode quads|points | size <px> | budget <n> | clear".into(),
+            );
+        }
+    }
+    Ok(renderer.summary())
+}
+
+fn lines(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let lines = &mut state.lines;
+    let style = &mut lines.debug_style;
+    match args.first() {
+        Some(&"on") => lines.debug_draw = true,
+        Some(&"off") => lines.debug_draw = false,
+        Some(&"width") => {
+            let width: f32 = number(args, 1, 2.0)?;
+            if !(0.1..=64.0).contains(&width) {
+                return Err("genişlik 0.1 ile 64 piksel arasında olmalı".into());
+            }
+            style.width = width;
+        }
+        Some(&"join") => style.join = args.get(1).ok_or("kullanım: lines join miter|round")?.parse()?,
+        Some(&"cap") => style.cap = args.get(1).ok_or("kullanım: lines cap butt|square|round")?.parse()?,
+        None => lines.debug_draw = !lines.debug_draw,
+        _ => return Err("kullanım: lines on|off | width <px> | join miter|round | cap butt|square|round".into()),
+    }
+    Ok(format!(
+        "hata ayıklama çizgileri {}: {} px, birleşim {}, uç {}",
+        if lines.debug_draw { "açık" } else { "kapalı" },
+        lines.debug_style.width,
+        lines.debug_style.join,
+        lines.debug_style.cap
+    ))
+}
+
+fn ninepatch(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if args.first() == Some(&"off") {
+        state.nine_slice_demo = None;
+        return Ok("dokuz parçalı paneller kapalı".into());
+    }
+    let inset: f32 = number(args, 0, PANEL_INSET)?;
+    let texture = match args.get(1) {
+        Some(name) => state
+            .assets
+            .textures()
+            .find(|(_, texture)| texture.name == *name)
+            .map(|(handle, _)| handle)
+            .ok_or_else(|| format!("doku bulunamadı: {name}"))?,
+        None => TextureHandle::PANEL,
+    };
+    let size = state.assets.texture_or_white(Some(texture)).size;
+    if inset < 0.0 || inset * 2.0 >= size[0].min(size[1]) as f32 {
+        return Err(format!("kenar payı 0 ile dokunun yarısı ({}x{}) arasında olmalı", size[0], size[1]));
+    }
+    state.nine_slice_demo = Some(NineSlice::new(texture, inset));
+    Ok(format!("{}x{} dokudan {inset} piksel kenar paylı paneller", size[0], size[1]))
+}
+
+fn theme(state: &mut State, args: &[&str]) -> Result<String, String> {
+    if let Some(preference) = args.first() {
+        state.settings.theme = preference.parse()?;
+        state.apply_theme();
+    }
+    Ok(format!(
+        "tema: {} (tercih: {}, sistem: {})",
+        state.theme, state.settings.theme, state.system_theme
+    ))
+}
+
+fn ui(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let visible = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        Some(&"menu") => {
+            let anchor = args.get(1).ok_or("kullanım: ui menu <konum>")?.parse()?;
+            state.ui.get_or_insert_with(DemoUi::new).set_menu_anchor(anchor);
+            return Ok(format!("menü konumu: {}", args[1]));
+        }
+        None => state.ui.is_none(),
+        _ => return Err("kullanım: ui on|off | menu <konum>".into()),
+    };
+    if !visible {
+        state.ui = None;
+        return Ok("arayüz kapalı".into());
+    }
+    state.ui.get_or_insert_with(DemoUi::new);
+    Ok("arayüz açık; menü butonları konsol komutlarını çalıştırır".into())
+}
+
+fn reload_shaders(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    state.reload_shaders()?;
+    Ok("shader'lar yeniden yüklendi".into())
+}
+
+#[cfg(feature = "debug-ui")]
+fn targets(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let viewer = &mut state.target_viewer;
+    viewer.open = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !viewer.open,
+        _ => return Err("kullanım: targets on|off".into()),
+    };
+    state.debug_ui.visible |= viewer.open;
+    Ok(format!("render hedefleri {}", if viewer.open { "açık" } else { "kapalı" }))
+}
+
+#[cfg(feature = "hot-reload")]
+fn reload_game(state: &mut State, _args: &[&str]) -> Result<String, String> {
+    let game = state.game.as_mut().ok_or("oyun kütüphanesi yüklü değil")?;
+    game.reload().map_err(|e| e.to_string())?;
+    Ok(format!("yüklendi: {}", game.path().display()))
+}
@@ -0,0 +1,228 @@
+use crate::State;
+use crate::overlay::Overlay;
+use std::collections::{BTreeMap, VecDeque};
+use winit::event::{ElementState, KeyEvent};
+use winit::keyboard::{Key, NamedKey};
+
+const MAX_LINES: usize = 200;
+
+pub type CommandFn = fn(&mut State, &[&str]) -> Result<String, String>;
+
+pub struct Command {
+    pub help: &'static str,
+    // Tab ile tamamlanabilecek ilk argüman değerleri
+    pub args: &'static [&'static str],
+    pub run: CommandFn,
+}
+
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: BTreeMap<&'static str, Command>,
+}
+
+impl CommandRegistry {
+    pub fn register(&mut self, name: &'static str, command: Command) {
+        self.commands.insert(name, command);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Command> {
+        self.commands.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Command)> {
+        self.commands.iter().map(|(name, command)| (*name, command))
+    }
+
+    // Girilen satırın son kelimesi için olası tamamlamaları döndürür
+    pub fn complete(&self, line: &str) -> Vec<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let typing_new_word = line.is_empty() || line.ends_with(' ');
+
+        match (words.as_slice(), typing_new_word) {
+            ([], _) | ([_], false) => {
+                let prefix = words.first().copied().unwrap_or("");
+                self.commands
+                    .keys()
+                    .filter(|name| name.starts_with(prefix))
+                    .map(|name| name.to_string())
+                    .collect()
+            }
+            ([name], true) | ([name, _], false) => {
+                let prefix = if typing_new_word { "" } else { words[1] };
+                self.get(name)
+                    .map(|command| {
+                        command
+                            .args
+                            .iter()
+                            .filter(|arg| arg.starts_with(prefix))
+                            .map(|arg| format!("{name} {arg}"))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum LineKind {
+    Input,
+    Output,
+    Error,
+}
+
+pub struct Console {
+    open: bool,
+    input: String,
+    history: Vec<String>,
+    history_cursor: Option<usize>,
+    lines: VecDeque<(LineKind, String)>,
+}
+
+impl Console {
+    pub fn new() -> Self {
+        let mut console = Self {
+            open: false,
+            input: String::new(),
+            history: Vec::new(),
+            history_cursor: None,
+            lines: VecDeque::new(),
+        };
+        console.print(LineKind::Output, "Komutlar için `help` yazın.");
+        console
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    pub fn print(&mut self, kind: LineKind, text: impl Into<String>) {
+        for line in text.into().lines() {
+            if self.lines.len() == MAX_LINES {
+                self.lines.pop_front();
+            }
+            self.lines.push_back((kind, line.to_string()));
+        }
+    }
+
+    // Enter'a basıldığında çalıştırılacak satırı döndürür
+    pub fn handle_key(&mut self, event: &KeyEvent, registry: &CommandRegistry) -> Option<String> {
+        if event.state != ElementState::Pressed {
+            return None;
+        }
+
+        match &event.logical_key {
+            Key::Named(NamedKey::Enter) => {
+                let line = std::mem::take(&mut self.input).trim().to_string();
+                self.history_cursor = None;
+                if line.is_empty() {
+                    return None;
+                }
+                if self.history.last() != Some(&line) {
+                    self.history.push(line.clone());
+                }
+                return Some(line);
+            }
+            Key::Named(NamedKey::Escape) => self.open = false,
+            Key::Named(NamedKey::Backspace) => {
+                self.input.pop();
+            }
+            Key::Named(NamedKey::ArrowUp) => self.browse_history(-1),
+            Key::Named(NamedKey::ArrowDown) => self.browse_history(1),
+            Key::Named(NamedKey::Tab) => self.autocomplete(registry),
+            _ => {
+                if let Some(text) = &event.text {
+                    self.input.extend(text.chars().filter(|c| !c.is_control()));
+                }
+            }
+        }
+        None
+    }
+
+    fn browse_history(&mut self, step: isize) {
+        if self.history.is_empty() {
+            return;
+        }
+        let last = self.history.len() as isize - 1;
+        let next = match self.history_cursor {
+            None if step < 0 => Some(last),
+            None => None,
+            Some(index) => {
+                let index = index as isize + step;
+                (index <= last).then_some(index.max(0))
+            }
+        };
+        self.history_cursor = next.map(|index| index as usize);
+        self.input = match self.history_cursor {
+            Some(index) => self.history[index].clone(),
+            None => String::new(),
+        };
+    }
+
+    fn autocomplete(&mut self, registry: &CommandRegistry) {
+        let candidates = registry.complete(&self.input);
+        match candidates.as_slice() {
+            [] => {}
+            [single] => self.input = format!("{single} "),
+            [first, rest @ ..] => {
+                let common = rest.iter().fold(first.as_str(), |common, candidate| {
+                    let len = common
+                        .char_indices()
+                        .zip(candidate.chars())
+                        .take_while(|((_, a), b)| a == b)
+                        .last()
+                        .map(|((i, c), _)| i + c.len_utf8())
+                        .unwrap_or(0);
+                    &common[..len]
+                });
+                if common.len() > self.input.len() {
+                    self.input = common.to_string();
+                }
+                let names: Vec<&str> = candidates
+                    .iter()
+                    .map(|c| c.rsplit(' ').next().unwrap_or(c))
+                    .collect();
+                self.print(LineKind::Output, names.join("  "));
+            }
+        }
+    }
+
+    pub fn draw(&self, overlay: &mut Overlay, width: f32, height: f32) {
+        if !self.open {
+            return;
+        }
+        let palette = overlay.palette;
+        let [_, line_height] = overlay.char_size();
+        let panel_height = (height * 0.4).max(line_height * 3.0);
+        let padding = 6.0;
+
+        overlay.rect(0.0, 0.0, width, panel_height, palette.panel);
+        overlay.rect(0.0, panel_height, width, 2.0, palette.accent);
+
+        let input_y = panel_height - line_height - padding;
+        overlay.text(padding, input_y, &format!("> {}_", self.input), palette.text);
+
+        let mut y = input_y - line_height - padding;
+        for (kind, text) in self.lines.iter().rev() {
+            if y < 0.0 {
+                break;
+            }
+            let color = match kind {
+                LineKind::Input => palette.input,
+                LineKind::Output => palette.output,
+                LineKind::Error => palette.error,
+            };
+            overlay.text(padding, y, text, color);
+            y -= line_height;
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::profiler::{Profiler, Span};
+use crate::settings::Settings;
+use crate::stats::{DrawStats, RenderStats};
+use log::{LevelFilter, Log, Metadata, Record};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+const LOG_LINES: usize = 500;
+
+// Panik kancası olay döngüsüne ulaşamadığı için rapora girecek bağlam burada
+// tutulur: adaptör bir kez, ayarlar ve kare istatistikleri her karede yazılır.
+struct Context {
+    adapter: String,
+    settings: Option<Settings>,
+    seed: u64,
+    frame_ms: f32,
+    passes: Vec<(&'static str, DrawStats)>,
+    cpu: Vec<Span>,
+    gpu: Vec<Span>,
+}
+
+static CONTEXT: Mutex<Context> = Mutex::new(Context {
+    adapter: String::new(),
+    settings: None,
+    seed: 0,
+    frame_ms: 0.0,
+    passes: Vec::new(),
+    cpu: Vec::new(),
+    gpu: Vec::new(),
+});
+static LOG: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+static START: OnceLock<Instant> = OnceLock::new();
+
+// Zehirlenmiş kilit de okunur; rapor zaten bir şeyler ters gittiğinde yazılıyor
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// env_logger'ı sarar: `RUST_LOG` ne olursa olsun info ve üstü rapor için saklanır,
+// terminale yine env_logger'ın filtresine göre yazılır
+struct Logger {
+    inner: env_logger::Logger,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= LevelFilter::Info || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let elapsed = START.get_or_init(Instant::now).elapsed().as_secs_f32();
+        let line = format!(
+            "{elapsed:9.3} {:5} {}: {}",
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        let mut log = lock(&LOG);
+        if log.len() == LOG_LINES {
+            log.pop_front();
+        }
+        log.push_back(line);
+        drop(log);
+        self.inner.log(record);
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+// `env_logger::init` yerine; ardından panikte rapor yazan kancayı kurar
+pub fn init() {
+    START.get_or_init(Instant::now);
+    let inner = env_logger::Builder::from_default_env().build();
+    log::set_max_level(inner.filter().max(LevelFilter::Info));
+    if log::set_boxed_logger(Box::new(Logger { inner })).is_err() {
+        eprintln!("log kaydedicisi zaten kurulmuş");
+    }
+
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        let backtrace = std::backtrace::Backtrace::force_capture();
+        match write(&format!("panik: {info}"), &[("geri izleme", backtrace.to_string())]) {
+            Ok(directory) => eprintln!("çökme raporu yazıldı: {}", directory.display()),
+            Err(e) => eprintln!("çökme raporu yazılamadı: {e}"),
+        }
+    }));
+}
+
+pub fn set_adapter(info: &wgpu::AdapterInfo) {
+    lock(&CONTEXT).adapter = format!("{info:?}");
+}
+
+// Her karenin başında; istatistikler bir önceki tamamlanan kareye aittir
+pub fn record_frame(settings: &Settings, seed: u64, stats: &RenderStats, profiler: &Profiler) {
+    let mut context = lock(&CONTEXT);
+    context.settings = Some(settings.clone());
+    context.seed = seed;
+    context.passes.clear();
+    context.passes.extend_from_slice(stats.last());
+    if let Some(frame) = profiler.last_frame() {
+        context.frame_ms = frame.frame_ms;
+        context.cpu.clone_from(&frame.cpu);
+        context.gpu.clone_from(&frame.gpu);
+    }
+}
+
+// `crashes/crash-<zaman>/` altına report.txt ve log.txt yazar; ekran görüntüsü
+// gibi ek dosyalar için klasörü döndürür
+pub fn write(reason: &str, sections: &[(&str, String)]) -> Result<PathBuf, Box<dyn Error>> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    let directory = PathBuf::from("crashes").join(format!("crash-{timestamp}"));
+    std::fs::create_dir_all(&directory)?;
+
+    let mut report = String::new();
+    writeln!(
+        report,
+        "{} {} raporu",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    )?;
+    writeln!(report, "neden: {reason}")?;
+    writeln!(report, "zaman: {timestamp} (unix ms)")?;
+    writeln!(report, "sistem: {} {}", std::env::consts::OS, std::env::consts::ARCH)?;
+    {
+        let context = lock(&CONTEXT);
+        let adapter = if context.adapter.is_empty() {
+            "henüz seçilmedi"
+        } else {
+            &context.adapter
+        };
+        writeln!(report, "adaptör: {adapter}")?;
+        match &context.settings {
+            Some(settings) => {
+                writeln!(report, "ayarlar: {settings:?}")?;
+                writeln!(
+                    report,
+                    "tohum: {} (tekrarlamak için --seed {})",
+                    context.seed, context.seed
+                )?;
+            }
+            None => writeln!(report, "ayarlar: ilk kareden önce")?,
+        }
+
+        writeln!(report, "\n== son kare ==")?;
+        writeln!(report, "kare süresi: {:.2} ms", context.frame_ms)?;
+        for (pass, stats) in &context.passes {
+            writeln!(
+                report,
+                "{pass:<16} {:>5} çizim {:>10} üçgen {:>8} örnek",
+                stats.draw_calls, stats.triangles, stats.instances
+            )?;
+        }
+        for (label, spans) in [("CPU", &context.cpu), ("GPU", &context.gpu)] {
+            for span in spans {
+                let indent = "  ".repeat(span.depth);
+                writeln!(report, "{label} {indent}{:<16} {:.3} ms", span.name, span.duration_ms())?;
+            }
+        }
+    }
+    for (title, text) in sections {
+        writeln!(report, "\n== {title} ==\n{text}")?;
+    }
+    std::fs::write(directory.join("report.txt"), report)?;
+
+    let log: Vec<String> = lock(&LOG).iter().cloned().collect();
+    std::fs::write(directory.join("log.txt"), log.join("\n"))?;
+    Ok(directory)
+}
@@ -0,0 +1,177 @@
+use crate::assets::AssetManager;
+use crate::settings::Theme;
+use crate::stats::DrawStats;
+use std::sync::Arc;
+use winit::event::WindowEvent;
+use winit::window::Window;
+
+// egui tabanlı hata ayıklama arayüzü. Panellerin kendisi ilgili modüllerde
+// (ör. `inspector`) tanımlanır, burada sadece winit/wgpu entegrasyonu var.
+pub struct DebugUi {
+    window: Arc<Window>,
+    context: egui::Context,
+    winit_state: egui_winit::State,
+    renderer: egui_wgpu::Renderer,
+    repaint_requested: bool,
+    pub visible: bool,
+}
+
+impl DebugUi {
+    pub fn new(window: Arc<Window>, device: &wgpu::Device, format: wgpu::TextureFormat, theme: Theme) -> Self {
+        let context = egui::Context::default();
+        context.set_theme(egui_theme(theme));
+        let winit_state = egui_winit::State::new(
+            context.clone(),
+            egui::ViewportId::ROOT,
+            &window,
+            Some(window.scale_factor() as f32),
+            window.theme(),
+            Some(device.limits().max_texture_dimension_2d as usize),
+        );
+        let renderer = egui_wgpu::Renderer::new(device, format, None, 1, false);
+
+        Self {
+            window,
+            context,
+            winit_state,
+            renderer,
+            repaint_requested: false,
+            visible: false,
+        }
+    }
+
+    // Tema elle seçilebildiği için egui'nin sistem temasını izlemesi yerine geçerli tema verilir
+    pub fn set_theme(&self, theme: Theme) {
+        self.context.set_theme(egui_theme(theme));
+    }
+
+    // egui olayı kullandıysa (ör. bir pencerenin üzerine tıklandıysa) true döner
+    pub fn on_window_event(&mut self, event: &WindowEvent) -> bool {
+        if !self.visible {
+            return false;
+        }
+        let response = self.winit_state.on_window_event(&self.window, event);
+        // Üzerine gelme gibi tüketilmeyen olaylar da yeni kare isteyebilir
+        self.repaint_requested |= response.repaint;
+        response.consumed
+    }
+
+    // egui bir animasyon veya durum değişikliği için hemen yeni kare istedi mi
+    pub fn wants_repaint(&self) -> bool {
+        self.visible && self.repaint_requested
+    }
+
+    // Bir metin alanına yazılıyorsa kısayollar egui'ye bırakılır
+    pub fn wants_keyboard_input(&self) -> bool {
+        self.visible && self.context.wants_keyboard_input()
+    }
+
+    // Henüz egui'ye tanıtılmamış dokular için küçük resim kaydeder
+    pub fn register_thumbnails(&mut self, device: &wgpu::Device, assets: &mut AssetManager) {
+        for texture in assets.textures_mut() {
+            if texture.thumbnail.is_none() {
+                texture.thumbnail = Some(self.renderer.register_native_texture(
+                    device,
+                    &texture.view,
+                    wgpu::FilterMode::Linear,
+                ));
+            }
+        }
+    }
+
+    // Boyutu değişince yeniden oluşturulan dokular aynı egui kimliğiyle güncellenir
+    pub fn register_texture(
+        &mut self,
+        device: &wgpu::Device,
+        view: &wgpu::TextureView,
+        id: Option<egui::TextureId>,
+    ) -> egui::TextureId {
+        match id {
+            Some(id) => {
+                self.renderer
+                    .update_egui_texture_from_wgpu_texture(device, view, wgpu::FilterMode::Linear, id);
+                id
+            }
+            None => self.renderer.register_native_texture(device, view, wgpu::FilterMode::Linear),
+        }
+    }
+
+    pub fn free_texture(&mut self, id: egui::TextureId) {
+        self.renderer.free_texture(&id);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        size: [u32; 2],
+        timestamp_writes: Option<wgpu::RenderPassTimestampWrites<'_>>,
+        run_ui: impl FnMut(&egui::Context),
+    ) -> DrawStats {
+        let mut stats = DrawStats::default();
+        if !self.visible {
+            return stats;
+        }
+
+        let raw_input = self.winit_state.take_egui_input(&self.window);
+        let output = self.context.run(raw_input, run_ui);
+        self.repaint_requested = output
+            .viewport_output
+            .get(&egui::ViewportId::ROOT)
+            .is_some_and(|viewport| viewport.repaint_delay.is_zero());
+        self.winit_state.handle_platform_output(&self.window, output.platform_output);
+
+        let primitives = self.context.tessellate(output.shapes, output.pixels_per_point);
+        let screen = egui_wgpu::ScreenDescriptor {
+            size_in_pixels: size,
+            pixels_per_point: output.pixels_per_point,
+        };
+
+        for (id, delta) in &output.textures_delta.set {
+            self.renderer.update_texture(device, queue, *id, delta);
+        }
+        self.renderer.update_buffers(device, queue, encoder, &primitives, &screen);
+
+        {
+            let render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Debug UI Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes,
+            });
+            self.renderer
+                .render(&mut render_pass.forget_lifetime(), &primitives, &screen);
+        }
+
+        for id in &output.textures_delta.free {
+            self.renderer.free_texture(id);
+        }
+
+        for primitive in &primitives {
+            if let egui::epaint::Primitive::Mesh(mesh) = &primitive.primitive
+                && !mesh.indices.is_empty()
+            {
+                stats.draw(mesh.indices.len() as u32 / 3, 1);
+            }
+        }
+        stats
+    }
+}
+
+fn egui_theme(theme: Theme) -> egui::Theme {
+    match theme {
+        Theme::Dark => egui::Theme::Dark,
+        Theme::Light => egui::Theme::Light,
+    }
+}
@@ -0,0 +1,27 @@
+use crate::State;
+use crate::color::Color;
+use crate::layer::Layer;
+use winit::event::WindowEvent;
+
+// İmleç renginin temanın arka planına karışma oranı
+const CURSOR_TINT: f32 = 0.25;
+
+// Şablonun örnek katmanı: temanın arka plan rengi imleç konumuna göre
+// renklenir. Kendi uygulamanızda bunun yerine kendi katmanınızı ekleyin.
+#[derive(Default)]
+pub struct DemoLayer;
+
+impl Layer for DemoLayer {
+    fn on_event(&mut self, state: &mut State, event: &WindowEvent) -> bool {
+        // İmleç konumu algısal (sRGB) bir gradyan olarak yorumlanır; tema
+        // rengi baskın kalır
+        if let WindowEvent::CursorMoved { .. } = event
+            && let Some(cursor) = state.input_state.cursor_normalized()
+        {
+            let [r, g] = cursor.map(|value| value.clamp(0.0, 1.0) as f32);
+            state.clear_color = state.theme.clear_color().lerp(Color::srgb(r, g, 1.0), CURSOR_TINT);
+            state.dirty = true;
+        }
+        false
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+use winit::event::WindowEvent;
+use winit::keyboard::PhysicalKey;
+
+const MAX_TEXT: usize = 160;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Window,
+    Input,
+    Action,
+    App,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 4] = [EventKind::Window, EventKind::Input, EventKind::Action, EventKind::App];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            EventKind::Window => "pencere",
+            EventKind::Input => "girdi",
+            EventKind::Action => "eylem",
+            EventKind::App => "uygulama",
+        }
+    }
+}
+
+pub struct LogEntry {
+    // Uygulama başlangıcından beri geçen saniye
+    pub time: f32,
+    pub frame: u64,
+    pub kind: EventKind,
+    // Her karede tekrarlanan olaylar (imleç hareketi, yeniden çizim); görüntüleyicide gizlenebilir
+    pub frequent: bool,
+    pub text: String,
+}
+
+// Son olayları tutan sabit kapasiteli halka tampon
+pub struct EventLog {
+    start: Instant,
+    frame: u64,
+    capacity: usize,
+    entries: VecDeque<LogEntry>,
+}
+
+impl EventLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            start: Instant::now(),
+            frame: 0,
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    fn push_entry(&mut self, kind: EventKind, frequent: bool, mut text: String) {
+        if let Some((index, _)) = text.char_indices().nth(MAX_TEXT) {
+            text.truncate(index);
+            text.push('…');
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(LogEntry {
+            time: self.start.elapsed().as_secs_f32(),
+            frame: self.frame,
+            kind,
+            frequent,
+            text,
+        });
+    }
+
+    pub fn push(&mut self, kind: EventKind, text: impl Into<String>) {
+        self.push_entry(kind, false, text.into());
+    }
+
+    pub fn push_window_event(&mut self, event: &WindowEvent) {
+        let (kind, frequent, text) = describe(event);
+        self.push_entry(kind, frequent, text);
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+// Olayın kısa bir özeti; `Debug` çıktısı klavye olayları için fazla uzun
+fn describe(event: &WindowEvent) -> (EventKind, bool, String) {
+    match event {
+        WindowEvent::KeyboardInput { event, .. } => {
+            let key = match event.physical_key {
+                PhysicalKey::Code(code) => format!("{code:?}"),
+                PhysicalKey::Unidentified(native) => format!("{native:?}"),
+            };
+            let repeat = if event.repeat { " (tekrar)" } else { "" };
+            let text = event.text.as_deref().map(|text| format!(" {text:?}")).unwrap_or_default();
+            (EventKind::Input, false, format!("KeyboardInput {key} {:?}{repeat}{text}", event.state))
+        }
+        WindowEvent::MouseInput { state, button, .. } => {
+            (EventKind::Input, false, format!("MouseInput {button:?} {state:?}"))
+        }
+        WindowEvent::MouseWheel { delta, .. } => (EventKind::Input, true, format!("MouseWheel {delta:?}")),
+        WindowEvent::CursorMoved { position, .. } => (
+            EventKind::Input,
+            true,
+            format!("CursorMoved ({:.1}, {:.1})", position.x, position.y),
+        ),
+        WindowEvent::ModifiersChanged(modifiers) => {
+            (EventKind::Input, false, format!("ModifiersChanged {:?}", modifiers.state()))
+        }
+        WindowEvent::CursorEntered { .. } | WindowEvent::CursorLeft { .. } | WindowEvent::Ime(_) => {
+            (EventKind::Input, false, format!("{event:?}"))
+        }
+        WindowEvent::AxisMotion { .. } => (EventKind::Input, true, format!("{event:?}")),
+        WindowEvent::Resized(size) => (
+            EventKind::Window,
+            false,
+            format!("Resized {}x{}", size.width, size.height),
+        ),
+        WindowEvent::RedrawRequested => (EventKind::Window, true, "RedrawRequested".to_string()),
+        _ => (EventKind::Window, false, format!("{event:?}")),
+    }
+}
@@ -0,0 +1,85 @@
+use crate::event_log::{EventKind, EventLog, LogEntry};
+
+pub struct EventViewer {
+    pub open: bool,
+    filter: String,
+    kinds: [bool; EventKind::ALL.len()],
+    show_frequent: bool,
+}
+
+impl Default for EventViewer {
+    fn default() -> Self {
+        Self {
+            open: false,
+            filter: String::new(),
+            kinds: [true; EventKind::ALL.len()],
+            show_frequent: false,
+        }
+    }
+}
+
+fn kind_color(kind: EventKind) -> egui::Color32 {
+    match kind {
+        EventKind::Window => egui::Color32::from_rgb(140, 180, 255),
+        EventKind::Input => egui::Color32::from_rgb(200, 200, 200),
+        EventKind::Action => egui::Color32::from_rgb(255, 210, 90),
+        EventKind::App => egui::Color32::from_rgb(130, 220, 140),
+    }
+}
+
+impl EventViewer {
+    pub fn show(&mut self, context: &egui::Context, log: &mut EventLog) {
+        let Self {
+            open,
+            filter,
+            kinds,
+            show_frequent,
+        } = self;
+
+        egui::Window::new("Olay Günlüğü")
+            .open(open)
+            .default_size([520.0, 320.0])
+            .show(context, |ui| {
+                ui.horizontal(|ui| {
+                    for (kind, enabled) in EventKind::ALL.iter().zip(kinds.iter_mut()) {
+                        ui.checkbox(enabled, kind.label());
+                    }
+                    ui.checkbox(show_frequent, "sık olaylar");
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Filtre");
+                    ui.text_edit_singleline(filter);
+                    if ui.button("Temizle").clicked() {
+                        log.clear();
+                    }
+                });
+                ui.separator();
+
+                let needle = filter.to_lowercase();
+                let visible = |entry: &&LogEntry| {
+                    let kind_index = EventKind::ALL.iter().position(|kind| *kind == entry.kind).unwrap_or(0);
+                    kinds[kind_index]
+                        && (*show_frequent || !entry.frequent)
+                        && (needle.is_empty() || entry.text.to_lowercase().contains(&needle))
+                };
+                let entries: Vec<&LogEntry> = log.iter().filter(visible).collect();
+
+                let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                egui::ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .stick_to_bottom(true)
+                    .show_rows(ui, row_height, entries.len(), |ui, rows| {
+                        for entry in &entries[rows] {
+                            let text = format!(
+                                "{:>8.3} #{:<6} {:<8} {}",
+                                entry.time,
+                                entry.frame,
+                                entry.kind.label(),
+                                entry.text
+                            );
+                            ui.label(egui::RichText::new(text).monospace().color(kind_color(entry.kind)));
+                        }
+                    });
+            });
+    }
+}
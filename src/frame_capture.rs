@@ -0,0 +1,227 @@
+use crate::stats::DrawStats;
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub enum Command {
+    SetPipeline(String),
+    SetBindGroup { index: u32, name: String },
+    SetVertexBuffer { slot: u32, name: String, size: u64 },
+    SetIndexBuffer { name: String, format: wgpu::IndexFormat, size: u64 },
+    Draw { vertices: Range<u32>, instances: Range<u32> },
+    DrawIndexed { indices: Range<u32>, base_vertex: i32, instances: Range<u32> },
+    // İçini göremediğimiz çizimler (ör. egui) için serbest açıklama
+    Note(String),
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::SetPipeline(name) => write!(f, "set_pipeline        \"{name}\""),
+            Command::SetBindGroup { index, name } => write!(f, "set_bind_group      {index} \"{name}\""),
+            Command::SetVertexBuffer { slot, name, size } => {
+                write!(f, "set_vertex_buffer   {slot} \"{name}\" ({size} B)")
+            }
+            Command::SetIndexBuffer { name, format, size } => {
+                write!(f, "set_index_buffer    \"{name}\" {format:?} ({size} B)")
+            }
+            Command::Draw { vertices, instances } => {
+                write!(f, "draw                vertices {vertices:?} instances {instances:?}")
+            }
+            Command::DrawIndexed { indices, base_vertex, instances } => write!(
+                f,
+                "draw_indexed        indices {indices:?} base_vertex {base_vertex} instances {instances:?}"
+            ),
+            Command::Note(text) => write!(f, "# {text}"),
+        }
+    }
+}
+
+pub struct PassRecord {
+    pub name: &'static str,
+    pub attachments: Vec<String>,
+    pub commands: Vec<Command>,
+    pub stats: DrawStats,
+}
+
+impl PassRecord {
+    pub fn push(&mut self, command: Command) {
+        self.commands.push(command);
+    }
+}
+
+// Bir karede verilen tüm pass ve komutların metin dökümü. `capture_frame`
+// komutuyla bir sonraki kare için açılır.
+pub struct FrameCapture {
+    header: Vec<String>,
+    passes: Vec<PassRecord>,
+}
+
+impl FrameCapture {
+    pub fn new(header: Vec<String>) -> Self {
+        Self {
+            header,
+            passes: Vec::new(),
+        }
+    }
+
+    pub fn pass(&mut self, name: &'static str, attachments: Vec<String>) -> &mut PassRecord {
+        self.passes.push(PassRecord {
+            name,
+            attachments,
+            commands: Vec::new(),
+            stats: DrawStats::default(),
+        });
+        self.passes.last_mut().unwrap()
+    }
+
+    pub fn report(&self) -> String {
+        let mut pipelines = BTreeSet::new();
+        let mut bind_groups = BTreeSet::new();
+        let mut total = DrawStats::default();
+        for pass in &self.passes {
+            total += pass.stats;
+            for command in &pass.commands {
+                match command {
+                    Command::SetPipeline(name) => {
+                        pipelines.insert(name.as_str());
+                    }
+                    Command::SetBindGroup { name, .. } => {
+                        bind_groups.insert(name.as_str());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for line in &self.header {
+            let _ = writeln!(out, "{line}");
+        }
+        let _ = writeln!(
+            out,
+            "{} pass, {} çizim, {} üçgen, {} pipeline, {} bind group\n",
+            self.passes.len(),
+            total.draw_calls,
+            total.triangles,
+            pipelines.len(),
+            bind_groups.len()
+        );
+
+        for (index, pass) in self.passes.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "pass {index} \"{}\" ({} çizim, {} üçgen)",
+                pass.name, pass.stats.draw_calls, pass.stats.triangles
+            );
+            for attachment in &pass.attachments {
+                let _ = writeln!(out, "  hedef: {attachment}");
+            }
+            for command in &pass.commands {
+                let _ = writeln!(out, "  {command}");
+            }
+            let _ = writeln!(out);
+        }
+
+        let _ = writeln!(out, "pipeline'lar:");
+        for name in &pipelines {
+            let _ = writeln!(out, "  {name}");
+        }
+        let _ = writeln!(out, "bind group'lar:");
+        for name in &bind_groups {
+            let _ = writeln!(out, "  {name}");
+        }
+        out
+    }
+
+    pub fn save(&self) -> Result<PathBuf, Box<dyn Error>> {
+        let directory = PathBuf::from("captures");
+        std::fs::create_dir_all(&directory)?;
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let path = directory.join(format!("frame-{timestamp}.txt"));
+        std::fs::write(&path, self.report())?;
+        Ok(path)
+    }
+}
+
+// Render pass üzerine ince sarmalayıcı: her çizimi istatistiklere sayar,
+// kare yakalama açıksa komutları isimleriyle birlikte kaydeder.
+pub struct TracedPass<'p, 'e> {
+    pass: &'p mut wgpu::RenderPass<'e>,
+    record: Option<&'p mut PassRecord>,
+    stats: DrawStats,
+}
+
+impl<'p, 'e> TracedPass<'p, 'e> {
+    pub fn new(pass: &'p mut wgpu::RenderPass<'e>, record: Option<&'p mut PassRecord>) -> Self {
+        Self {
+            pass,
+            record,
+            stats: DrawStats::default(),
+        }
+    }
+
+    fn push(&mut self, command: impl FnOnce() -> Command) {
+        if let Some(record) = self.record.as_mut() {
+            record.push(command());
+        }
+    }
+
+    pub fn set_pipeline(&mut self, pipeline: &wgpu::RenderPipeline, name: &str) {
+        self.pass.set_pipeline(pipeline);
+        self.push(|| Command::SetPipeline(name.to_string()));
+    }
+
+    pub fn set_bind_group(&mut self, index: u32, bind_group: &wgpu::BindGroup, name: &str) {
+        self.pass.set_bind_group(index, bind_group, &[]);
+        self.push(|| Command::SetBindGroup {
+            index,
+            name: name.to_string(),
+        });
+    }
+
+    pub fn set_vertex_buffer(&mut self, slot: u32, buffer: &wgpu::Buffer, name: &str) {
+        self.pass.set_vertex_buffer(slot, buffer.slice(..));
+        self.push(|| Command::SetVertexBuffer {
+            slot,
+            name: name.to_string(),
+            size: buffer.size(),
+        });
+    }
+
+    pub fn set_index_buffer(&mut self, buffer: &wgpu::Buffer, format: wgpu::IndexFormat, name: &str) {
+        self.pass.set_index_buffer(buffer.slice(..), format);
+        self.push(|| Command::SetIndexBuffer {
+            name: name.to_string(),
+            format,
+            size: buffer.size(),
+        });
+    }
+
+    pub fn draw(&mut self, vertices: Range<u32>, instances: Range<u32>) {
+        self.pass.draw(vertices.clone(), instances.clone());
+        self.stats.draw(vertices.len() as u32 / 3, instances.len() as u32);
+        self.push(|| Command::Draw { vertices, instances });
+    }
+
+    pub fn draw_indexed(&mut self, indices: Range<u32>, base_vertex: i32, instances: Range<u32>) {
+        self.pass.draw_indexed(indices.clone(), base_vertex, instances.clone());
+        self.stats.draw(indices.len() as u32 / 3, instances.len() as u32);
+        self.push(|| Command::DrawIndexed {
+            indices,
+            base_vertex,
+            instances,
+        });
+    }
+
+    pub fn finish(self) -> DrawStats {
+        if let Some(record) = self.record {
+            record.stats += self.stats;
+        }
+        self.stats
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Aynı anda GPU'da bulunabilecek kare sayısı. Yüzeyin
+// `desired_maximum_frame_latency` değerinden bir fazlası: CPU bir sonraki
+// kareyi hazırlarken GPU'nun önceki ikisini işlemesine izin verir.
+pub const FRAMES_IN_FLIGHT: usize = 3;
+
+// Bu karenin ring içindeki yuvası; dinamik kaynaklar bu yuvanın kopyasına yazılır
+#[derive(Copy, Clone, Debug)]
+pub struct FrameContext {
+    pub slot: usize,
+    pub frame: u64,
+}
+
+struct Slot {
+    submission: Option<wgpu::SubmissionIndex>,
+    // `on_submitted_work_done` ile GPU bu yuvanın işini bitirince işaretlenir
+    done: Arc<AtomicBool>,
+}
+
+// Yuvaların hangi gönderime ait olduğunu izler. Bir yuva yeniden
+// kullanılmadan önce GPU'nun o yuvayı okuyan gönderimi bitirmiş olması
+// gerekir; bitmemişse CPU o gönderim indeksini bekler.
+pub struct FrameRing {
+    slots: Vec<Slot>,
+    current: FrameContext,
+    // GPU'yu beklemek zorunda kalınan kare sayısı
+    stalls: u64,
+}
+
+impl FrameRing {
+    pub fn new(count: usize) -> Self {
+        Self {
+            slots: (0..count)
+                .map(|_| Slot {
+                    submission: None,
+                    done: Arc::new(AtomicBool::new(true)),
+                })
+                .collect(),
+            current: FrameContext {
+                slot: count - 1,
+                frame: 0,
+            },
+            stalls: 0,
+        }
+    }
+
+    // Bir sonraki yuvaya geçer; GPU o yuvayı hâlâ kullanıyorsa bekler
+    pub fn begin(&mut self, device: &wgpu::Device) -> FrameContext {
+        let slot = (self.current.slot + 1) % self.slots.len();
+        let _ = device.poll(wgpu::PollType::Poll);
+        let entry = &mut self.slots[slot];
+        if let Some(index) = entry.submission.take()
+            && !entry.done.load(Ordering::Acquire)
+        {
+            self.stalls += 1;
+            if let Err(e) = device.poll(wgpu::PollType::WaitForSubmissionIndex(index)) {
+                log::warn!("kare yuvası {slot} beklenirken hata: {e}");
+            }
+        }
+        self.current = FrameContext {
+            slot,
+            frame: self.current.frame + 1,
+        };
+        self.current
+    }
+
+    pub fn current(&self) -> FrameContext {
+        self.current
+    }
+
+    pub fn submitted(&mut self, queue: &wgpu::Queue, index: wgpu::SubmissionIndex) {
+        let slot = &mut self.slots[self.current.slot];
+        let done = Arc::new(AtomicBool::new(false));
+        let flag = done.clone();
+        queue.on_submitted_work_done(move || flag.store(true, Ordering::Release));
+        slot.submission = Some(index);
+        slot.done = done;
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    // GPU'nun henüz bitirmediği gönderimi olan yuva sayısı
+    pub fn in_flight(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| slot.submission.is_some() && !slot.done.load(Ordering::Acquire))
+            .count()
+    }
+
+    pub fn stalls(&self) -> u64 {
+        self.stalls
+    }
+}
+
+// Her yuva için ayrı bir kopyası tutulan dinamik kaynak (uniform, örnek
+// buffer'ları). Bir kareye yazılan veri, GPU önceki kareleri okurken
+// onların kopyalarının üzerine yazılmaz.
+pub struct PerFrame<T> {
+    items: Vec<T>,
+}
+
+impl<T> PerFrame<T> {
+    pub fn new(count: usize, create: impl FnMut(usize) -> T) -> Self {
+        Self {
+            items: (0..count).map(create).collect(),
+        }
+    }
+
+    pub fn get(&self, frame: FrameContext) -> &T {
+        &self.items[frame.slot]
+    }
+
+    pub fn get_mut(&mut self, frame: FrameContext) -> &mut T {
+        &mut self.items[frame.slot]
+    }
+}
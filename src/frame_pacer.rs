@@ -0,0 +1,212 @@
+use crate::settings::Pacing;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+const HISTORY: usize = 120;
+// Bu süreden uzun aralıklar boşta beklemedir (OnDemand, simge durumu), ölçüme girmez
+const MAX_INTERVAL: f64 = 0.1;
+// Zamanlayıcı ve sürücü gecikmesi için kare başlangıcından bırakılan pay
+const MARGIN: Duration = Duration::from_micros(1500);
+
+// Bir aralığın periyodun en yakın katına uzaklığı, periyot cinsinden
+fn off_grid(interval: f64, period: f64) -> f64 {
+    let ratio = interval / period;
+    (ratio - ratio.round()).abs()
+}
+
+fn percentile(samples: &VecDeque<f64>, fraction: f64) -> Option<f64> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<f64> = samples.iter().copied().collect();
+    sorted.sort_by(f64::total_cmp);
+    Some(sorted[((sorted.len() - 1) as f64 * fraction) as usize])
+}
+
+// Sunumdan sunuma geçen süreleri ölçer, ekranın yenileme hızını ve VRR
+// davranışını tahmin eder ve Continuous politikasında bir sonraki karenin
+// ne zaman başlaması gerektiğini hesaplar. wgpu sunum zaman damgası
+// vermediği için ölçümler `present()` çağrısının CPU tarafındaki anıdır;
+// vsync açıkken bloklayan acquire sayesinde bunlar dikey boşluklara hizalanır.
+pub struct FramePacer {
+    // İşletim sisteminin bildirdiği yenileme hızı; yoksa sadece ölçüme güvenilir
+    monitor_hz: Option<f64>,
+    // Present mode sunumları dikey boşluğa bağlıyor mu
+    synced: bool,
+    frame_start: Option<Instant>,
+    acquire_wait: Duration,
+    last_present: Option<Instant>,
+    // Saniye cinsinden sunum aralıkları ve acquire beklemesi hariç kare iş süreleri
+    intervals: VecDeque<f64>,
+    work: VecDeque<f64>,
+}
+
+impl FramePacer {
+    pub fn new(monitor_hz: Option<f64>, present_mode: wgpu::PresentMode) -> Self {
+        let mut pacer = Self {
+            monitor_hz,
+            synced: false,
+            frame_start: None,
+            acquire_wait: Duration::ZERO,
+            last_present: None,
+            intervals: VecDeque::with_capacity(HISTORY),
+            work: VecDeque::with_capacity(HISTORY),
+        };
+        pacer.reset(present_mode);
+        pacer
+    }
+
+    pub fn synced(&self) -> bool {
+        self.synced
+    }
+
+    pub fn set_monitor_hz(&mut self, hz: Option<f64>) {
+        if self.monitor_hz != hz {
+            self.monitor_hz = hz;
+            self.intervals.clear();
+        }
+    }
+
+    // Present mode değişince eski aralıklar yeni davranışı yansıtmaz
+    pub fn reset(&mut self, present_mode: wgpu::PresentMode) {
+        self.synced = matches!(
+            present_mode,
+            wgpu::PresentMode::Fifo | wgpu::PresentMode::FifoRelaxed | wgpu::PresentMode::AutoVsync
+        );
+        self.last_present = None;
+        self.intervals.clear();
+        self.work.clear();
+    }
+
+    pub fn begin_frame(&mut self) {
+        self.frame_start = Some(Instant::now());
+        self.acquire_wait = Duration::ZERO;
+    }
+
+    pub fn acquired(&mut self, wait: Duration) {
+        self.acquire_wait += wait;
+    }
+
+    pub fn presented(&mut self) {
+        let now = Instant::now();
+        if let Some(last) = self.last_present.replace(now) {
+            let interval = (now - last).as_secs_f64();
+            if interval < MAX_INTERVAL {
+                push(&mut self.intervals, interval);
+            }
+        }
+        if let Some(start) = self.frame_start.take() {
+            push(&mut self.work, (now - start).saturating_sub(self.acquire_wait).as_secs_f64());
+        }
+    }
+
+    // Vsync açıkken sunumlar yenileme periyodunun katlarına düşer; en kısa
+    // tutarlı aralık bir periyottur. Aralıkların çoğu bu ızgaraya oturmuyorsa
+    // (VRR ya da düzensiz yük) ölçüm kullanılmaz.
+    fn measured_period(&self) -> Option<f64> {
+        if !self.synced || self.intervals.len() < 30 {
+            return None;
+        }
+        let period = percentile(&self.intervals, 0.1)?;
+        let on_grid = self
+            .intervals
+            .iter()
+            .filter(|interval| off_grid(**interval, period) < 0.1)
+            .count();
+        (on_grid * 2 >= self.intervals.len()).then_some(period)
+    }
+
+    pub fn refresh_period(&self) -> Option<Duration> {
+        self.measured_period()
+            .or(self.monitor_hz.map(|hz| 1.0 / hz))
+            .map(Duration::from_secs_f64)
+    }
+
+    // VRR ekranda, yenileme hızına yetişemeyen kareler periyodun katına
+    // yuvarlanmadan hemen gösterilir. Sabit yenilemeli ekranda aynı kareler
+    // bir sonraki dikey boşluğu bekler. Tespit için uygulamanın ara sıra
+    // yenileme hızının altına düşmesi gerekir; o zamana kadar sabit varsayılır.
+    pub fn vrr_detected(&self) -> bool {
+        let Some(period) = self.monitor_hz.map(|hz| 1.0 / hz) else {
+            return false;
+        };
+        if !self.synced {
+            return false;
+        }
+        let slow: Vec<f64> = self
+            .intervals
+            .iter()
+            .copied()
+            .filter(|interval| *interval > period * 1.1)
+            .collect();
+        let off = slow.iter().filter(|interval| off_grid(**interval, period) > 0.15).count();
+        slow.len() >= 20 && off * 2 > slow.len()
+    }
+
+    // Yavaş karelere göre pay bırakmak için iş süresinin 90. yüzdeliği
+    pub fn work_estimate(&self) -> Duration {
+        Duration::from_secs_f64(percentile(&self.work, 0.9).unwrap_or(0.0))
+    }
+
+    fn resolve(&self, pacing: Pacing) -> Pacing {
+        match pacing {
+            Pacing::Auto if self.vrr_detected() => Pacing::Vrr,
+            Pacing::Auto => Pacing::Fixed,
+            other => other,
+        }
+    }
+
+    // Bir sonraki karenin başlama zamanı; None ise hemen başlanır
+    pub fn next_frame_start(&self, pacing: Pacing) -> Option<Instant> {
+        let last = self.last_present?;
+        let period = self.refresh_period()?;
+        let budget = self.work_estimate() + MARGIN;
+        match self.resolve(pacing) {
+            Pacing::Off | Pacing::Auto => None,
+            // Kare, sunumun yetişeceği ilk dikey boşluktan iş süresi kadar önce
+            // başlar; girdi daha geç okunduğu için gecikme azalır
+            Pacing::Fixed if self.synced => {
+                let frames = (budget.as_secs_f64() / period.as_secs_f64()).ceil().max(1.0);
+                let vblank = last + period.mul_f64(frames);
+                Some(vblank.checked_sub(budget).unwrap_or(last))
+            }
+            Pacing::Fixed => None,
+            // Ekran sunumu beklediği için aralık en az bir periyot ve en yavaş
+            // karelerin süresi kadar tutulur; sabit aralık takılmayı önler
+            Pacing::Vrr => {
+                let interval = period.max(budget);
+                Some(last + interval - budget)
+            }
+        }
+    }
+
+    pub fn summary(&self, pacing: Pacing) -> String {
+        let hz = |period: Option<Duration>| {
+            period.map_or("?".to_string(), |period| format!("{:.2} Hz", 1.0 / period.as_secs_f64()))
+        };
+        let average = if self.intervals.is_empty() {
+            0.0
+        } else {
+            self.intervals.iter().sum::<f64>() / self.intervals.len() as f64
+        };
+        format!(
+            "zamanlama: {pacing} ({})  vsync: {}\n\
+             monitör: {}  ölçülen: {}  VRR: {}\n\
+             sunum aralığı ort {:.2} ms  iş süresi p90 {:.2} ms",
+            self.resolve(pacing),
+            if self.synced { "açık" } else { "kapalı" },
+            self.monitor_hz.map_or("?".to_string(), |hz| format!("{hz:.2} Hz")),
+            hz(self.measured_period().map(Duration::from_secs_f64)),
+            if self.vrr_detected() { "tespit edildi" } else { "yok" },
+            average * 1000.0,
+            self.work_estimate().as_secs_f64() * 1000.0,
+        )
+    }
+}
+
+fn push(samples: &mut VecDeque<f64>, value: f64) {
+    if samples.len() == HISTORY {
+        samples.pop_front();
+    }
+    samples.push_back(value);
+}
@@ -0,0 +1,371 @@
+use rand::Rng;
+use rand::rngs::SmallRng;
+use std::time::Instant;
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 256;
+// Tek boyuttaki grup sınırı (65535) altındaki en büyük ikinin kuvveti
+const MAX_GROUPS_X: u32 = 32768;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct StageRaw {
+    j: u32,
+    k: u32,
+    count: u32,
+    row: u32,
+}
+
+// Sıralanacak uzunluk ikinin kuvveti olmalı; fazlası `u32::MAX` anahtarla doldurulur
+pub fn padded_len(count: u32) -> u32 {
+    count.max(1).next_power_of_two()
+}
+
+// f32'yi sıralaması korunan u32'ye çevirir: negatiflerin tüm bitleri,
+// pozitiflerin işaret biti çevrilir. Arkadan öne (azalan derinlik) için `!key`.
+pub fn float_key(value: f32) -> u32 {
+    let bits = value.to_bits();
+    if bits & 0x8000_0000 != 0 { !bits } else { bits | 0x8000_0000 }
+}
+
+// Bitonik ağın adımları sırayla: her `k` blok boyutu için `j = k/2 .. 1`
+fn stages(count: u32) -> Vec<StageRaw> {
+    let groups = (count / WORKGROUP_SIZE).max(1);
+    let row = groups.min(MAX_GROUPS_X) * WORKGROUP_SIZE;
+    let mut stages = Vec::new();
+    let mut k = 2;
+    while k <= count {
+        let mut j = k / 2;
+        while j > 0 {
+            stages.push(StageRaw { j, k, count, row });
+            j /= 2;
+        }
+        k *= 2;
+    }
+    stages
+}
+
+// Storage buffer'daki u32 anahtar/değer çiftlerini GPU'da bitonik ağla
+// sıralar (parçacık derinliği, saydam nesne sırası). Sıralama kararlı değildir.
+pub struct GpuSorter {
+    pipeline: wgpu::ComputePipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+    stage_stride: u32,
+    // Son kullanılan uzunluk için adım parametreleri; her adım ayrı dinamik ofsette
+    stages: Option<(u32, wgpu::Buffer, u32)>,
+}
+
+impl GpuSorter {
+    pub fn new(device: &wgpu::Device) -> Result<Self, String> {
+        let storage = |binding: u32| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Sort Bind Group Layout"),
+            entries: &[
+                storage(0),
+                storage(1),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<StageRaw>() as u64),
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Sort Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout)?;
+        let alignment = device.limits().min_uniform_buffer_offset_alignment;
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group_layout,
+            stage_stride: (std::mem::size_of::<StageRaw>() as u32).next_multiple_of(alignment),
+            stages: None,
+        })
+    }
+
+    fn create_pipeline(device: &wgpu::Device, layout: &wgpu::PipelineLayout) -> Result<wgpu::ComputePipeline, String> {
+        let shader = crate::shaders::create_module(device, "bitonic_sort.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Sort Pipeline"),
+                layout: Some(layout),
+                module: &shader,
+                entry_point: Some("cs_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout)?;
+        Ok(())
+    }
+
+    // Uzunluk değişmedikçe adım buffer'ı yeniden kullanılır
+    fn prepare_stages(&mut self, device: &wgpu::Device, count: u32) -> u32 {
+        if let Some((cached, _, stages)) = &self.stages
+            && *cached == count
+        {
+            return *stages;
+        }
+        let mut raw = Vec::new();
+        for stage in stages(count) {
+            let mut bytes = bytemuck::bytes_of(&stage).to_vec();
+            bytes.resize(self.stage_stride as usize, 0);
+            raw.extend_from_slice(&bytes);
+        }
+        let stages = (raw.len() / self.stage_stride as usize) as u32;
+        // Tek elemanlı dizide adım yok, ama bağlama için boş olmayan buffer gerekir
+        raw.resize(raw.len().max(self.stage_stride as usize), 0);
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sort Stages"),
+            contents: &raw,
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        self.stages = Some((count, buffer, stages));
+        stages
+    }
+
+    // `keys` ve `values` en az `count` u32 tutan STORAGE buffer'lar; `count`
+    // ikinin kuvveti olmalı (`padded_len`). Tüm adımlar tek compute pass'e kaydedilir.
+    pub fn sort(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        keys: &wgpu::Buffer,
+        values: &wgpu::Buffer,
+        count: u32,
+    ) {
+        assert!(count.is_power_of_two(), "sıralama uzunluğu ikinin kuvveti olmalı: {count}");
+        let stage_count = self.prepare_stages(device, count);
+        let Some((_, stages, _)) = &self.stages else { return };
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Sort Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: keys.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: values.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                        buffer: stages,
+                        offset: 0,
+                        size: wgpu::BufferSize::new(std::mem::size_of::<StageRaw>() as u64),
+                    }),
+                },
+            ],
+        });
+
+        let groups = (count / WORKGROUP_SIZE).max(1);
+        let groups_x = groups.min(MAX_GROUPS_X);
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Sort Pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        for stage in 0..stage_count {
+            pass.set_bind_group(0, &bind_group, &[stage * self.stage_stride]);
+            pass.dispatch_workgroups(groups_x, groups / groups_x, 1);
+        }
+    }
+
+    // Rastgele f32 derinlikleri arkadan öne GPU'da sıralar ve sonucu CPU'daki
+    // referans sıralamayla karşılaştırır. GPU'yu bekler; sadece konsoldan çağrılır.
+    pub fn self_test(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        rng: &mut SmallRng,
+        count: u32,
+    ) -> Result<String, String> {
+        let padded = padded_len(count);
+        let depths: Vec<f32> = (0..count).map(|_| rng.random_range(-100.0..100.0)).collect();
+        let mut keys: Vec<u32> = depths.iter().map(|depth| !float_key(*depth)).collect();
+        keys.resize(padded as usize, u32::MAX);
+        let values: Vec<u32> = (0..padded).collect();
+
+        let usage = wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC;
+        let key_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sort Test Keys"),
+            contents: bytemuck::cast_slice(&keys),
+            usage,
+        });
+        let value_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Sort Test Values"),
+            contents: bytemuck::cast_slice(&values),
+            usage,
+        });
+        let size = padded as u64 * 4;
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sort Test Readback"),
+            size: size * 2,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Sort Test Encoder"),
+        });
+        self.sort(device, &mut encoder, &key_buffer, &value_buffer, padded);
+        encoder.copy_buffer_to_buffer(&key_buffer, 0, &readback, 0, size);
+        encoder.copy_buffer_to_buffer(&value_buffer, 0, &readback, size, size);
+        let start = Instant::now();
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::PollType::Wait).map_err(|e| e.to_string())?;
+        let gpu_ms = start.elapsed().as_secs_f64() * 1000.0;
+        let (gpu_keys, gpu_values) = {
+            let data = slice.get_mapped_range();
+            let words: &[u32] = bytemuck::cast_slice(&data);
+            (words[..padded as usize].to_vec(), words[padded as usize..].to_vec())
+        };
+        readback.unmap();
+
+        let start = Instant::now();
+        let mut reference = keys.clone();
+        reference.sort_unstable();
+        let cpu_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+        if let Some(index) = gpu_keys.iter().zip(&reference).position(|(gpu, cpu)| gpu != cpu) {
+            return Err(format!(
+                "anahtar uyuşmazlığı, indeks {index}: GPU {:#010x}, CPU {:#010x}",
+                gpu_keys[index], reference[index]
+            ));
+        }
+        // Sıralama kararlı olmadığından değerler sadece anahtarlarıyla tutarlılık
+        // ve her indeksin tam bir kez görünmesi açısından kontrol edilir
+        let mut seen = vec![false; padded as usize];
+        for (key, value) in gpu_keys.iter().zip(&gpu_values) {
+            let slot = seen.get_mut(*value as usize).ok_or(format!("geçersiz değer: {value}"))?;
+            if std::mem::replace(slot, true) {
+                return Err(format!("değer iki kez görüldü: {value}"));
+            }
+            if keys[*value as usize] != *key {
+                return Err(format!("değer {value} yanlış anahtarla taşınmış"));
+            }
+        }
+
+        Ok(format!(
+            "{count} eleman ({padded} dolgulu) doğru sıralandı: GPU {gpu_ms:.2} ms (gönderim+okuma), CPU referans {cpu_ms:.2} ms"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    // `bitonic_sort.wgsl`'in bir adımını her iş parçacığı için CPU'da çalıştırır
+    fn run_stage(stage: &StageRaw, keys: &mut [u32], values: &mut [u32]) {
+        for i in 0..stage.count {
+            let partner = i ^ stage.j;
+            if partner <= i {
+                continue;
+            }
+            let ascending = (i & stage.k) == 0;
+            let (i, partner) = (i as usize, partner as usize);
+            if (keys[i] > keys[partner]) == ascending {
+                keys.swap(i, partner);
+                values.swap(i, partner);
+            }
+        }
+    }
+
+    #[test]
+    fn float_key_preserves_order() {
+        let values = [
+            f32::NEG_INFINITY,
+            -f32::MAX,
+            -1.5,
+            -f32::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f32::MIN_POSITIVE,
+            1.5,
+            f32::MAX,
+            f32::INFINITY,
+        ];
+        for pair in values.windows(2) {
+            assert!(
+                float_key(pair[0]) < float_key(pair[1]),
+                "{} ve {} sırası korunmadı",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn padded_len_is_next_power_of_two() {
+        for (count, padded) in [(0, 1), (1, 1), (2, 2), (3, 4), (256, 256), (257, 512), (1 << 24, 1 << 24)] {
+            assert_eq!(padded_len(count), padded, "adet {count}");
+        }
+    }
+
+    #[test]
+    fn stages_sort_on_cpu() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        for count in [1, 2, 4, 256, 1024, 4096] {
+            let mut keys: Vec<u32> = (0..count).map(|_| rng.random_range(0..64)).collect();
+            let mut values: Vec<u32> = (0..count).collect();
+            let original = keys.clone();
+            for stage in stages(count) {
+                assert_eq!(stage.row % WORKGROUP_SIZE, 0);
+                run_stage(&stage, &mut keys, &mut values);
+            }
+            assert!(keys.is_sorted(), "adet {count} sıralanmadı");
+            for (key, value) in keys.iter().zip(&values) {
+                assert_eq!(original[*value as usize], *key);
+            }
+            let mut seen = values.clone();
+            seen.sort_unstable();
+            assert!(seen.iter().copied().eq(0..count));
+        }
+    }
+
+    // Adaptör yoksa (ör. GPU'suz CI) atlanır
+    #[test]
+    fn self_test_on_gpu() {
+        let instance = wgpu::Instance::default();
+        let Ok(adapter) = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default())) else {
+            eprintln!("GPU adaptörü yok, test atlandı");
+            return;
+        };
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+            .expect("GPU cihazı oluşturulamadı");
+        let mut sorter = GpuSorter::new(&device).expect("sıralama pipeline'ı oluşturulamadı");
+        let mut rng = SmallRng::seed_from_u64(0);
+        for count in [1, 1000, 65536] {
+            sorter.self_test(&device, &queue, &mut rng, count).expect("GPU sıralaması CPU referansıyla uyuşmadı");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use game_api::{GameApi, GameCube, GameFrame, GameInput, MAX_CUBES};
+use libloading::Library;
+use std::error::Error;
+use std::ffi::c_void;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// `GAME_LIB` ortam değişkeni verilmezse `cargo build -p game` çıktısı kullanılır
+fn default_library_path() -> PathBuf {
+    let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join(profile)
+        .join(libloading::library_filename("game"))
+}
+
+struct LoadedLibrary {
+    api: GameApi,
+    // `api` içindeki fonksiyon işaretçileri bu kütüphane açık kaldığı sürece geçerli
+    _library: Library,
+    shadow_path: PathBuf,
+}
+
+impl LoadedLibrary {
+    // Derleyici orijinal dosyanın üzerine yazabilsin diye kopyası yüklenir.
+    // Her yüklemede farklı isim kullanılır, aksi halde dlopen önbellekteki eski kopyayı döndürür.
+    fn load(path: &Path, generation: u32) -> Result<Self, Box<dyn Error>> {
+        let file_name = path.file_name().ok_or("geçersiz kütüphane yolu")?;
+        let shadow_path = std::env::temp_dir().join(format!(
+            "{}-{}-{}",
+            std::process::id(),
+            generation,
+            file_name.to_string_lossy()
+        ));
+        std::fs::copy(path, &shadow_path)?;
+
+        // SAFETY: kütüphane bu depodaki `game` crate'inden derlenir ve
+        // `game_api` ile aynı ABI'yi kullanır
+        let library = unsafe { Library::new(&shadow_path)? };
+        let api = unsafe { **library.get::<*const GameApi>(game_api::API_SYMBOL)? };
+
+        Ok(Self {
+            api,
+            _library: library,
+            shadow_path,
+        })
+    }
+}
+
+impl Drop for LoadedLibrary {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.shadow_path);
+    }
+}
+
+pub struct GameLibrary {
+    path: PathBuf,
+    modified: SystemTime,
+    generation: u32,
+    library: LoadedLibrary,
+    state: *mut c_void,
+    start: Instant,
+    last_update: Instant,
+    last_poll: Instant,
+    frame: Box<GameFrame>,
+}
+
+impl GameLibrary {
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let path = std::env::var_os("GAME_LIB")
+            .map(PathBuf::from)
+            .unwrap_or_else(default_library_path);
+        let modified = std::fs::metadata(&path)?.modified()?;
+        let library = LoadedLibrary::load(&path, 0)?;
+        let state = (library.api.create)();
+        let now = Instant::now();
+
+        log::info!("Oyun kütüphanesi yüklendi: {}", path.display());
+        Ok(Self {
+            path,
+            modified,
+            generation: 0,
+            library,
+            state,
+            start: now,
+            last_update: now,
+            last_poll: now,
+            frame: Box::new(GameFrame {
+                clear_color: [0.0; 4],
+                cube_count: 0,
+                cubes: [GameCube::default(); MAX_CUBES],
+            }),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Dosya değiştiyse yeniden yükler. Yeni sürümün durum düzeni aynıysa
+    // mevcut durum korunur.
+    pub fn reload(&mut self) -> Result<(), Box<dyn Error>> {
+        self.modified = std::fs::metadata(&self.path)?.modified()?;
+        let library = LoadedLibrary::load(&self.path, self.generation + 1)?;
+        self.generation += 1;
+
+        if library.api.state_version == self.library.api.state_version {
+            log::info!("Oyun kütüphanesi yeniden yüklendi, durum korundu");
+        } else {
+            log::warn!(
+                "Durum sürümü değişti ({} -> {}), durum sıfırlanıyor",
+                self.library.api.state_version,
+                library.api.state_version
+            );
+            (self.library.api.destroy)(self.state);
+            self.state = (library.api.create)();
+        }
+        // Eski kütüphane burada kapatılır
+        self.library = library;
+        Ok(())
+    }
+
+    fn poll_changes(&mut self) {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return;
+        }
+        self.last_poll = Instant::now();
+
+        let changed = std::fs::metadata(&self.path)
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| modified != self.modified);
+        // Derleme sürerken dosya yarım olabilir; derleyici bitirince
+        // değişen mtime yüklemeyi yeniden tetikler
+        if changed && let Err(e) = self.reload() {
+            log::warn!("Oyun kütüphanesi yüklenemedi: {e}");
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.poll_changes();
+
+        let now = Instant::now();
+        let input = GameInput {
+            dt: (now - self.last_update).as_secs_f32(),
+            time: (now - self.start).as_secs_f32(),
+        };
+        self.last_update = now;
+        (self.library.api.update)(self.state, &input);
+    }
+
+    pub fn render(&mut self, clear_color: [f32; 4]) -> &GameFrame {
+        self.frame.clear_color = clear_color;
+        self.frame.cube_count = 0;
+        (self.library.api.render)(self.state, &mut self.frame);
+        &self.frame
+    }
+}
+
+impl Drop for GameLibrary {
+    fn drop(&mut self) {
+        (self.library.api.destroy)(self.state);
+    }
+}
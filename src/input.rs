@@ -0,0 +1,66 @@
+use winit::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
+use winit::event::WindowEvent;
+
+// İmlecin güncel durumu. winit konumları fiziksel piksel
+// verir; burada ölçek faktörü ve pencerenin en son bildirilen boyutuyla
+// mantıksal ve normalize koordinatlara çevrilir. Boyut, yüzeyin yeniden
+// yapılandırılmasını beklemeden `Resized` olayıyla güncellenir.
+pub struct InputState {
+    scale_factor: f64,
+    size: PhysicalSize<u32>,
+    cursor: Option<PhysicalPosition<f64>>,
+}
+
+impl InputState {
+    pub fn new(scale_factor: f64, size: PhysicalSize<u32>) -> Self {
+        Self {
+            scale_factor,
+            size,
+            cursor: None,
+        }
+    }
+
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::Resized(size) if size.width > 0 && size.height > 0 => self.size = *size,
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => self.scale_factor = *scale_factor,
+            WindowEvent::CursorMoved { position, .. } => self.cursor = Some(*position),
+            WindowEvent::CursorLeft { .. } => self.cursor = None,
+            _ => {}
+        }
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    pub fn aspect(&self) -> f32 {
+        self.size.width as f32 / self.size.height.max(1) as f32
+    }
+
+    pub fn cursor_physical(&self) -> Option<PhysicalPosition<f64>> {
+        self.cursor
+    }
+
+    pub fn cursor_logical(&self) -> Option<LogicalPosition<f64>> {
+        self.cursor.map(|cursor| cursor.to_logical(self.scale_factor))
+    }
+
+    // Pencere içinde 0..1 aralığında, sol üst köşe orijin
+    pub fn normalize(&self, position: PhysicalPosition<f64>) -> [f64; 2] {
+        [
+            position.x / self.size.width.max(1) as f64,
+            position.y / self.size.height.max(1) as f64,
+        ]
+    }
+
+    pub fn cursor_normalized(&self) -> Option<[f64; 2]> {
+        self.cursor.map(|cursor| self.normalize(cursor))
+    }
+
+    // Normalize cihaz koordinatları (-1..1, y yukarı)
+    pub fn to_ndc(&self, position: PhysicalPosition<f64>) -> [f32; 2] {
+        let [x, y] = self.normalize(position);
+        [(x * 2.0 - 1.0) as f32, (1.0 - y * 2.0) as f32]
+    }
+}
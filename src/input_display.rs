@@ -0,0 +1,302 @@
+use crate::State;
+use crate::actions::Action;
+use crate::builder::AppBuilder;
+use crate::console::Command;
+use crate::overlay::Overlay;
+use crate::plugin::Plugin;
+use crate::shapes::{ShapeRenderer, ShapeStyle};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::keyboard::{Key, KeyCode, ModifiersState, NamedKey, PhysicalKey};
+
+const TRAIL_DURATION: Duration = Duration::from_millis(400);
+const CLICK_DURATION: Duration = Duration::from_millis(500);
+const KEY_DURATION: Duration = Duration::from_millis(1500);
+const MAX_TRAIL: usize = 128;
+const MAX_KEYS: usize = 8;
+
+struct Click {
+    position: [f32; 2],
+    button: MouseButton,
+    time: Instant,
+}
+
+// Ekranda gösterilen bir tuş basışı; aynı tuşa art arda basılınca sayaç artar
+struct Keystroke {
+    label: String,
+    key: PhysicalKey,
+    count: u32,
+    time: Instant,
+    held: bool,
+}
+
+// Demo ve ekran kayıtları için girdi görselleştirmesi: imlecin izi, tıklama
+// halkaları ve basılan tuşlar ekranın altında gösterilir. Olaylar egui veya
+// konsol tüketmeden önce kaydedilir, böylece girdi hattında nerede
+// kaybolduklarını görmek için de kullanılabilir. İsteğe bağlı bir eklentidir;
+// F7 veya `inputs` komutuyla açılır.
+#[derive(Default)]
+pub struct InputDisplay {
+    enabled: bool,
+    trail: VecDeque<([f32; 2], Instant)>,
+    clicks: VecDeque<Click>,
+    keys: VecDeque<Keystroke>,
+    // İz zamanla boşaldığı için tıklama konumu ayrıca tutulur
+    cursor: Option<[f32; 2]>,
+    modifiers: ModifiersState,
+}
+
+impl Plugin for InputDisplay {
+    fn build(&mut self, builder: &mut AppBuilder) {
+        // Diğer kısayollar gibi konsol açıkken veya egui'ye yazılırken çalışmaz
+        builder.add_binding(KeyCode::F7, Action::Command("inputs"));
+        builder.add_command(
+            "inputs",
+            Command {
+                help: "inputs on|off - imleç izini, tıklamaları ve basılan tuşları ekranda gösterir (F7)",
+                args: &["on", "off"],
+                run: inputs,
+            },
+        );
+    }
+
+    fn on_event(&mut self, state: &mut State, event: &WindowEvent) -> bool {
+        state.dirty |= self.handle_event(event);
+        false
+    }
+
+    fn on_update(&mut self, state: &mut State, _dt: f32) {
+        self.draw(
+            &mut state.overlay,
+            &mut state.shapes,
+            state.input_state.scale_factor() as f32,
+            state.size.width as f32,
+            state.size.height as f32,
+        );
+    }
+
+    // Kare başında temizlenen yeniden çizim işareti solma sürdükçe tekrar konur
+    fn on_render(&mut self, state: &mut State, _encoder: &mut wgpu::CommandEncoder, _view: &wgpu::TextureView) {
+        state.dirty |= self.is_animating();
+    }
+}
+
+fn inputs(state: &mut State, args: &[&str]) -> Result<String, String> {
+    let display = state.plugin_mut::<InputDisplay>()?;
+    display.enabled = match args.first() {
+        Some(&"on") => true,
+        Some(&"off") => false,
+        None => !display.enabled,
+        _ => return Err("kullanım: inputs on|off".into()),
+    };
+    Ok(format!("girdi gösterimi {}", if display.enabled { "açık" } else { "kapalı" }))
+}
+
+impl InputDisplay {
+    // Gösterilen bir şey değiştiyse true
+    fn handle_event(&mut self, event: &WindowEvent) -> bool {
+        if !self.enabled {
+            // Kapalıyken kaçırılan bırakma olayları tuşları basılı bırakmasın
+            self.clear();
+            return false;
+        }
+        let now = Instant::now();
+        match event {
+            WindowEvent::CursorMoved { position, .. } => {
+                let position = [position.x as f32, position.y as f32];
+                self.cursor = Some(position);
+                if self.trail.len() == MAX_TRAIL {
+                    self.trail.pop_front();
+                }
+                self.trail.push_back((position, now));
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor = None;
+                self.trail.clear();
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button,
+                ..
+            } => {
+                if let Some(position) = self.cursor {
+                    self.clicks.push_back(Click {
+                        position,
+                        button: *button,
+                        time: now,
+                    });
+                }
+            }
+            // Değiştiriciler bir sonraki tuşun etiketinde görünür
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+                return false;
+            }
+            WindowEvent::KeyboardInput { event, .. } => {
+                if event.state == ElementState::Released {
+                    for key in self.keys.iter_mut().filter(|key| key.key == event.physical_key) {
+                        key.held = false;
+                        key.time = now;
+                    }
+                    return true;
+                }
+                if event.repeat {
+                    return false;
+                }
+                let label = self.label(&event.logical_key, event.physical_key);
+                if let Some(last) = self.keys.back_mut()
+                    && last.label == label
+                {
+                    last.count += 1;
+                    last.time = now;
+                    last.held = true;
+                    return true;
+                }
+                if self.keys.len() == MAX_KEYS {
+                    self.keys.pop_front();
+                }
+                self.keys.push_back(Keystroke {
+                    label,
+                    key: event.physical_key,
+                    count: 1,
+                    time: now,
+                    held: true,
+                });
+            }
+            WindowEvent::Focused(false) => {
+                for key in &mut self.keys {
+                    key.held = false;
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.trail.clear();
+        self.clicks.clear();
+        self.keys.clear();
+        self.cursor = None;
+    }
+
+    // Solan bir şey kaldıkça kareler çizilmeye devam etmeli
+    fn is_animating(&self) -> bool {
+        !self.trail.is_empty() || !self.clicks.is_empty() || self.keys.iter().any(|key| !key.held)
+    }
+
+    // Değiştirici tuşlar tek başına kendi adlarıyla, diğerleri basılı değiştiricilerle gösterilir
+    fn label(&self, key: &Key, physical: PhysicalKey) -> String {
+        let name = match key {
+            Key::Character(text) if text.trim().is_empty() => "Space".to_string(),
+            Key::Character(text) => text.to_uppercase(),
+            Key::Named(named) => format!("{named:?}"),
+            _ => match physical {
+                PhysicalKey::Code(code) => format!("{code:?}"),
+                PhysicalKey::Unidentified(_) => "?".to_string(),
+            },
+        };
+        if matches!(
+            key,
+            Key::Named(NamedKey::Control | NamedKey::Shift | NamedKey::Alt | NamedKey::Super)
+        ) {
+            return name;
+        }
+        let mut label = String::new();
+        for (modifier, prefix) in [
+            (ModifiersState::CONTROL, "Ctrl+"),
+            (ModifiersState::ALT, "Alt+"),
+            (ModifiersState::SHIFT, "Shift+"),
+            (ModifiersState::SUPER, "Super+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                label.push_str(prefix);
+            }
+        }
+        label + &name
+    }
+
+    fn draw(&mut self, overlay: &mut Overlay, shapes: &mut ShapeRenderer, scale: f32, width: f32, height: f32) {
+        if !self.enabled {
+            return;
+        }
+        let now = Instant::now();
+        self.trail.retain(|(_, time)| now - *time < TRAIL_DURATION);
+        self.clicks.retain(|click| now - click.time < CLICK_DURATION);
+        self.keys.retain(|key| key.held || now - key.time < KEY_DURATION);
+        // 0 yeni, 1 süresi dolmuş
+        let age = |time: Instant, duration: Duration| ((now - time).as_secs_f32() / duration.as_secs_f32()).min(1.0);
+
+        let palette = overlay.palette;
+        // İz: noktalar arası hızlı harekette boşluk kalmasın diye ara daireler eklenir
+        let mut previous: Option<[f32; 2]> = None;
+        for &(point, time) in &self.trail {
+            let fade = 1.0 - age(time, TRAIL_DURATION);
+            let radius = 6.0 * scale * fade;
+            let style = ShapeStyle::fill(palette.accent.with_alpha(0.6 * fade));
+            let from = previous.unwrap_or(point);
+            let distance = (point[0] - from[0]).hypot(point[1] - from[1]);
+            let steps = (distance / (radius * 0.75).max(1.0)).ceil().clamp(1.0, 32.0) as usize;
+            for step in 1..=steps {
+                let t = step as f32 / steps as f32;
+                let x = from[0] + (point[0] - from[0]) * t;
+                let y = from[1] + (point[1] - from[1]) * t;
+                shapes.circle(x, y, radius, style);
+            }
+            previous = Some(point);
+        }
+        // Ekran kaydında sistem imleci görünmeyebilir
+        if let Some([x, y]) = self.cursor {
+            shapes.ring(x, y, 10.0 * scale, 2.0 * scale, ShapeStyle::fill(palette.accent));
+        }
+
+        for click in &self.clicks {
+            let t = age(click.time, CLICK_DURATION);
+            let color = match click.button {
+                MouseButton::Left => palette.accent,
+                MouseButton::Right => palette.warn,
+                MouseButton::Middle => palette.good,
+                _ => palette.text_dim,
+            };
+            let [x, y] = click.position;
+            let radius = (8.0 + 20.0 * t) * scale;
+            shapes.ring(x, y, radius, 3.0 * scale, ShapeStyle::fill(color.with_alpha(1.0 - t)));
+        }
+
+        // Tuşlar alt ortada, eskiden yeniye soldan sağa
+        let [char_w, line_height] = overlay.char_size();
+        let padding = 8.0 * scale;
+        let gap = 6.0 * scale;
+        let labels: Vec<String> = self
+            .keys
+            .iter()
+            .map(|key| match key.count {
+                1 => key.label.clone(),
+                count => format!("{} x{count}", key.label),
+            })
+            .collect();
+        let widths: Vec<f32> = labels
+            .iter()
+            .map(|label| label.chars().count() as f32 * char_w + padding * 2.0)
+            .collect();
+        let total = widths.iter().sum::<f32>() + gap * widths.len().saturating_sub(1) as f32;
+        let pill_height = line_height + padding * 2.0;
+        let y = height - pill_height - 32.0 * scale;
+        let mut x = (width - total) * 0.5;
+        for ((key, label), w) in self.keys.iter().zip(&labels).zip(&widths) {
+            let fade = if key.held {
+                1.0
+            } else {
+                1.0 - age(key.time, KEY_DURATION)
+            };
+            let mut style = ShapeStyle::fill(palette.panel.with_alpha(0.85 * fade));
+            if key.held {
+                style = style.with_border(palette.accent, 2.0 * scale);
+            }
+            shapes.rounded_rect(x, y, *w, pill_height, 6.0 * scale, style);
+            overlay.text(x + padding, y + padding, label, palette.text.with_alpha(fade));
+            x += w + gap;
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::assets::AssetManager;
+use crate::color::Color;
+use crate::scene::{EntityId, Light, Material, Scene};
+use glam::{EulerRot, Quat};
+
+pub fn show(context: &egui::Context, scene: &mut Scene, assets: &AssetManager) {
+    egui::SidePanel::left("hierarchy")
+        .default_width(200.0)
+        .show(context, |ui| {
+            ui.heading("Hiyerarşi");
+            ui.horizontal(|ui| {
+                if ui.button("+ Varlık").clicked() {
+                    let id = scene.spawn("Yeni Varlık", None);
+                    scene.selected = Some(id);
+                }
+                if ui.button("+ Küp").clicked() {
+                    scene.selected = Some(scene.spawn_cube());
+                }
+            });
+            ui.separator();
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let roots: Vec<EntityId> = scene.roots().collect();
+                for id in roots {
+                    hierarchy_node(ui, scene, id);
+                }
+            });
+        });
+
+    egui::SidePanel::right("inspector")
+        .default_width(260.0)
+        .show(context, |ui| {
+            ui.heading("Denetçi");
+            ui.separator();
+            match scene.selected {
+                Some(id) => inspector(ui, scene, assets, id),
+                None => {
+                    ui.label("Seçili varlık yok. Sahnede bir nesneye tıklayın.");
+                }
+            }
+        });
+}
+
+fn hierarchy_node(ui: &mut egui::Ui, scene: &mut Scene, id: EntityId) {
+    let Some(entity) = scene.get(id) else { return };
+    let name = entity.name.clone();
+    let children = entity.children.clone();
+    let selected = scene.selected == Some(id);
+
+    if children.is_empty() {
+        if ui.selectable_label(selected, name).clicked() {
+            scene.selected = Some(id);
+        }
+        return;
+    }
+
+    let header_id = ui.make_persistent_id(("hierarchy", id));
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), header_id, true)
+        .show_header(ui, |ui| {
+            if ui.selectable_label(selected, name).clicked() {
+                scene.selected = Some(id);
+            }
+        })
+        .body(|ui| {
+            for child in children {
+                hierarchy_node(ui, scene, child);
+            }
+        });
+}
+
+fn vec3_editor(ui: &mut egui::Ui, label: &str, value: &mut [f32; 3], speed: f32) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(label);
+        for component in value.iter_mut() {
+            changed |= ui.add(egui::DragValue::new(component).speed(speed)).changed();
+        }
+    });
+    changed
+}
+
+fn inspector(ui: &mut egui::Ui, scene: &mut Scene, assets: &AssetManager, id: EntityId) {
+    let mut despawn = false;
+    let mut add_child = false;
+
+    if let Some(entity) = scene.get_mut(id) {
+        ui.text_edit_singleline(&mut entity.name);
+        ui.add_space(4.0);
+
+        egui::CollapsingHeader::new("Transform")
+            .default_open(true)
+            .show(ui, |ui| {
+                let transform = &mut entity.transform;
+                let mut translation = transform.translation.to_array();
+                if vec3_editor(ui, "Konum", &mut translation, 0.05) {
+                    transform.translation = translation.into();
+                }
+
+                let (y, x, z) = transform.rotation.to_euler(EulerRot::YXZ);
+                let mut degrees = [x.to_degrees(), y.to_degrees(), z.to_degrees()];
+                if vec3_editor(ui, "Dönüş", &mut degrees, 1.0) {
+                    let [x, y, z] = degrees.map(f32::to_radians);
+                    transform.rotation = Quat::from_euler(EulerRot::YXZ, y, x, z);
+                }
+
+                let mut scale = transform.scale.to_array();
+                if vec3_editor(ui, "Ölçek", &mut scale, 0.02) {
+                    transform.scale = scale.into();
+                }
+            });
+
+        egui::CollapsingHeader::new("Mesh")
+            .default_open(true)
+            .show(ui, |ui| {
+                let selected = entity
+                    .mesh
+                    .and_then(|mesh| assets.mesh(mesh))
+                    .map_or("Yok", |mesh| mesh.name.as_str());
+                egui::ComboBox::from_id_salt("mesh")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut entity.mesh, None, "Yok");
+                        for (handle, mesh) in assets.meshes() {
+                            ui.selectable_value(&mut entity.mesh, Some(handle), &mesh.name);
+                        }
+                    });
+            });
+
+        egui::CollapsingHeader::new("Malzeme")
+            .default_open(true)
+            .show(ui, |ui| match entity.material.as_mut() {
+                Some(material) => {
+                    ui.horizontal(|ui| {
+                        ui.label("Temel renk");
+                        // egui renk seçici doğrusal değerlerle çalışır
+                        let mut rgba = material.base_color.to_linear();
+                        if ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed() {
+                            material.base_color = Color::from_linear(rgba);
+                        }
+                    });
+                    let selected = material
+                        .texture
+                        .and_then(|texture| assets.texture(texture))
+                        .map_or("Yok", |texture| texture.name.as_str());
+                    egui::ComboBox::from_label("Doku")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut material.texture, None, "Yok");
+                            for (handle, texture) in assets.textures() {
+                                ui.selectable_value(&mut material.texture, Some(handle), &texture.name);
+                            }
+                        });
+                    if ui.button("Kaldır").clicked() {
+                        entity.material = None;
+                    }
+                }
+                None => {
+                    if ui.button("Malzeme ekle").clicked() {
+                        entity.material = Some(Material::default());
+                    }
+                }
+            });
+
+        egui::CollapsingHeader::new("Işık")
+            .default_open(true)
+            .show(ui, |ui| match entity.light.as_mut() {
+                Some(light) => {
+                    ui.horizontal(|ui| {
+                        ui.label("Renk");
+                        ui.color_edit_button_rgb(&mut light.color);
+                    });
+                    ui.add(egui::Slider::new(&mut light.intensity, 0.0..=20.0).text("Yoğunluk"));
+                    ui.add(egui::Slider::new(&mut light.range, 0.1..=50.0).text("Menzil"));
+                    if ui.button("Kaldır").clicked() {
+                        entity.light = None;
+                    }
+                }
+                None => {
+                    if ui.button("Işık ekle").clicked() {
+                        entity.light = Some(Light::default());
+                    }
+                }
+            });
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            add_child = ui.button("Çocuk ekle").clicked();
+            despawn = ui.button("Sil").clicked();
+        });
+    }
+
+    if add_child {
+        scene.selected = Some(scene.spawn("Yeni Varlık", Some(id)));
+    }
+    if despawn {
+        scene.despawn(id);
+    }
+}
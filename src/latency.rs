@@ -0,0 +1,155 @@
+use crate::color::Color;
+use crate::overlay::Overlay;
+use std::collections::VecDeque;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+const HISTORY: usize = 64;
+// Fotodiyot veya yüksek hızlı kamerayla ölçüm için sol üst köşedeki kare
+const FLASH_SIZE: f32 = 72.0;
+
+// Girdiyi içeren ve GPU'ya gönderilmiş bir kare
+struct InFlight {
+    input: Instant,
+    submitted: Instant,
+    presented: Option<Instant>,
+    gpu_done: Arc<OnceLock<Instant>>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Sample {
+    pub to_submit: Duration,
+    pub to_present: Duration,
+    pub to_gpu_done: Duration,
+    // GPU bitişine ekranın kareyi göstermesi için beklenen süre eklenmiş tahmin
+    pub estimated: Duration,
+}
+
+// Girdi -> foton gecikme testi. Açıkken her sol tık zaman damgası alır;
+// girdiyi işleyen kare köşede beyaz bir kare çizer ve bu karenin gönderim,
+// sunum ve GPU bitiş anları ölçülür. winit olay zaman damgası vermediği için
+// ölçüm olayın uygulamaya ulaştığı andan başlar; işletim sistemi ve
+// cihaz gecikmesi dahil değildir.
+#[derive(Default)]
+pub struct LatencyTest {
+    pub enabled: bool,
+    pending: Option<Instant>,
+    in_flight: Vec<InFlight>,
+    samples: VecDeque<Sample>,
+}
+
+impl LatencyTest {
+    pub fn input(&mut self) {
+        if self.enabled && self.pending.is_none() {
+            self.pending = Some(Instant::now());
+        }
+    }
+
+    // Bu karede işlenecek girdi; varsa kare flaş çizer
+    pub fn take_pending(&mut self) -> Option<Instant> {
+        self.pending.take()
+    }
+
+    pub fn submitted(&mut self, queue: &wgpu::Queue, input: Instant) {
+        let gpu_done = Arc::new(OnceLock::new());
+        let done = gpu_done.clone();
+        queue.on_submitted_work_done(move || {
+            let _ = done.set(Instant::now());
+        });
+        self.in_flight.push(InFlight {
+            input,
+            submitted: Instant::now(),
+            presented: None,
+            gpu_done,
+        });
+    }
+
+    pub fn presented(&mut self) {
+        let now = Instant::now();
+        for frame in self.in_flight.iter_mut().filter(|frame| frame.presented.is_none()) {
+            frame.presented = Some(now);
+        }
+    }
+
+    // GPU'su biten kareleri örneğe çevirir. Vsync açıkken kare bir sonraki
+    // dikey boşluğu ve taramayı bekler; bunun için bir yenileme periyodu eklenir.
+    pub fn collect(&mut self, device: &wgpu::Device, refresh: Option<Duration>, synced: bool) -> Vec<Sample> {
+        if self.in_flight.is_empty() {
+            return Vec::new();
+        }
+        let _ = device.poll(wgpu::PollType::Poll);
+        let display_delay = if synced { refresh.unwrap_or_default() } else { Duration::ZERO };
+
+        let mut finished = Vec::new();
+        self.in_flight.retain(|frame| {
+            let (Some(presented), Some(gpu_done)) = (frame.presented, frame.gpu_done.get()) else {
+                return true;
+            };
+            let visible = (*gpu_done).max(presented);
+            finished.push(Sample {
+                to_submit: frame.submitted - frame.input,
+                to_present: presented - frame.input,
+                to_gpu_done: *gpu_done - frame.input,
+                estimated: visible - frame.input + display_delay,
+            });
+            false
+        });
+        for sample in &finished {
+            if self.samples.len() == HISTORY {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(*sample);
+        }
+        finished
+    }
+
+    pub fn reset(&mut self) {
+        self.pending = None;
+        self.in_flight.clear();
+        self.samples.clear();
+    }
+
+    pub fn summary(&self) -> String {
+        let count = self.samples.len();
+        if count == 0 {
+            return "örnek yok; test açıkken pencereye tıklayın".into();
+        }
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+        let average = |field: fn(&Sample) -> Duration| {
+            self.samples.iter().map(|sample| ms(field(sample))).sum::<f64>() / count as f64
+        };
+        let mut estimated: Vec<f64> = self.samples.iter().map(|sample| ms(sample.estimated)).collect();
+        estimated.sort_by(f64::total_cmp);
+        format!(
+            "{count} örnek  tahmini ort {:.1} ms  min {:.1}  p95 {:.1}  max {:.1}\n\
+             girdi -> gönderim {:.1} ms  -> sunum {:.1} ms  -> GPU bitişi {:.1} ms",
+            average(|sample| sample.estimated),
+            estimated[0],
+            estimated[((count - 1) as f64 * 0.95) as usize],
+            estimated[count - 1],
+            average(|sample| sample.to_submit),
+            average(|sample| sample.to_present),
+            average(|sample| sample.to_gpu_done),
+        )
+    }
+
+    // Test açıkken köşedeki kare siyahtır, girdiyi işleyen karede beyaz olur
+    pub fn draw(&self, overlay: &mut Overlay, flash: bool) {
+        if !self.enabled {
+            return;
+        }
+        let color = if flash { Color::WHITE } else { Color::BLACK };
+        overlay.rect(0.0, 0.0, FLASH_SIZE, FLASH_SIZE, color);
+
+        let palette = overlay.palette;
+        let [char_w, line_height] = overlay.char_size();
+        let padding = 6.0;
+        let text = match self.samples.back() {
+            Some(last) => format!("gecikme ~{:.1} ms", last.estimated.as_secs_f64() * 1000.0),
+            None => "gecikme testi: tıklayın".into(),
+        };
+        let width = text.chars().count() as f32 * char_w + padding * 2.0;
+        overlay.rect(FLASH_SIZE, 0.0, width, line_height + padding * 2.0, palette.panel);
+        overlay.text(FLASH_SIZE + padding, padding, &text, palette.text);
+    }
+}
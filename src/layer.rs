@@ -0,0 +1,24 @@
+use crate::State;
+use winit::event::WindowEvent;
+
+// Uygulamanın kendi mantığı; `AppBuilder::with_layer` ile eklenir. Katmanlar
+// eklendikleri sırayla güncellenir ve çizilir. Olaylar konsol, egui ve `ui`
+// tüketmediyse en son eklenen katmandan başlayarak dağıtılır.
+pub trait Layer {
+    // Pencere ve GPU hazır olduktan sonra bir kez
+    fn on_attach(&mut self, _state: &mut State) {}
+
+    // true dönerse olay alttaki katmanlara ve uygulamaya geçmez;
+    // `RedrawRequested`, `CloseRequested`, `Resized` ve imleç/boyut/tema takibi
+    // yine de işlenir. Tüketilen olaylar yeni kare ister; olayı tüketmeden
+    // görüntüyü değiştiren katman `state.dirty`'yi işaretler.
+    fn on_event(&mut self, _state: &mut State, _event: &WindowEvent) -> bool {
+        false
+    }
+
+    // Her karede, saniye cinsinden geçen süreyle; duraklatılmışken çağrılmaz
+    fn on_update(&mut self, _state: &mut State, _dt: f32) {}
+
+    // Kare çizilmeden hemen önce; overlay, şekil ve çizgi çağrıları bu karede görünür
+    fn on_draw(&mut self, _state: &mut State) {}
+}
@@ -0,0 +1,429 @@
+use crate::assets::AssetManager;
+use crate::color::{Color, SELECTION};
+use crate::frame_capture::TracedPass;
+use crate::frame_context::{FRAMES_IN_FLIGHT, FrameContext, PerFrame};
+use crate::scene::{Camera, Scene};
+use glam::Vec3;
+use std::fmt;
+use std::str::FromStr;
+
+// lines.wgsl'deki bayraklarla aynı
+const DISC: u32 = 1;
+const SQUARE_START: u32 = 2;
+const SQUARE_END: u32 = 4;
+const MITER_START: u32 = 8;
+const MITER_END: u32 = 16;
+
+// Bir çizgi parçası veya (yuvarlak birleşim/uç için) bir disk. Dört köşeli bir
+// dörtgene genişletilir; genişlik piksel cinsindendir, mesafeyle değişmez.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct LineInstance {
+    start: [f32; 3],
+    width: f32,
+    end: [f32; 3],
+    flags: u32,
+    // Gönye birleşimi için komşu noktalar; komşu yoksa uç noktanın kendisi
+    prev: [f32; 3],
+    next: [f32; 3],
+    color: [f32; 4],
+}
+
+impl LineInstance {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
+        0 => Float32x4, 1 => Float32x3, 2 => Uint32, 3 => Float32x3, 4 => Float32x3, 5 => Float32x4
+    ];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<LineInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineJoin {
+    // Keskin köşelerde gönye uzunluğu sınırlanır
+    Miter,
+    // Köşelere disk eklenir; saydam çizgilerde disk ile parçanın kesişimi koyu görünür
+    Round,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineCap {
+    Butt,
+    // Uç yarım genişlik kadar uzatılır
+    Square,
+    Round,
+}
+
+impl fmt::Display for LineJoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+        })
+    }
+}
+
+impl FromStr for LineJoin {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "miter" => Ok(LineJoin::Miter),
+            "round" => Ok(LineJoin::Round),
+            _ => Err(format!("bilinmeyen birleşim: {text} (miter | round)")),
+        }
+    }
+}
+
+impl fmt::Display for LineCap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        })
+    }
+}
+
+impl FromStr for LineCap {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "butt" => Ok(LineCap::Butt),
+            "square" => Ok(LineCap::Square),
+            "round" => Ok(LineCap::Round),
+            _ => Err(format!("bilinmeyen uç: {text} (butt | square | round)")),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct LineStyle {
+    // Piksel
+    pub width: f32,
+    pub color: Color,
+    pub join: LineJoin,
+    pub cap: LineCap,
+}
+
+impl Default for LineStyle {
+    fn default() -> Self {
+        Self {
+            width: 2.0,
+            color: Color::WHITE,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+        }
+    }
+}
+
+impl LineStyle {
+    pub fn with_color(self, color: Color) -> Self {
+        Self { color, ..self }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    view_proj: [[f32; 4]; 4],
+    viewport: [f32; 2],
+    _padding: [f32; 2],
+}
+
+struct FrameParams {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+// Dünya uzayında kalın, kenarları yumuşatılmış çizgiler. Yerel çizgi
+// primitifleri 1 piksel olduğundan (daha kalını arka uca göre desteklenmez)
+// her parça vertex shader'da ekran uzayında bir dörtgene genişletilir.
+// Overlay gibi anlık çalışır: çizgiler her kare yeniden eklenir.
+pub struct LineRenderer {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    frames: PerFrame<FrameParams>,
+    instance_buffer: wgpu::Buffer,
+    instance_capacity: usize,
+    instance_count: u32,
+    instances: Vec<LineInstance>,
+    format: wgpu::TextureFormat,
+    // Eksenler ve seçili nesnenin sınır kutusu
+    pub debug_draw: bool,
+    pub debug_style: LineStyle,
+}
+
+impl LineRenderer {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self, String> {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Line Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Line Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+        let frames = PerFrame::new(FRAMES_IN_FLIGHT, |_| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Line Params"),
+                size: std::mem::size_of::<ParamsRaw>() as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Line Bind Group"),
+                layout: &bind_group_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.as_entire_binding(),
+                }],
+            });
+            FrameParams { buffer, bind_group }
+        });
+
+        let instance_capacity = 256;
+        let instance_buffer = Self::create_instance_buffer(device, instance_capacity);
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            frames,
+            instance_buffer,
+            instance_capacity,
+            instance_count: 0,
+            instances: Vec::new(),
+            format,
+            debug_draw: false,
+            debug_style: LineStyle::default(),
+        })
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Line Instances"),
+            size: (capacity * std::mem::size_of::<LineInstance>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "lines.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Line Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[LineInstance::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleStrip,
+                    ..Default::default()
+                },
+                // Sahne tarafından örtülür ama derinliğe yazmaz; yumuşak kenarlar
+                // üst üste binen parçaları kesmesin diye
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: crate::renderer::DEPTH_FORMAT,
+                    depth_write_enabled: false,
+                    depth_compare: wgpu::CompareFunction::LessEqual,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    pub fn line(&mut self, a: Vec3, b: Vec3, style: LineStyle) {
+        self.polyline(&[a, b], false, style);
+    }
+
+    // `closed` ise son nokta ilkine bağlanır ve tüm köşeler birleşim alır
+    pub fn polyline(&mut self, points: &[Vec3], closed: bool, style: LineStyle) {
+        let mut points = points.to_vec();
+        points.dedup();
+        if closed && points.len() > 2 && points.first() == points.last() {
+            points.pop();
+        }
+        if points.len() < 2 {
+            return;
+        }
+        let color = style.color.for_target(self.format);
+        let count = points.len();
+        let segments = if closed && count > 2 { count } else { count - 1 };
+        let closed = segments == count;
+        let point = |index: usize| points[index % count].to_array();
+
+        for i in 0..segments {
+            let has_prev = closed || i > 0;
+            let has_next = closed || i + 1 < segments;
+            let mut flags = 0;
+            if style.join == LineJoin::Miter {
+                flags |= if has_prev { MITER_START } else { 0 };
+                flags |= if has_next { MITER_END } else { 0 };
+            }
+            if style.cap == LineCap::Square {
+                flags |= if has_prev { 0 } else { SQUARE_START };
+                flags |= if has_next { 0 } else { SQUARE_END };
+            }
+            self.instances.push(LineInstance {
+                start: point(i),
+                width: style.width,
+                end: point(i + 1),
+                flags,
+                prev: if has_prev { point(i + count - 1) } else { point(i) },
+                next: if has_next { point(i + 2) } else { point(i + 1) },
+                color,
+            });
+        }
+
+        // Yuvarlak birleşim ve uçlar: parçaların uçlarına yerleştirilen diskler
+        let mut disc = |index: usize| {
+            self.instances.push(LineInstance {
+                start: point(index),
+                width: style.width,
+                end: point(index),
+                flags: DISC,
+                prev: point(index),
+                next: point(index),
+                color,
+            })
+        };
+        if style.join == LineJoin::Round {
+            let interior = if closed { 0..count } else { 1..count - 1 };
+            interior.for_each(&mut disc);
+        }
+        if style.cap == LineCap::Round && !closed {
+            disc(0);
+            disc(count - 1);
+        }
+    }
+
+    // Dünya eksenleri ve seçili nesnenin dünya uzayındaki sınır kutusu
+    pub fn draw_debug(&mut self, scene: &Scene, assets: &AssetManager) {
+        if !self.debug_draw {
+            return;
+        }
+        let style = self.debug_style;
+        let axes = [
+            (Vec3::X, Color::srgb(0.9, 0.25, 0.2)),
+            (Vec3::Y, Color::srgb(0.3, 0.85, 0.3)),
+            (Vec3::Z, Color::srgb(0.25, 0.45, 1.0)),
+        ];
+        for (axis, color) in axes {
+            self.line(Vec3::ZERO, axis, style.with_color(color));
+        }
+
+        let Some(id) = scene.selected else { return };
+        let Some((min, max)) = scene
+            .get(id)
+            .and_then(|entity| entity.mesh)
+            .and_then(|mesh| assets.mesh(mesh))
+            .map(|mesh| mesh.bounds)
+        else {
+            return;
+        };
+        let matrix = scene.world_matrix(id);
+        let corner = |x: bool, y: bool, z: bool| {
+            let local = Vec3::new(
+                if x { max.x } else { min.x },
+                if y { max.y } else { min.y },
+                if z { max.z } else { min.z },
+            );
+            matrix.transform_point3(local)
+        };
+        let style = style.with_color(SELECTION);
+        for top in [false, true] {
+            let ring = [
+                corner(false, top, false),
+                corner(true, top, false),
+                corner(true, top, true),
+                corner(false, top, true),
+            ];
+            self.polyline(&ring, true, style);
+        }
+        for (x, z) in [(false, false), (true, false), (true, true), (false, true)] {
+            self.line(corner(x, false, z), corner(x, true, z), style);
+        }
+    }
+
+    // Biriken parçaları GPU'ya yükler ve bir sonraki kare için listeyi boşaltır
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        frame: FrameContext,
+        camera: &Camera,
+        aspect: f32,
+        viewport: [f32; 2],
+    ) {
+        let raw = ParamsRaw {
+            view_proj: camera.view_proj(aspect).to_cols_array_2d(),
+            viewport,
+            _padding: [0.0; 2],
+        };
+        queue.write_buffer(&self.frames.get(frame).buffer, 0, bytemuck::bytes_of(&raw));
+
+        if self.instances.len() > self.instance_capacity {
+            self.instance_capacity = self.instances.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.instance_capacity);
+        }
+        if !self.instances.is_empty() {
+            queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(&self.instances));
+        }
+        self.instance_count = self.instances.len() as u32;
+        self.instances.clear();
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>, frame: FrameContext) {
+        if self.instance_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline, "Line Pipeline");
+        render_pass.set_bind_group(0, &self.frames.get(frame).bind_group, "Line Bind Group");
+        render_pass.set_vertex_buffer(0, &self.instance_buffer, "Line Instances");
+        render_pass.draw(0..4, 0..self.instance_count);
+    }
+}
@@ -1,188 +1,1423 @@
+mod accessibility;
+mod actions;
+#[cfg(feature = "debug-ui")]
+mod asset_browser;
+mod assets;
+mod builder;
+mod color;
+mod commands;
+mod console;
+mod crash_report;
+#[cfg(feature = "debug-ui")]
+mod debug_ui;
+mod demo_layer;
+mod event_log;
+#[cfg(feature = "debug-ui")]
+mod event_viewer;
+mod frame_capture;
+mod frame_context;
+mod frame_pacer;
+mod gpu_sort;
+mod input;
+mod input_display;
+mod latency;
+mod layer;
+mod lines;
+mod marching_cubes;
+#[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+mod menu;
+#[cfg(feature = "hot-reload")]
+mod hot_reload;
+#[cfg(feature = "debug-ui")]
+mod inspector;
+mod noise;
+mod noise_bake;
+mod overlay;
+mod plugin;
+mod point_cloud;
+mod profiler;
+mod renderer;
+mod scene;
+mod screenshot;
+mod settings;
+mod shaders;
+mod shapes;
+mod skinning;
+mod splash;
+mod sprites;
+mod stats;
+#[cfg(feature = "debug-ui")]
+mod target_viewer;
+mod tonemap;
+#[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+mod tray;
+mod ui;
+mod voxels;
+
+use accessibility::Accessibility;
+use actions::{Action, KeyBindings};
+use assets::AssetManager;
+use builder::AppBuilder;
+use color::Color;
+use console::{CommandRegistry, Console, LineKind};
+use event_log::{EventKind, EventLog};
+use frame_capture::{Command, FrameCapture, TracedPass};
+use frame_context::FrameRing;
+use frame_pacer::FramePacer;
+use gpu_sort::GpuSorter;
+use input::InputState;
+use latency::LatencyTest;
+use layer::Layer;
+use lines::LineRenderer;
+use marching_cubes::MarchingCubes;
+use noise_bake::NoiseBaker;
+use overlay::Overlay;
+use plugin::Plugin;
+use point_cloud::PointCloudRenderer;
+use profiler::Profiler;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use renderer::SceneRenderer;
+use scene::Scene;
+use settings::{RedrawPolicy, Settings, Theme};
+use shapes::ShapeRenderer;
+use skinning::Skinning;
+use sprites::{NineSlice, SpriteBatch};
+use stats::RenderStats;
+use std::any::Any;
 use std::error::Error;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use tonemap::Tonemapper;
+use voxels::Voxels;
 use winit::application::ApplicationHandler;
-use winit::dpi::PhysicalSize;
-use winit::event::{ElementState, KeyEvent, WindowEvent};
-use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
-use winit::keyboard::{Key, NamedKey};
-use winit::window::{Window, WindowAttributes, WindowId};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, MouseButton, WindowEvent};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoopProxy};
+use winit::keyboard::{KeyCode, PhysicalKey};
+use winit::window::{Fullscreen, Window, WindowAttributes, WindowId};
+
+// Bu kadar üst üste yüzey zaman aşımında GPU takıldı sayılıp rapor yazılır
+const HANG_TIMEOUTS: u32 = 5;
 
 struct State {
     surface: wgpu::Surface<'static>,
     device: wgpu::Device,
     queue: wgpu::Queue,
     surface_config: wgpu::SurfaceConfiguration,
+    present_modes: Vec<wgpu::PresentMode>,
     size: PhysicalSize<u32>,
-    clear_color: wgpu::Color,
+    // Bu kare içinde gelen en son Resized boyutu ve kaç olayın birleştirildiği
+    pending_resize: Option<PhysicalSize<u32>>,
+    coalesced_resizes: u32,
+    clear_color: Color,
+    // Pencerenin bildirdiği son sistem teması ve ayarlarla çözülmüş geçerli tema
+    system_theme: Theme,
+    theme: Theme,
+    // Tam ekran ve başlık gibi pencere işlemleri için
+    window: Arc<Window>,
+    input_state: InputState,
+    scene: Scene,
+    assets: AssetManager,
+    scene_renderer: SceneRenderer,
+    skinning: Skinning,
+    marching_cubes: MarchingCubes,
+    voxels: Voxels,
+    point_cloud: PointCloudRenderer,
+    lines: LineRenderer,
+    gpu_sorter: GpuSorter,
+    noise_baker: NoiseBaker,
+    tonemapper: Tonemapper,
+    frame_pacer: FramePacer,
+    frame_ring: FrameRing,
+    latency: LatencyTest,
+    overlay: Overlay,
+    shapes: ShapeRenderer,
+    sprites: SpriteBatch,
+    // `ninepatch` komutunun örnek panelleri
+    nine_slice_demo: Option<NineSlice>,
+    // `ui` komutunun HUD'u ve menüsü
+    ui: Option<ui::DemoUi>,
+    console: Console,
+    bindings: KeyBindings,
+    event_log: EventLog,
+    exit_requested: bool,
+    settings: Settings,
+    // Rastgele sistemlerin ortak üreteci; aynı tohumla aynı komutlar aynı çıktıyı verir
+    seed: u64,
+    rng: SmallRng,
+    // OnDemand modunda yeni kare gerektiren bir değişiklik oldu mu
+    dirty: bool,
+    // Duraklatılmışken oyun güncellenmez ve kareler sadece değişiklik olunca çizilir
+    paused: bool,
+    profiler: Profiler,
+    stats: RenderStats,
+    commands: CommandRegistry,
+    // `AppBuilder::with_plugin` ile eklenenler; konsol komutları `plugin_mut` ile ulaşır
+    plugins: Vec<Box<dyn Plugin>>,
+    // Bir eklenti kancası çalışırken liste `with_plugins` tarafından dışarı alınmıştır
+    plugins_busy: bool,
+    screenshot_requested: bool,
+    // `report` komutu; rapor bu karenin ekran görüntüsüyle yazılır
+    report_requested: bool,
+    // Üst üste zaman aşımına uğrayan yüzey alımları; GPU takılmasının işareti
+    surface_timeouts: u32,
+    frame_capture_requested: bool,
+    #[cfg(feature = "debug-ui")]
+    debug_ui: debug_ui::DebugUi,
+    #[cfg(feature = "debug-ui")]
+    event_viewer: event_viewer::EventViewer,
+    #[cfg(feature = "debug-ui")]
+    target_viewer: target_viewer::TargetViewer,
+    #[cfg(feature = "hot-reload")]
+    game: Option<hot_reload::GameLibrary>,
 }
 
-impl State {
-    async fn new(window: Arc<Window>) -> Result<Self, Box<dyn Error>> {
-        let size = window.inner_size();
-        // Pencere boyutu 0 ise wgpu başlamaz
-        if size.width == 0 || size.height == 0 {
-            return Err("Pencere boyutu sıfır olamaz.".into());
-        }
-
-        let instance_descriptor = wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::all(),
-            ..Default::default()
-        };
-
-        let instance = wgpu::Instance::new(&instance_descriptor);
-
-        let surface = instance.create_surface(window.clone()).unwrap();
+// Adaptör ve cihaz istekleri sürücüye göre saniyeler sürebilir; açılış penceresi
+// çizilmeye devam etsin diye ayrı bir iş parçacığında yapılır. Yüzey ana iş
+// parçacığında oluşturulur (macOS bunu şart koşar) ve buraya taşınır.
+#[derive(Debug)]
+struct Gpu {
+    surface: wgpu::Surface<'static>,
+    adapter: wgpu::Adapter,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
 
+impl Gpu {
+    async fn request(
+        instance: wgpu::Instance,
+        surface: wgpu::Surface<'static>,
+        proxy: &EventLoopProxy<AppEvent>,
+    ) -> Result<Self, String> {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::PowerPreference::HighPerformance,
                 compatible_surface: Some(&surface),
                 force_fallback_adapter: false,
-            }).await.unwrap();
+            })
+            .await
+            .map_err(|e| format!("GPU adaptörü bulunamadı: {e}"))?;
 
         log::info!("Adaptör: {:?}", adapter.get_info());
+        crash_report::set_adapter(&adapter.get_info());
+        let _ = proxy.send_event(AppEvent::Startup(splash::Stage::Device));
 
         let device_descriptor = wgpu::DeviceDescriptor {
             label: Some("Device"),
-            required_features: wgpu::Features::default(),
+            // GPU zamanlaması için; desteklenmiyorsa profiler sadece CPU ölçer
+            required_features: adapter.features() & wgpu::Features::TIMESTAMP_QUERY,
             required_limits: wgpu::Limits::default(),
             memory_hints: wgpu::MemoryHints::Performance,
             trace: wgpu::Trace::Off
         };
-        let (device, queue) = adapter.request_device(&device_descriptor).await.unwrap();
+        let (device, queue) = adapter
+            .request_device(&device_descriptor)
+            .await
+            .map_err(|e| format!("GPU cihazı oluşturulamadı: {e}"))?;
+        // Cihaz kaybı bir sonraki bakımda başka bir iş parçacığından bildirilebilir
+        let proxy = std::sync::Mutex::new(proxy.clone());
+        device.set_device_lost_callback(move |reason, message| {
+            if reason != wgpu::DeviceLostReason::Destroyed
+                && let Ok(proxy) = proxy.lock()
+            {
+                let _ = proxy.send_event(AppEvent::DeviceLost(format!("{reason:?}: {message}")));
+            }
+        });
+        Ok(Self { surface, adapter, device, queue })
+    }
+}
+
+impl State {
+    fn new(window: Arc<Window>, gpu: Gpu, settings: Settings) -> Result<Self, Box<dyn Error>> {
+        let size = window.inner_size();
+        // Pencere boyutu 0 ise wgpu başlamaz
+        if size.width == 0 || size.height == 0 {
+            return Err("Pencere boyutu sıfır olamaz.".into());
+        }
+        let Gpu { surface, adapter, device, queue } = gpu;
 
         let surface_caps = surface.get_capabilities(&adapter);
 
-        let surface_format = surface_caps.formats.iter()
+        let sdr_format = surface_caps.formats.iter()
             .find(|f| f.is_srgb())
             .copied()
             .unwrap_or(surface_caps.formats[0]);
+        // wgpu yüzey renk uzayını seçtirmiyor; Rgba16Float yüzey, işletim sistemi
+        // HDR açıkken scRGB olarak sunulur (DX12, Vulkan EXTENDED_SRGB_LINEAR,
+        // Metal EDR). PQ kodlu HDR10 (Rgb10a2) için renk uzayı API'si olmadığından
+        // sadece scRGB yolu destekleniyor, diğer durumlarda SDR'ye düşülür.
+        let hdr_format = wgpu::TextureFormat::Rgba16Float;
+        let surface_format = if settings.hdr && surface_caps.formats.contains(&hdr_format) {
+            log::info!("HDR çıktı: {hdr_format:?} (scRGB)");
+            hdr_format
+        } else {
+            if settings.hdr {
+                log::warn!("Yüzey HDR formatını desteklemiyor ({:?}), SDR kullanılıyor", surface_caps.formats);
+            }
+            sdr_format
+        };
+
+        // Ekran görüntüsü için yüzeyden kopyalama yapabilmek gerekiyor
+        let mut usage = wgpu::TextureUsages::RENDER_ATTACHMENT;
+        if surface_caps.usages.contains(wgpu::TextureUsages::COPY_SRC) {
+            usage |= wgpu::TextureUsages::COPY_SRC;
+        }
 
         let surface_config = wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            usage,
             format: surface_format,
             width: size.width,
             height: size.height,
-            present_mode: surface_caps.present_modes[0],
+            present_mode: settings
+                .vsync
+                .map_or(surface_caps.present_modes[0], |vsync| present_mode(vsync, &surface_caps.present_modes)),
             alpha_mode: surface_caps.alpha_modes[0],
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
         };
-        
-        let clear_color = wgpu::Color::BLACK;
+        surface.configure(&device, &surface_config);
+
+        let system_theme = Theme::from_system(window.theme());
+        let theme = settings.theme.resolve(system_theme);
+        let clear_color = theme.clear_color();
+
+        let seed = settings.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+        });
+        log::info!("rastgele tohum {seed}; aynı çıktı için --seed {seed}");
+
+        let assets = AssetManager::new(&device, &queue);
+        let scene_renderer = SceneRenderer::new(&device, &assets, tonemap::HDR_FORMAT, size.width, size.height)?;
+        let skinning = Skinning::new(&device)?;
+        let marching_cubes = MarchingCubes::new(&device)?;
+        let voxels = Voxels::new(&device, &queue, scene_renderer.camera_layout(), tonemap::HDR_FORMAT)?;
+        let point_cloud = PointCloudRenderer::new(&device, tonemap::HDR_FORMAT)?;
+        let lines = LineRenderer::new(&device, tonemap::HDR_FORMAT)?;
+        let gpu_sorter = GpuSorter::new(&device)?;
+        let noise_baker = NoiseBaker::new(&device)?;
+        let tonemapper = Tonemapper::new(&device, surface_format, size.width, size.height)?;
+        let shapes = ShapeRenderer::new(&device, surface_format)?;
+        let sprites = SpriteBatch::new(&device, &assets, surface_format)?;
+        let mut overlay = Overlay::new(&device, &queue, surface_format, 16.0 * window.scale_factor() as f32)?;
+        overlay.palette = theme.palette();
+
+        let profiler = Profiler::new(&device, &queue);
+        let frame_pacer = FramePacer::new(monitor_hz(&window), surface_config.present_mode);
+
+        let mut commands = CommandRegistry::default();
+        commands::register_builtins(&mut commands);
 
         Ok(Self {
+            #[cfg(feature = "debug-ui")]
+            debug_ui: debug_ui::DebugUi::new(window.clone(), &device, surface_format, theme),
+            #[cfg(feature = "debug-ui")]
+            event_viewer: event_viewer::EventViewer::default(),
+            #[cfg(feature = "debug-ui")]
+            target_viewer: target_viewer::TargetViewer::new(&device)?,
             surface,
             device,
             queue,
             surface_config,
+            present_modes: surface_caps.present_modes,
             size,
+            pending_resize: None,
+            coalesced_resizes: 0,
             clear_color,
+            system_theme,
+            theme,
+            input_state: InputState::new(window.scale_factor(), size),
+            window: window.clone(),
+            scene: Scene::new(),
+            assets,
+            scene_renderer,
+            skinning,
+            marching_cubes,
+            voxels,
+            point_cloud,
+            lines,
+            gpu_sorter,
+            noise_baker,
+            tonemapper,
+            frame_pacer,
+            frame_ring: FrameRing::new(frame_context::FRAMES_IN_FLIGHT),
+            latency: LatencyTest::default(),
+            overlay,
+            shapes,
+            sprites,
+            nine_slice_demo: None,
+            ui: None,
+            console: Console::new(),
+            bindings: KeyBindings::default(),
+            event_log: EventLog::new(1000),
+            exit_requested: false,
+            settings,
+            seed,
+            rng: SmallRng::seed_from_u64(seed),
+            dirty: true,
+            paused: false,
+            profiler,
+            stats: RenderStats::default(),
+            commands,
+            plugins: Vec::new(),
+            plugins_busy: false,
+            screenshot_requested: false,
+            report_requested: false,
+            surface_timeouts: 0,
+            frame_capture_requested: false,
+            #[cfg(feature = "hot-reload")]
+            game: hot_reload::GameLibrary::load()
+                .inspect_err(|e| log::warn!("Oyun kütüphanesi yüklenemedi, `cargo build -p game` çalıştırın: {e}"))
+                .ok(),
         })
     }
 
+    // Sürükleyerek boyutlandırırken her olayda yüzeyi yeniden yapılandırmak
+    // takılmaya yol açar; olay sadece kaydedilir, `apply_resize` karede bir kez uygular.
     pub fn resize(&mut self, new_size: PhysicalSize<u32>) {
-        if new_size.width > 0 && new_size.height > 0 && self.size != new_size {
-            self.size = new_size;
-            self.surface_config.width = new_size.width;
-            self.surface_config.height = new_size.height;
-            self.surface.configure(&self.device, &self.surface_config);
+        self.pending_resize = Some(new_size);
+        self.coalesced_resizes += 1;
+    }
+
+    fn apply_resize(&mut self) {
+        let Some(new_size) = self.pending_resize.take() else { return };
+        let coalesced = std::mem::take(&mut self.coalesced_resizes);
+        // Simge durumundaki pencere 0x0 bildirir; eski boyutla devam edilir
+        if new_size.width == 0 || new_size.height == 0 || self.size == new_size {
+            return;
+        }
+        self.size = new_size;
+        self.surface_config.width = new_size.width;
+        self.surface_config.height = new_size.height;
+        self.surface.configure(&self.device, &self.surface_config);
+        self.scene_renderer.resize(&self.device, new_size.width, new_size.height);
+        self.tonemapper.resize(&self.device, new_size.width, new_size.height);
+        self.event_log.push(
+            EventKind::App,
+            format!(
+                "yüzey {}x{} olarak yapılandırıldı ({coalesced} Resized olayı birleştirildi)",
+                new_size.width, new_size.height
+            ),
+        );
+    }
+
+    // Kaybolan/eskiyen yüzey aynı ayarlarla yeniden oluşturulur
+    fn reconfigure(&mut self) {
+        self.surface.configure(&self.device, &self.surface_config);
+    }
+
+    // Tema tercihi veya sistem teması değiştiğinde paneller, arka plan ve
+    // egui görünümü geçerli temaya geçirilir
+    pub fn apply_theme(&mut self) {
+        let theme = self.settings.theme.resolve(self.system_theme);
+        if theme == self.theme {
+            return;
+        }
+        self.theme = theme;
+        self.overlay.palette = theme.palette();
+        self.clear_color = theme.clear_color();
+        #[cfg(feature = "debug-ui")]
+        self.debug_ui.set_theme(theme);
+        self.event_log.push(EventKind::App, format!("tema: {theme}"));
+        self.dirty = true;
+    }
+
+    pub fn set_vsync(&mut self, enabled: bool) -> wgpu::PresentMode {
+        self.settings.vsync = Some(enabled);
+        self.surface_config.present_mode = present_mode(enabled, &self.present_modes);
+        self.surface.configure(&self.device, &self.surface_config);
+        self.frame_pacer.reset(self.surface_config.present_mode);
+        self.surface_config.present_mode
+    }
+
+    pub fn reload_shaders(&mut self) -> Result<(), String> {
+        self.scene_renderer.reload_shaders(&self.device)?;
+        self.skinning.reload_shaders(&self.device)?;
+        self.marching_cubes.reload_shaders(&self.device)?;
+        self.voxels.reload_shaders(&self.device)?;
+        self.point_cloud.reload_shaders(&self.device)?;
+        self.lines.reload_shaders(&self.device)?;
+        self.gpu_sorter.reload_shaders(&self.device)?;
+        self.noise_baker.reload_shaders(&self.device)?;
+        self.tonemapper.reload_shaders(&self.device)?;
+        self.overlay.reload_shaders(&self.device)?;
+        self.shapes.reload_shaders(&self.device)?;
+        self.sprites.reload_shaders(&self.device)?;
+        #[cfg(feature = "debug-ui")]
+        self.target_viewer.reload_shaders(&self.device)?;
+        Ok(())
+    }
+
+    // Dokunun eski görünümüne bağlı küçük resim serbest bırakılır; bir sonraki
+    // karede yeni görünüm için yeniden kaydedilir
+    #[cfg(feature = "debug-ui")]
+    fn free_thumbnail(&mut self, asset: assets::AssetRef) {
+        if let assets::AssetRef::Texture(handle) = asset
+            && let Some(thumbnail) = self.assets.texture(handle).and_then(|texture| texture.thumbnail)
+        {
+            self.debug_ui.free_texture(thumbnail);
+        }
+    }
+
+    pub fn reload_asset(&mut self, asset: assets::AssetRef) -> Result<(), String> {
+        #[cfg(feature = "debug-ui")]
+        self.free_thumbnail(asset);
+        self.assets
+            .reload(&self.device, &self.queue, asset)
+            .map_err(|e| e.to_string())
+    }
+
+    fn run_command(&mut self, line: &str) {
+        self.console.print(LineKind::Input, format!("> {line}"));
+        self.event_log.push(EventKind::App, format!("komut: {line}"));
+        let mut words = line.split_whitespace();
+        let Some(name) = words.next() else { return };
+        let args: Vec<&str> = words.collect();
+
+        match self.commands.get(name).map(|command| command.run) {
+            Some(run) => match run(self, &args) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => self.console.print(LineKind::Output, output),
+                Err(error) => self.console.print(LineKind::Error, error),
+            },
+            None => self.console.print(LineKind::Error, format!("bilinmeyen komut: {name}")),
+        }
+    }
+
+    // Dosyayı uzantısına göre açar: mesh sahneye eklenir, doku seçili nesneye
+    // atanır, nokta bulutu yüklenir
+    pub fn open_file(&mut self, path: &Path) -> Result<String, String> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "obj" => {
+                let mesh = self.assets.load_mesh(&self.device, path).map_err(|e| e.to_string())?;
+                let name = self.assets.mesh(mesh).map_or("Mesh".to_string(), |mesh| mesh.name.clone());
+                let id = self.scene.spawn_on_spiral(&name, mesh);
+                self.scene.selected = Some(id);
+                Ok(format!("{name} eklendi"))
+            }
+            "png" | "jpg" | "jpeg" => {
+                let texture = self
+                    .assets
+                    .load_texture(&self.device, &self.queue, path)
+                    .map_err(|e| e.to_string())?;
+                let name = self.assets.texture(texture).map_or(String::new(), |texture| texture.name.clone());
+                match self.scene.selected.and_then(|id| self.scene.get_mut(id)) {
+                    Some(entity) => {
+                        entity.material.get_or_insert_with(Default::default).texture = Some(texture);
+                        Ok(format!("{name} yüklendi ve {} nesnesine atandı", entity.name))
+                    }
+                    None => Ok(format!("{name} yüklendi")),
+                }
+            }
+            "ply" | "las" => self.point_cloud.load(&self.device, path),
+            _ => Err(format!("desteklenmeyen dosya türü: {}", path.display())),
+        }
+    }
+
+    // Kancalar `&mut State` aldığı için eklentiler çağrı süresince listeden çıkarılır;
+    // `visit` true dönerse kalan eklentiler atlanır
+    fn with_plugins(&mut self, mut visit: impl FnMut(&mut dyn Plugin, &mut State) -> bool) -> bool {
+        let mut plugins = std::mem::take(&mut self.plugins);
+        let busy = std::mem::replace(&mut self.plugins_busy, true);
+        let consumed = plugins.iter_mut().any(|plugin| visit(plugin.as_mut(), self));
+        self.plugins_busy = busy;
+        self.plugins = plugins;
+        consumed
+    }
+
+    // Eklenti kancalarının içinden (ör. bir eklentinin `on_event`'inde çalıştırılan
+    // konsol komutu) eklentiler listede olmadığı için hata döner
+    pub fn plugin_mut<T: Plugin>(&mut self) -> Result<&mut T, String> {
+        if self.plugins_busy {
+            return Err(format!(
+                "{} bir eklenti kancası sırasında erişilemez",
+                std::any::type_name::<T>()
+            ));
+        }
+        self.plugins
+            .iter_mut()
+            .find_map(|plugin| (plugin.as_mut() as &mut dyn Any).downcast_mut::<T>())
+            .ok_or_else(|| format!("{} eklentisi yüklü değil", std::any::type_name::<T>()))
+    }
+
+    // Olay günlüğünü ekleyerek çökme raporu yazar; hata konsola ve loga düşer
+    fn write_report(&mut self, reason: &str) -> Option<PathBuf> {
+        let events: Vec<String> = self
+            .event_log
+            .iter()
+            .rev()
+            .take(200)
+            .map(|entry| format!("{:9.3} #{} {} {}", entry.time, entry.frame, entry.kind.label(), entry.text))
+            .collect();
+        let events = events.into_iter().rev().collect::<Vec<_>>().join("\n");
+        match crash_report::write(reason, &[("son olaylar", events)]) {
+            Ok(directory) => {
+                log::error!("rapor yazıldı ({reason}): {}", directory.display());
+                self.console.print(LineKind::Output, format!("rapor yazıldı: {}", directory.display()));
+                Some(directory)
+            }
+            Err(e) => {
+                log::error!("rapor yazılamadı: {e}");
+                self.console.print(LineKind::Error, format!("rapor yazılamadı: {e}"));
+                None
+            }
+        }
+    }
+
+    // Fiziksel piksel konumundan sahneye ışın gönderir
+    fn pick(&self, position: PhysicalPosition<f64>) -> (Option<scene::EntityId>, glam::Vec3, glam::Vec3) {
+        let [ndc_x, ndc_y] = self.input_state.to_ndc(position);
+        let (origin, direction) = self.scene.camera.ray(self.input_state.aspect(), ndc_x, ndc_y);
+        let hit = self.scene.pick(origin, direction, |mesh| {
+            self.assets.mesh(mesh).map(|mesh| mesh.bounds)
+        });
+        (hit, origin, direction)
+    }
+
+    fn pick_at_cursor(&mut self) {
+        let Some(cursor) = self.input_state.cursor_physical() else { return };
+        let (hit, _, _) = self.pick(cursor);
+        self.scene.selected = hit;
+    }
+
+    #[cfg(feature = "debug-ui")]
+    fn apply_asset_action(&mut self, action: asset_browser::AssetAction) {
+        use asset_browser::AssetAction;
+        use assets::{AssetRef, MeshHandle};
+
+        let result = match action {
+            AssetAction::Drop { asset, position } => {
+                let (hit, origin, direction) = self.pick(PhysicalPosition::new(position[0] as f64, position[1] as f64));
+                match (asset, hit) {
+                    // Bir nesnenin üzerine bırakılan doku o nesnenin malzemesine atanır
+                    (AssetRef::Texture(texture), Some(id)) => {
+                        if let Some(entity) = self.scene.get_mut(id) {
+                            entity.material.get_or_insert_with(Default::default).texture = Some(texture);
+                        }
+                    }
+                    (asset, _) => {
+                        let position = scene::ground_point(origin, direction).unwrap_or(self.scene.camera.target);
+                        let (mesh, texture) = match asset {
+                            AssetRef::Mesh(mesh) => (mesh, None),
+                            AssetRef::Texture(texture) => (MeshHandle::CUBE, Some(texture)),
+                        };
+                        let name = self.assets.mesh(mesh).map_or("Mesh".to_string(), |mesh| mesh.name.clone());
+                        let id = self.scene.spawn_mesh(name, mesh, position);
+                        if let Some(material) = self.scene.get_mut(id).and_then(|entity| entity.material.as_mut()) {
+                            material.texture = texture;
+                        }
+                        self.scene.selected = Some(id);
+                    }
+                }
+                Ok(())
+            }
+            AssetAction::Reload(asset) => self.reload_asset(asset),
+            AssetAction::Unload(asset) => {
+                self.free_thumbnail(asset);
+                self.assets.unload(asset)
+            }
+            AssetAction::ReloadShaders => self.reload_shaders(),
+        };
+
+        if let Err(e) = result {
+            self.console.print(LineKind::Error, e);
+        }
+    }
+
+    pub fn perform(&mut self, action: Action) {
+        self.event_log.push(EventKind::Action, format!("{action:?}"));
+        match action {
+            Action::ToggleConsole => self.console.toggle(),
+            #[cfg(feature = "debug-ui")]
+            Action::ToggleDebugUi => self.debug_ui.visible = !self.debug_ui.visible,
+            #[cfg(feature = "debug-ui")]
+            Action::ToggleEventLog => {
+                self.event_viewer.open = !self.event_viewer.open;
+                self.debug_ui.visible |= self.event_viewer.open;
+            }
+            #[cfg(feature = "debug-ui")]
+            Action::ToggleTargetViewer => {
+                self.target_viewer.open = !self.target_viewer.open;
+                self.debug_ui.visible |= self.target_viewer.open;
+            }
+            Action::ToggleProfiler => self.profiler.visible = !self.profiler.visible,
+            Action::ToggleStats => self.stats.visible = !self.stats.visible,
+            Action::ToggleDebugDraw => self.lines.debug_draw = !self.lines.debug_draw,
+            Action::ToggleFullscreen => {
+                let fullscreen = match self.window.fullscreen() {
+                    Some(_) => None,
+                    None => Some(Fullscreen::Borderless(None)),
+                };
+                self.window.set_fullscreen(fullscreen);
+            }
+            Action::Screenshot => self.run_command("screenshot"),
+            Action::TogglePause => self.paused = !self.paused,
+            #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+            Action::ToggleWindow => {
+                let visible = !self.window.is_visible().unwrap_or(true);
+                self.window.set_visible(visible);
+                if visible {
+                    self.window.focus_window();
+                }
+            }
+            #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+            Action::OpenFile => {
+                let picked = rfd::FileDialog::new()
+                    .add_filter("Varlıklar", &["obj", "png", "jpg", "jpeg", "ply", "las"])
+                    .set_directory("assets")
+                    .pick_file();
+                if let Some(path) = picked {
+                    match self.open_file(&path) {
+                        Ok(output) => self.console.print(LineKind::Output, output),
+                        Err(error) => self.console.print(LineKind::Error, error),
+                    }
+                }
+            }
+            Action::Command(line) => self.run_command(line),
+            Action::Quit => self.exit_requested = true,
+        }
+    }
+
+    // Eklentiler, egui veya konsol olayı tüketse bile imleç, boyut ve tema
+    // takip edilir; olay dağıtımından önce çağrılır
+    fn track(&mut self, event: &WindowEvent) {
+        self.input_state.handle_event(event);
+        // Elle seçilmiş bir tema varsa sistem teması sadece kaydedilir
+        if let WindowEvent::ThemeChanged(theme) = event {
+            self.system_theme = Theme::from_system(Some(*theme));
+            self.apply_theme();
         }
     }
 
-    #[allow(unused_variables)]
     fn input(&mut self, event: &WindowEvent) -> bool {
+        if let WindowEvent::MouseInput {
+            state: ElementState::Pressed,
+            button: MouseButton::Left,
+            ..
+        } = event
+        {
+            self.latency.input();
+        }
+
+        if let WindowEvent::KeyboardInput { event, .. } = event
+            && event.state == ElementState::Pressed
+            && let PhysicalKey::Code(code) = event.physical_key
+        {
+            let action = self.bindings.action(code);
+            // Konsol açıkken sadece konsolu kapatan kısayol çalışır
+            if action == Some(Action::ToggleConsole) {
+                if !event.repeat {
+                    self.perform(Action::ToggleConsole);
+                }
+                return true;
+            }
+            #[cfg(feature = "debug-ui")]
+            let typing = self.debug_ui.wants_keyboard_input();
+            #[cfg(not(feature = "debug-ui"))]
+            let typing = false;
+            if !self.console.is_open()
+                && !typing
+                && let Some(action) = action
+            {
+                if !event.repeat {
+                    self.perform(action);
+                }
+                return true;
+            }
+        }
+
+        if let WindowEvent::KeyboardInput { event, .. } = event
+            && self.console.is_open()
+        {
+            if let Some(line) = self.console.handle_key(event, &self.commands) {
+                self.run_command(&line);
+            }
+            return true;
+        }
+
+        #[cfg(feature = "debug-ui")]
+        {
+            let consumed = self.debug_ui.on_window_event(event);
+            self.dirty |= self.debug_ui.wants_repaint();
+            if consumed {
+                return true;
+            }
+        }
+
         match event {
-            WindowEvent::CursorMoved {position, ..} => {
-                self.clear_color = wgpu::Color {
-                    r: position.x / self.size.width as f64,
-                    g: position.y / self.size.height as f64,
-                    b: 1.0,
-                    a: 1.0,
-                };
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: MouseButton::Left,
+                ..
+            } => {
+                // Arayüz panellerine tıklama sahneye geçmez
+                let cursor = self.input_state.cursor_physical().map(|p| [p.x as f32, p.y as f32]);
+                if let Some(ui) = &mut self.ui
+                    && let Some(node) = cursor.and_then(|cursor| ui.ui.hit(cursor))
+                {
+                    if let Some(line) = ui.ui.activate(node, cursor) {
+                        self.run_command(&line);
+                    }
+                    return true;
+                }
+                self.pick_at_cursor();
                 true
-            },
+            }
+            WindowEvent::CursorMoved { .. } => {
+                if let Some(ui) = &mut self.ui {
+                    let cursor = self.input_state.cursor_physical().map(|p| [p.x as f32, p.y as f32]);
+                    self.dirty |= ui.ui.hover(cursor);
+                }
+                // Hareket tüketilmez; katmanlar da imleci izleyebilir
+                false
+            }
             _ => false
         }
     }
 
+    fn begin_frame(&mut self) {
+        self.event_log.next_frame();
+        self.profiler.begin_frame(&self.device);
+        self.stats.begin_frame();
+        crash_report::record_frame(&self.settings, self.seed, &self.stats, &self.profiler);
+        self.frame_pacer.begin_frame();
+        self.frame_ring.begin(&self.device);
+        let samples = self.latency.collect(
+            &self.device,
+            self.frame_pacer.refresh_period(),
+            self.frame_pacer.synced(),
+        );
+        for sample in samples {
+            self.event_log.push(
+                EventKind::App,
+                format!("gecikme ~{:.1} ms", sample.estimated.as_secs_f64() * 1000.0),
+            );
+        }
+    }
+
     fn update(&mut self) {
-        
+        if self.paused {
+            return;
+        }
+        self.profiler.begin("update");
+        #[cfg(feature = "hot-reload")]
+        if let Some(game) = self.game.as_mut() {
+            game.update();
+        }
+        self.profiler.end();
+    }
+
+    #[cfg(feature = "hot-reload")]
+    fn render_game(&mut self) {
+        let Some(game) = self.game.as_mut() else { return };
+        // Oyun kütüphanesiyle renkler sRGB olarak paylaşılır
+        let frame = game.render(self.clear_color.to_srgb());
+
+        let [r, g, b, a] = frame.clear_color;
+        self.clear_color = Color::srgba(r, g, b, a);
+        // Sayı kütüphaneden gelir; dizinin dışına taşmasın
+        let count = (frame.cube_count as usize).min(frame.cubes.len());
+        self.scene.frame_cubes = frame.cubes[..count]
+            .iter()
+            .map(|cube| scene::Cube {
+                position: cube.position.into(),
+                rotation: glam::Quat::from_array(cube.rotation),
+                color: {
+                    let [r, g, b, a] = cube.color;
+                    Color::srgba(r, g, b, a)
+                },
+            })
+            .collect();
     }
 
     fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
-        let output = self.surface.get_current_texture()?;
+        self.dirty = false;
+        self.apply_resize();
+        self.profiler.begin("render");
+        let result = self.render_frame();
+        self.profiler.end();
+        result
+    }
+
+    fn render_frame(&mut self) -> Result<(), wgpu::SurfaceError> {
+        self.profiler.begin("acquire");
+        let acquire_start = Instant::now();
+        let output = self.surface.get_current_texture();
+        self.frame_pacer.acquired(acquire_start.elapsed());
+        self.profiler.end();
+        let output = output?;
         let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
         
         let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { 
             label: Some("CommandEncoder") 
         });
 
+        // Gecikme testinde bu kare bekleyen girdiyi işler ve flaş çizer
+        let latency_input = self.latency.take_pending();
+
+        let mut frame_capture = std::mem::take(&mut self.frame_capture_requested).then(|| {
+            FrameCapture::new(vec![
+                format!("yüzey: {}x{} {:?}", self.size.width, self.size.height, self.surface_config.format),
+                format!("ton eşleme: {:?}, pozlama {}", self.tonemapper.output, self.tonemapper.exposure),
+                format!("present mode: {:?}", self.surface_config.present_mode),
+                format!("varlık sayısı: {}", self.scene.iter().count()),
+            ])
+        });
+
+        #[cfg(feature = "hot-reload")]
         {
-            let _render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            self.profiler.begin("game");
+            self.render_game();
+            self.profiler.end();
+        }
+
+        let frame = self.frame_ring.current();
+        if !self.skinning.is_empty() {
+            self.profiler.begin("skinning");
+            self.skinning.prepare(&self.queue, frame);
+            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Skinning Pass"),
+                timestamp_writes: self.profiler.gpu_compute_pass("skinning"),
+            });
+            let record = frame_capture
+                .as_mut()
+                .map(|capture| capture.pass("Skinning Pass", vec!["compute: mesh vertex buffer'ları (storage)".into()]));
+            self.skinning.dispatch(&mut compute_pass, frame, &self.assets, record);
+            self.profiler.end();
+        }
+
+        if !self.marching_cubes.is_empty() {
+            self.profiler.begin("marching cubes");
+            self.marching_cubes.update(&self.device, &self.queue, &mut self.assets, frame);
+            if self.marching_cubes.uses_gpu() {
+                let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("Marching Cubes Pass"),
+                    timestamp_writes: self.profiler.gpu_compute_pass("marching cubes"),
+                });
+                let record = frame_capture.as_mut().map(|capture| {
+                    capture.pass("Marching Cubes Pass", vec!["compute: mesh vertex buffer'ı (storage)".into()])
+                });
+                self.marching_cubes.dispatch(&mut compute_pass, frame, &self.assets, record);
+            }
+            self.profiler.end();
+        }
+
+        self.profiler.begin("scene");
+        let aspect = self.size.width as f32 / self.size.height as f32;
+        self.scene_renderer.prepare(&self.device, &self.queue, frame, &self.scene, aspect);
+        self.voxels.prepare(&self.device);
+        let viewport = [self.size.width as f32, self.size.height as f32];
+        self.point_cloud.prepare(&self.queue, frame, &self.scene.camera, aspect, viewport);
+        self.lines.draw_debug(&self.scene, &self.assets);
+        self.lines.prepare(&self.device, &self.queue, frame, &self.scene.camera, aspect, viewport);
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: self.tonemapper.target_view(),
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(self.clear_color.to_wgpu(tonemap::HDR_FORMAT)),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: self.scene_renderer.depth_view(),
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
+                occlusion_query_set: None,
+                timestamp_writes: self.profiler.gpu_pass("scene"),
+            });
+            let record = frame_capture.as_mut().map(|capture| {
+                let [r, g, b, a] = self.clear_color.for_target(tonemap::HDR_FORMAT);
+                let [width, height] = self.tonemapper.target_size();
+                capture.pass(
+                    "Render Pass",
+                    vec![
+                        format!(
+                            "renk: HDR Target {width}x{height} {:?}, clear ({r:.3}, {g:.3}, {b:.3}, {a:.3})",
+                            tonemap::HDR_FORMAT
+                        ),
+                        format!("derinlik: {:?}, clear 1.0", renderer::DEPTH_FORMAT),
+                    ],
+                )
+            });
+            let mut traced = TracedPass::new(&mut render_pass, record);
+            self.scene_renderer.render(&mut traced, frame, &self.assets);
+            self.voxels.render(&mut traced, self.scene_renderer.camera_bind_group(frame));
+            self.point_cloud.render(&mut traced, frame);
+            self.lines.render(&mut traced, frame);
+            self.stats.record("scene", traced.finish());
+        }
+        self.profiler.end();
+
+        self.profiler.begin("tonemap");
+        self.tonemapper.prepare(&self.queue);
+        {
+            let mut tonemap_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Tonemap Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                     view: &view,
                     resolve_target: None,
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(self.clear_color),
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
                 depth_stencil_attachment: None,
                 occlusion_query_set: None,
-                timestamp_writes: None,
+                timestamp_writes: self.profiler.gpu_pass("tonemap"),
+            });
+            let record = frame_capture.as_mut().map(|capture| {
+                capture.pass(
+                    "Tonemap Pass",
+                    vec![format!("renk: yüzey {:?}, clear", self.surface_config.format)],
+                )
             });
+            let mut traced = TracedPass::new(&mut tonemap_pass, record);
+            self.tonemapper.render(&mut traced);
+            self.stats.record("tonemap", traced.finish());
         }
+        self.profiler.end();
+
+        // Konsol ekran görüntüsüne girmesin diye kopya overlay'den önce alınır
+        let capture = std::mem::take(&mut self.screenshot_requested).then(|| {
+            screenshot::Capture::record(
+                &self.device,
+                &mut encoder,
+                &output.texture,
+                self.tonemapper.white_level(),
+            )
+        });
+        if capture.is_some()
+            && let Some(frame_capture) = frame_capture.as_mut()
+        {
+            frame_capture
+                .pass("Screenshot Copy", Vec::new())
+                .push(Command::Note("copy_texture_to_buffer: yüzey -> Screenshot Buffer".into()));
+        }
+
+        #[cfg(feature = "debug-ui")]
+        {
+            self.profiler.begin("debug ui");
+            let mut asset_actions = Vec::new();
+            self.debug_ui.register_thumbnails(&self.device, &mut self.assets);
+            // Görüntü dokuları egui pass'inden önce çizilmeli
+            if self.debug_ui.visible && self.target_viewer.open {
+                let size = self.tonemapper.target_size();
+                self.target_viewer.prepare(&self.device, &self.queue, size, &mut self.debug_ui);
+                let stats = self.target_viewer.render(
+                    &self.device,
+                    &mut encoder,
+                    self.tonemapper.target_view(),
+                    self.scene_renderer.depth_view(),
+                    frame_capture.as_mut(),
+                );
+                self.stats.record("targets", stats);
+            }
+            let stats = self.debug_ui.render(
+                &self.device,
+                &self.queue,
+                &mut encoder,
+                &view,
+                [self.size.width, self.size.height],
+                self.profiler.gpu_pass("debug ui"),
+                |context| {
+                    asset_browser::show(context, &self.assets, &mut asset_actions);
+                    inspector::show(context, &mut self.scene, &self.assets);
+                    self.event_viewer.show(context, &mut self.event_log);
+                    self.target_viewer.show(context);
+                },
+            );
+            self.stats.record("debug ui", stats);
+            self.dirty |= self.debug_ui.wants_repaint();
+            if self.debug_ui.visible
+                && let Some(frame_capture) = frame_capture.as_mut()
+            {
+                let record = frame_capture.pass(
+                    "Debug UI Pass",
+                    vec![format!("renk: yüzey {:?}, load", self.surface_config.format)],
+                );
+                record.stats = stats;
+                record.push(Command::Note(format!(
+                    "egui_wgpu: {} çizim, {} üçgen (ayrıntı egui-wgpu içinde)",
+                    stats.draw_calls, stats.triangles
+                )));
+            }
+            for action in asset_actions {
+                self.apply_asset_action(action);
+            }
+            self.profiler.end();
+        }
+
+        self.profiler.begin("overlay");
+        let (width, height) = (self.size.width as f32, self.size.height as f32);
+        self.profiler.draw(&mut self.overlay, width, height);
+        self.stats.draw(&mut self.overlay, &mut self.shapes, width, &self.device, &self.profiler, &self.frame_ring);
+        self.latency.draw(&mut self.overlay, latency_input.is_some());
+        if let Some(slice) = &self.nine_slice_demo {
+            // Aynı küçük dokudan üç farklı boyutta panel
+            let mut x = 40.0;
+            for (w, h) in [(96.0, 48.0), (220.0, 120.0), (360.0, 220.0)] {
+                let y = height - h - 40.0;
+                self.sprites.nine_slice(&self.assets, slice, [x, y, w, h], Color::WHITE);
+                self.overlay.text(x + 12.0, y + 10.0, &format!("{w}x{h}"), self.overlay.palette.text);
+                x += w + 24.0;
+            }
+        }
+        if let Some(ui) = &mut self.ui {
+            let frame_ms = self.profiler.last_frame().map(|frame| frame.frame_ms);
+            let selected = self.scene.selected.and_then(|id| self.scene.get(id));
+            ui.update(
+                frame_ms,
+                self.scene.iter().count(),
+                selected.map(|entity| entity.name.as_str()),
+                self.tonemapper.exposure,
+            );
+            ui.ui.layout(self.overlay.char_size(), [width, height]);
+            ui.ui.draw(&mut self.sprites, &mut self.overlay, &self.assets);
+        }
+        self.console.draw(&mut self.overlay, width, height);
+        self.sprites.prepare(&self.device, &self.queue, self.size.width, self.size.height);
+        self.shapes.prepare(&self.device, &self.queue, self.size.width, self.size.height);
+        self.overlay.prepare(&self.device, &self.queue, self.size.width, self.size.height);
+
+        {
+            let mut overlay_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Overlay Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: self.profiler.gpu_pass("overlay"),
+            });
+            let record = frame_capture.as_mut().map(|capture| {
+                capture.pass(
+                    "Overlay Pass",
+                    vec![format!("renk: yüzey {:?}, load", self.surface_config.format)],
+                )
+            });
+            let mut traced = TracedPass::new(&mut overlay_pass, record);
+            self.sprites.render(&mut traced, &self.assets);
+            self.shapes.render(&mut traced);
+            self.overlay.render(&mut traced);
+            self.stats.record("overlay", traced.finish());
+        }
+        self.profiler.end();
+
+        self.with_plugins(|plugin, state| {
+            plugin.on_render(state, &mut encoder, &view);
+            false
+        });
+
+        self.profiler.resolve(&mut encoder);
 
         // submit will accept anything that implements IntoIter
-        self.queue.submit(std::iter::once(encoder.finish()));
+        self.profiler.begin("submit");
+        let submission = self.queue.submit(std::iter::once(encoder.finish()));
+        self.frame_ring.submitted(&self.queue, submission);
+        if let Some(input) = latency_input {
+            self.latency.submitted(&self.queue, input);
+        }
+        self.profiler.after_submit();
+        self.profiler.end();
+
+        let report = std::mem::take(&mut self.report_requested)
+            .then(|| self.write_report("`report` komutuyla istendi"))
+            .flatten();
+        if let Some(capture) = capture {
+            let saved = match &report {
+                Some(directory) => capture.save_as(&self.device, directory.join("screenshot.png")),
+                None => capture.save(&self.device),
+            };
+            match saved {
+                Ok(path) => self.console.print(LineKind::Output, format!("kaydedildi: {}", path.display())),
+                Err(e) => self.console.print(LineKind::Error, format!("ekran görüntüsü alınamadı: {e}")),
+            }
+        }
+
+        if let Some(frame_capture) = frame_capture {
+            match frame_capture.save() {
+                Ok(path) => self.console.print(LineKind::Output, format!("kare kaydedildi: {}", path.display())),
+                Err(e) => self.console.print(LineKind::Error, format!("kare yakalanamadı: {e}")),
+            }
+        }
+
+        self.profiler.begin("present");
         output.present();
+        self.frame_pacer.presented();
+        self.latency.presented();
+        self.profiler.end();
 
         Ok(())
     }
 }
 
+// Yüzeyin desteklediği kipler arasından vsync tercihine uyan sunum kipi
+fn present_mode(vsync: bool, supported: &[wgpu::PresentMode]) -> wgpu::PresentMode {
+    let preferred: &[wgpu::PresentMode] = if vsync {
+        &[wgpu::PresentMode::Fifo]
+    } else {
+        &[wgpu::PresentMode::Mailbox, wgpu::PresentMode::Immediate]
+    };
+    preferred
+        .iter()
+        .find(|mode| supported.contains(mode))
+        .copied()
+        .unwrap_or(wgpu::PresentMode::Fifo)
+}
+
+fn monitor_hz(window: &Window) -> Option<f64> {
+    window
+        .current_monitor()
+        .and_then(|monitor| monitor.refresh_rate_millihertz())
+        .map(|millihertz| millihertz as f64 / 1000.0)
+}
+
+// Olay döngüsüne başka iş parçacıklarından `EventLoopProxy` ile gönderilen olaylar
+#[derive(Debug)]
+enum AppEvent {
+    AccessKit(accesskit_winit::Event),
+    // Açılış iş parçacığı bir sonraki aşamaya geçti
+    Startup(splash::Stage),
+    GpuReady(Result<Gpu, String>),
+    // Sürücü GPU cihazını kaybetti (sıfırlama, takılma, sürücü güncellemesi)
+    DeviceLost(String),
+    #[cfg(all(any(feature = "menu-bar", feature = "tray"), any(windows, target_os = "macos")))]
+    Menu(muda::MenuId),
+    #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+    Tray(tray_icon::TrayIconEvent),
+}
+
+impl From<accesskit_winit::Event> for AppEvent {
+    fn from(event: accesskit_winit::Event) -> Self {
+        AppEvent::AccessKit(event)
+    }
+}
+
 struct App {
     window: Option<Arc<Window>>,
     state: Option<State>,
+    proxy: EventLoopProxy<AppEvent>,
+    // Ekran okuyucular için `ui` düğümlerinin aynası
+    accessibility: Option<Accessibility>,
+    // GPU hazırlanırken gösterilen açılış penceresi
+    splash: Option<splash::Splash>,
+    #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+    menu_bar: Option<menu::MenuBar>,
+    #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+    tray: Option<tray::Tray>,
+    // FixedRate modunda bir sonraki karenin zamanı
+    next_redraw: Option<Instant>,
+    // `AppBuilder` ve komut satırından gelen başlangıç ayarları
+    settings: Settings,
+    // Ana pencere bu özelliklerle, gizli olarak açılır
+    window_attributes: WindowAttributes,
+    layers: Vec<Box<dyn Layer>>,
+    // State kurulunca ona taşınır
+    plugins: Vec<Box<dyn Plugin>>,
+    commands: Vec<(&'static str, console::Command)>,
+    bindings: Vec<(KeyCode, Action)>,
+    // Katmanların `on_update` süresi için
+    last_frame: Option<Instant>,
 }
 
-impl Default for App {
-    fn default() -> Self {
-        Self { window: None, state: None }
+impl App {
+    pub fn builder() -> AppBuilder {
+        AppBuilder::default()
+    }
+
+    fn new(
+        proxy: EventLoopProxy<AppEvent>,
+        window_attributes: WindowAttributes,
+        settings: Settings,
+        layers: Vec<Box<dyn Layer>>,
+        plugins: Vec<Box<dyn Plugin>>,
+        commands: Vec<(&'static str, console::Command)>,
+        bindings: Vec<(KeyCode, Action)>,
+    ) -> Self {
+        Self {
+            window: None,
+            state: None,
+            proxy,
+            accessibility: None,
+            splash: None,
+            #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+            menu_bar: None,
+            #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+            tray: None,
+            next_redraw: None,
+            settings,
+            window_attributes,
+            layers,
+            plugins,
+            commands,
+            bindings,
+            last_frame: None,
+        }
+    }
+
+    // Adaptör ve cihaz hazır: sahne kurulur, açılış penceresi kapanır, ana pencere gösterilir
+    fn start(&mut self, event_loop: &ActiveEventLoop, gpu: Result<Gpu, String>) {
+        let Some(window) = self.window.clone() else { return };
+        // Varlık ve shader yüklemesi ana iş parçacığını bekletir; açılış penceresi
+        // son aşamayı göstererek bekler
+        if let Some(splash) = self.splash.as_mut() {
+            splash.set_stage(splash::Stage::Assets);
+            splash.draw();
+        }
+        let state = gpu
+            .map_err(Box::<dyn Error>::from)
+            .and_then(|gpu| State::new(window.clone(), gpu, self.settings.clone()));
+        self.splash = None;
+        match state {
+            Ok(mut state) => {
+                state.event_log.push(EventKind::App, "resumed: pencere ve GPU hazır");
+                #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+                match menu::MenuBar::new(&state.window, &state.bindings) {
+                    Ok(menu_bar) => self.menu_bar = Some(menu_bar),
+                    Err(e) => log::error!("{e}"),
+                }
+                #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+                match tray::Tray::new() {
+                    Ok(tray) => self.tray = Some(tray),
+                    Err(e) => log::error!("{e}"),
+                }
+                // muda ve tray-icon olayları kendi iş parçacıklarından gelir;
+                // işleyici süreç başına bir kez kurulabildiği için menü çubuğu
+                // ve bildirim alanı aynı işleyiciyi paylaşır
+                #[cfg(all(any(feature = "menu-bar", feature = "tray"), any(windows, target_os = "macos")))]
+                {
+                    let proxy = std::sync::Mutex::new(self.proxy.clone());
+                    muda::MenuEvent::set_event_handler(Some(move |event: muda::MenuEvent| {
+                        if let Ok(proxy) = proxy.lock() {
+                            let _ = proxy.send_event(AppEvent::Menu(event.id));
+                        }
+                    }));
+                }
+                #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+                {
+                    let proxy = std::sync::Mutex::new(self.proxy.clone());
+                    tray_icon::TrayIconEvent::set_event_handler(Some(move |event| {
+                        if let Ok(proxy) = proxy.lock() {
+                            let _ = proxy.send_event(AppEvent::Tray(event));
+                        }
+                    }));
+                }
+                for (name, command) in self.commands.drain(..) {
+                    state.commands.register(name, command);
+                }
+                for (key, action) in self.bindings.drain(..) {
+                    state.bindings.bind(key, action);
+                }
+                state.plugins = std::mem::take(&mut self.plugins);
+                state.with_plugins(|plugin, state| {
+                    plugin.on_startup(state);
+                    false
+                });
+                for layer in &mut self.layers {
+                    layer.on_attach(&mut state);
+                }
+                window.set_visible(true);
+                window.focus_window();
+                self.state = Some(state);
+                log::info!("window & state hazır");
+            }
+            Err(e) => {
+                log::error!("bir error yaklaşıyor efendim: {}", e);
+                match crash_report::write(&format!("başlatılamadı: {e}"), &[]) {
+                    Ok(directory) => log::error!("rapor yazıldı: {}", directory.display()),
+                    Err(e) => log::error!("rapor yazılamadı: {e}"),
+                }
+                event_loop.exit();
+            }
+        }
     }
 }
 
-impl ApplicationHandler for App {
+impl ApplicationHandler<AppEvent> for App {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.window.is_none() {
             log::info!("winit & wgpu başlatılıyor");
-            let window_attributes = WindowAttributes::default().with_title("winit & wgpu");
+            // GPU hazır olana kadar ana pencere gizli kalır ve yerine açılış penceresi gösterilir
+            match splash::Splash::new(event_loop, &self.window_attributes.title, self.settings.theme) {
+                Ok(splash) => self.splash = Some(splash),
+                Err(e) => log::warn!("açılış penceresi açılamadı: {e}"),
+            }
+            // AccessKit bağdaştırıcısı pencere ilk kez gösterilmeden kurulmalı
+            let window_attributes = self.window_attributes.clone().with_visible(false);
 
             let window = Arc::new(
                 event_loop
                     .create_window(window_attributes)
                     .expect("Pencere oluşturulamadı"),
             );
+            self.accessibility = Some(Accessibility::new(event_loop, &window, self.proxy.clone()));
             self.window = Some(window.clone());
 
-            match pollster::block_on(State::new(window)) {
-                Ok(state) => {
-                    self.state = Some(state);
-                    log::info!("window & state hazır");
-                }
+            let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+                backends: wgpu::Backends::all(),
+                ..Default::default()
+            });
+            let surface = match instance.create_surface(window) {
+                Ok(surface) => surface,
                 Err(e) => {
-                    log::error!("bir error yaklaşıyor efendim: {}", e);
+                    log::error!("yüzey oluşturulamadı: {e}");
                     event_loop.exit();
+                    return;
                 }
-            }
+            };
+            let proxy = self.proxy.clone();
+            std::thread::spawn(move || {
+                let gpu = pollster::block_on(Gpu::request(instance, surface, &proxy));
+                let _ = proxy.send_event(AppEvent::GpuReady(gpu));
+            });
         } else {
             log::info!("window resume");
+            if let Some(state) = self.state.as_mut() {
+                state.event_log.push(EventKind::App, "resumed");
+            }
+        }
+    }
+
+    fn suspended(&mut self, _event_loop: &ActiveEventLoop) {
+        if let Some(state) = self.state.as_mut() {
+            state.event_log.push(EventKind::App, "suspended");
         }
     }
 
-    fn window_event(&mut self, event_loop: &ActiveEventLoop, _window_id: WindowId, event: WindowEvent) {
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId, event: WindowEvent) {
+        if let Some(splash) = self.splash.as_mut()
+            && splash.id() == window_id
+        {
+            match event {
+                WindowEvent::RedrawRequested => splash.draw(),
+                WindowEvent::CloseRequested => event_loop.exit(),
+                _ => {}
+            }
+            return;
+        }
+        if let (Some(accessibility), Some(window)) = (self.accessibility.as_mut(), self.window.as_ref()) {
+            accessibility.process_event(window, &event);
+        }
 
         let state = match self.state.as_mut() {
             Some(s) => s,
@@ -195,37 +1430,208 @@ impl ApplicationHandler for App {
             }
         };
 
-        if !state.input(&event) {
-            match event {
-                WindowEvent::CloseRequested
-                | WindowEvent::KeyboardInput {
-                    event: KeyEvent { state: ElementState::Pressed, physical_key: winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape),..},..}
-                => event_loop.exit(),
-                
-                WindowEvent::Resized(physical_size) => state.resize(physical_size),
+        state.event_log.push_window_event(&event);
+        // Pencere başka bir monitöre geçmiş olabilir
+        if let WindowEvent::Moved(_) = event
+            && let Some(window) = self.window.as_ref()
+        {
+            state.frame_pacer.set_monitor_hz(monitor_hz(window));
+        }
+
+        // Eklentiler olayları uygulamadan önce, katmanlar yalnızca uygulamanın
+        // tüketmediklerini görür; en üstteki katman önce. Kare çizimi, kapatma,
+        // boyut değişikliği ve girdi takibi tüketilse de yapılır, yoksa bir eklenti
+        // pencereyi dondurabilir ya da yüzeyi eski boyutta bırakabilirdi.
+        state.track(&event);
+        let consumed = state.with_plugins(|plugin, state| plugin.on_event(state, &event))
+            || state.input(&event)
+            || self.layers.iter_mut().rev().any(|layer| layer.on_event(state, &event));
+        // OnDemand'da sadece bir şeyi değiştiren olaylar yeni kare ister; tüketmeden
+        // görüntüyü değiştirenler (tema, `ui` vurgusu, egui) `dirty`'yi kendileri işaretler
+        if consumed || matches!(event, WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. }) {
+            state.dirty = true;
+        }
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            
+            WindowEvent::Resized(physical_size) => state.resize(physical_size),
+            
+            WindowEvent::RedrawRequested => {
+                state.begin_frame();
+                state.update();
+                let now = Instant::now();
+                let dt = self.last_frame.map_or(0.0, |last| (now - last).as_secs_f32());
+                self.last_frame = Some(now);
+                state.with_plugins(|plugin, state| {
+                    plugin.on_update(state, dt);
+                    false
+                });
+                for layer in &mut self.layers {
+                    if !state.paused {
+                        layer.on_update(state, dt);
+                    }
+                    layer.on_draw(state);
+                }
                 
-                WindowEvent::RedrawRequested => {
-                    if let Some(window) = self.window.as_ref() {
-                        window.request_redraw();
+                match state.render() {
+                    Ok(_) => state.surface_timeouts = 0,
+                    Err(e @ (wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                        state.event_log.push(EventKind::App, format!("yüzey hatası: {e}"));
+                        state.reconfigure()
+                    },
+                    Err(e @ (wgpu::SurfaceError::OutOfMemory | wgpu::SurfaceError::Other)) => {
+                        log::error!("{e}");
+                        state.write_report(&format!("yüzey hatası: {e}"));
+                        event_loop.exit();
+                    },
+                    Err(wgpu::SurfaceError::Timeout) => {
+                        log::warn!("Surface timeout");
+                        state.surface_timeouts += 1;
+                        // Rapor bir kez yazılır; sayaç bir kare başarıyla çizilince sıfırlanır
+                        if state.surface_timeouts == HANG_TIMEOUTS {
+                            state.write_report(&format!(
+                                "yüzey {HANG_TIMEOUTS} kez üst üste zaman aşımına uğradı, GPU takılmış olabilir"
+                            ));
+                        }
+                    },
+                }
+            }
+            _=> {}
+        }
+    }
+
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, event: AppEvent) {
+        match event {
+            AppEvent::Startup(stage) => {
+                if let Some(splash) = self.splash.as_mut() {
+                    splash.set_stage(stage);
+                }
+            }
+            AppEvent::GpuReady(gpu) => self.start(event_loop, gpu),
+            AppEvent::DeviceLost(reason) => {
+                log::error!("GPU cihazı kaybedildi: {reason}");
+                let reason = format!("GPU cihazı kaybedildi: {reason}");
+                match self.state.as_mut() {
+                    Some(state) => {
+                        state.write_report(&reason);
+                        state.exit_requested = true;
+                    }
+                    None => {
+                        if let Err(e) = crash_report::write(&reason, &[]) {
+                            log::error!("rapor yazılamadı: {e}");
+                        }
+                        event_loop.exit();
+                    }
+                }
+            }
+            AppEvent::AccessKit(event) => {
+                use accesskit_winit::WindowEvent as AccessKitEvent;
+                match event.window_event {
+                    AccessKitEvent::InitialTreeRequested => {
+                        if let Some(accessibility) = self.accessibility.as_mut() {
+                            accessibility.invalidate();
+                        }
+                    }
+                    AccessKitEvent::ActionRequested(request) => {
+                        let Some(state) = self.state.as_mut() else { return };
+                        state.event_log.push(EventKind::App, format!("erişilebilirlik: {:?}", request.action));
+                        let line = state.ui.as_mut().and_then(|ui| accessibility::perform(&mut ui.ui, &request));
+                        if let Some(line) = line {
+                            state.run_command(&line);
+                        }
+                        state.dirty = true;
+                    }
+                    AccessKitEvent::AccessibilityDeactivated => {}
+                }
+            }
+            #[cfg(all(any(feature = "menu-bar", feature = "tray"), any(windows, target_os = "macos")))]
+            AppEvent::Menu(id) => {
+                let mut action = None;
+                #[cfg(all(feature = "menu-bar", any(windows, target_os = "macos")))]
+                if let Some(menu_bar) = self.menu_bar.as_ref() {
+                    action = action.or(menu_bar.action(&id));
+                }
+                #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+                if let Some(tray) = self.tray.as_ref() {
+                    action = action.or(tray.action(&id));
+                }
+                let Some((action, state)) = action.zip(self.state.as_mut()) else { return };
+                state.perform(action);
+                state.dirty = true;
+            }
+            #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+            AppEvent::Tray(event) => {
+                use tray_icon::{MouseButton, MouseButtonState, TrayIconEvent};
+                // Sol tık menü yerine pencereyi gizler/gösterir
+                if let TrayIconEvent::Click {
+                    button: MouseButton::Left,
+                    button_state: MouseButtonState::Up,
+                    ..
+                } = event
+                    && let Some(state) = self.state.as_mut()
+                {
+                    state.perform(Action::ToggleWindow);
+                    state.dirty = true;
+                }
+            }
+        }
+    }
+
+    // Yeni karenin ne zaman isteneceğine çizim politikası karar verir
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let (Some(window), Some(state)) = (self.window.as_ref(), self.state.as_ref()) else {
+            return;
+        };
+        // Çıkış pencere olayından, menüden veya ekran okuyucudan istenmiş olabilir
+        if state.exit_requested {
+            event_loop.exit();
+            return;
+        }
+        if let Some(accessibility) = self.accessibility.as_mut() {
+            accessibility.update(state.ui.as_ref().map(|ui| &ui.ui));
+        }
+        let visible = window.is_visible().unwrap_or(true);
+        #[cfg(all(feature = "tray", any(windows, target_os = "macos")))]
+        if let Some(tray) = self.tray.as_mut() {
+            tray.sync(visible, state.paused);
+        }
+        // Bildirim alanına gizlenmiş pencere çizilmez
+        if !visible {
+            self.next_redraw = None;
+            event_loop.set_control_flow(ControlFlow::Wait);
+            return;
+        }
+        let policy = if state.paused { RedrawPolicy::OnDemand } else { state.settings.redraw_policy };
+        match policy {
+            RedrawPolicy::Continuous => {
+                self.next_redraw = None;
+                match state.frame_pacer.next_frame_start(state.settings.pacing) {
+                    Some(start) if start > Instant::now() => {
+                        event_loop.set_control_flow(ControlFlow::WaitUntil(start));
                     }
-                    
-                    state.update();
-                    
-                    match state.render() {
-                        Ok(_) => {},
-                        Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
-                            state.resize(state.size)
-                        },
-                        Err(wgpu::SurfaceError::OutOfMemory) | Err(wgpu::SurfaceError::Other) => {
-                            log::error!("OutOfMemory");
-                            event_loop.exit();
-                        },
-                        Err(wgpu::SurfaceError::Timeout) => {
-                            log::warn!("Surface timeout")
-                        },
+                    _ => {
+                        event_loop.set_control_flow(ControlFlow::Poll);
+                        window.request_redraw();
                     }
                 }
-                _=> {}
+            }
+            RedrawPolicy::OnDemand => {
+                self.next_redraw = None;
+                event_loop.set_control_flow(ControlFlow::Wait);
+                if state.dirty {
+                    window.request_redraw();
+                }
+            }
+            RedrawPolicy::FixedRate(hz) => {
+                let now = Instant::now();
+                let interval = Duration::from_secs_f64(1.0 / hz);
+                let next = self.next_redraw.get_or_insert(now);
+                if now >= *next {
+                    window.request_redraw();
+                    // Geride kalındıysa kareleri toplu çizmek yerine ileri atlanır
+                    *next = (*next + interval).max(now);
+                }
+                event_loop.set_control_flow(ControlFlow::WaitUntil(*next));
             }
         }
     }
@@ -236,17 +1642,13 @@ impl ApplicationHandler for App {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    env_logger::init();
-
-    log::info!("Olay döngüsü oluşturuluyor...");
-    let event_loop = EventLoop::new().unwrap();
+    crash_report::init();
 
-    event_loop.set_control_flow(ControlFlow::Poll);
-
-    let mut app = App::default();
-
-    event_loop.run_app(&mut app)?;
-
-    log::info!("Olay döngüsü tamamlandı.");
-    Ok(())
-}
\ No newline at end of file
+    App::builder()
+        .title("winit & wgpu")
+        .size(1280, 720)
+        .vsync(true)
+        .with_plugin(input_display::InputDisplay::default())
+        .with_layer(demo_layer::DemoLayer)
+        .run()
+}
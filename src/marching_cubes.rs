@@ -0,0 +1,494 @@
+use crate::assets::{AssetManager, MeshHandle, MeshVertex};
+use crate::frame_capture::{Command, PassRecord};
+use crate::frame_context::{FRAMES_IN_FLIGHT, FrameContext, PerFrame};
+use crate::noise;
+use bytemuck::Zeroable;
+use glam::{UVec3, Vec3};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 4;
+// Bir hücre en fazla beş üçgen üretir; tablo satırları -1 ile biter
+const MAX_CELL_VERTICES: usize = 15;
+const TABLE_ROW: usize = 16;
+
+// Örnek alanın ızgarası: her eksende hücre sayısı ve kapladığı küp
+const BLOB_CELLS: u32 = 24;
+const BLOB_EXTENT: f32 = 1.5;
+
+// Köşe i'nin birim küpteki konumu (i & 1, (i >> 1) & 1, (i >> 2) & 1)
+fn corner_offset(corner: usize) -> Vec3 {
+    Vec3::new((corner & 1) as f32, ((corner >> 1) & 1) as f32, ((corner >> 2) & 1) as f32)
+}
+
+// Kenar e, `e / 4` ekseni boyuncadır; alt köşe, dört kenardan hangisi
+// olduğunu veren iki bitin arasına eksen bitine 0 eklenerek bulunur.
+// marching_cubes.wgsl'deki `edge_corners` ile aynı numaralandırma.
+fn edge_corners(edge: usize) -> (usize, usize) {
+    let axis = edge / 4;
+    let k = edge % 4;
+    let lower = (k & ((1 << axis) - 1)) | ((k >> axis) << (axis + 1));
+    (lower, lower | 1 << axis)
+}
+
+fn edge_between(a: usize, b: usize) -> usize {
+    (0..12)
+        .find(|edge| edge_corners(*edge) == (a.min(b), a.max(b)))
+        .expect("komşu köşeler")
+}
+
+// 256 köşe durumu için üçgen listesi (kenar numaraları). Elle yazılmış klasik
+// tablo yerine yüzlerden üretilir: her yüzde işaret değiştiren kenarlar
+// bölütlerle bağlanır, bölütler halkalara zincirlenir ve halkalar yelpaze
+// şeklinde üçgenlenir. Belirsiz (çapraz) yüzlerde içerideki köşeler her zaman
+// ayrılır; komşu hücreler aynı yüzü aynı şekilde böldüğünden yüzey deliksizdir.
+pub fn case_table() -> &'static [[i8; TABLE_ROW]; 256] {
+    static TABLE: OnceLock<[[i8; TABLE_ROW]; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| std::array::from_fn(build_case))
+}
+
+fn build_case(case: usize) -> [i8; TABLE_ROW] {
+    let inside = |corner: usize| case & (1 << corner) != 0;
+    let mut links: [Vec<usize>; 12] = Default::default();
+    let mut link = |a: usize, b: usize| {
+        links[a].push(b);
+        links[b].push(a);
+    };
+
+    for axis in 0..3 {
+        for side in 0..2 {
+            let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+            let base = side << axis;
+            let ring = [base, base | 1 << u, base | 1 << u | 1 << v, base | 1 << v];
+            // `edges[k]`, ring[k] ile ring[k + 1] arasındaki kenar
+            let edges: [usize; 4] = std::array::from_fn(|k| edge_between(ring[k], ring[(k + 1) % 4]));
+            let crossing: Vec<usize> = (0..4).filter(|k| inside(ring[*k]) != inside(ring[(k + 1) % 4])).collect();
+            match crossing[..] {
+                [a, b] => link(edges[a], edges[b]),
+                [_, _, _, _] => {
+                    for k in (0..4).filter(|k| inside(ring[*k])) {
+                        link(edges[(k + 3) % 4], edges[k]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut row = [-1; TABLE_ROW];
+    let mut written = 0;
+    let mut visited = [false; 12];
+    for start in 0..12 {
+        if visited[start] || links[start].is_empty() {
+            continue;
+        }
+        let mut polygon = vec![start];
+        visited[start] = true;
+        let (mut previous, mut current) = (start, links[start][0]);
+        while current != start {
+            visited[current] = true;
+            polygon.push(current);
+            let next = if links[current][0] == previous { links[current][1] } else { links[current][0] };
+            (previous, current) = (current, next);
+        }
+
+        // Halka, kenarların içeriden dışarıya bakan yönüne göre saat yönünün
+        // tersine çevrilir; böylece ön yüzler alanın dışını gösterir
+        let midpoint = |edge: usize| {
+            let (a, b) = edge_corners(edge);
+            (corner_offset(a) + corner_offset(b)) * 0.5
+        };
+        let outward: Vec3 = polygon
+            .iter()
+            .map(|edge| {
+                let (a, b) = edge_corners(*edge);
+                let direction = corner_offset(b) - corner_offset(a);
+                if inside(a) { direction } else { -direction }
+            })
+            .sum();
+        let normal: Vec3 = (0..polygon.len())
+            .map(|i| midpoint(polygon[i]).cross(midpoint(polygon[(i + 1) % polygon.len()])))
+            .sum();
+        if normal.dot(outward) < 0.0 {
+            polygon.reverse();
+        }
+        for i in 1..polygon.len() - 1 {
+            for edge in [polygon[0], polygon[i], polygon[i + 1]] {
+                row[written] = edge as i8;
+                written += 1;
+            }
+        }
+    }
+    assert!(written <= MAX_CELL_VERTICES, "durum {case}: {written} köşe");
+    row
+}
+
+// Düzenli ızgarada örneklenmiş skaler alan; `size` her eksendeki örnek sayısı
+pub struct ScalarField {
+    pub size: UVec3,
+    pub origin: Vec3,
+    pub spacing: f32,
+    pub values: Vec<f32>,
+}
+
+impl ScalarField {
+    pub fn sample(size: UVec3, origin: Vec3, spacing: f32, field: impl Fn(Vec3) -> f32) -> Self {
+        let mut values = Vec::with_capacity((size.x * size.y * size.z) as usize);
+        for z in 0..size.z {
+            for y in 0..size.y {
+                for x in 0..size.x {
+                    values.push(field(origin + UVec3::new(x, y, z).as_vec3() * spacing));
+                }
+            }
+        }
+        Self {
+            size,
+            origin,
+            spacing,
+            values,
+        }
+    }
+
+    pub fn value(&self, p: UVec3) -> f32 {
+        self.values[((p.z * self.size.y + p.y) * self.size.x + p.x) as usize]
+    }
+
+    fn position(&self, p: UVec3) -> Vec3 {
+        self.origin + p.as_vec3() * self.spacing
+    }
+
+    // Merkezi fark; kenarlarda tek yönlü
+    fn gradient(&self, p: UVec3) -> Vec3 {
+        let axis = |axis: usize| {
+            let mut low = p;
+            let mut high = p;
+            low[axis] = low[axis].saturating_sub(1);
+            high[axis] = (high[axis] + 1).min(self.size[axis] - 1);
+            (self.value(high) - self.value(low)) / ((high[axis] - low[axis]).max(1) as f32 * self.spacing)
+        };
+        Vec3::new(axis(0), axis(1), axis(2))
+    }
+}
+
+// `iso` değerinden büyük bölge içeri sayılır. Komşu hücreler kenar
+// köşelerini paylaşır; normaller alan gradyanından gelir.
+pub fn polygonize(field: &ScalarField, iso: f32) -> (Vec<MeshVertex>, Vec<u32>) {
+    let table = case_table();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    // (alt köşe, eksen) -> köşe indeksi
+    let mut shared: HashMap<(UVec3, usize), u32> = HashMap::new();
+
+    let cells = field.size.saturating_sub(UVec3::ONE);
+    for z in 0..cells.z {
+        for y in 0..cells.y {
+            for x in 0..cells.x {
+                let cell = UVec3::new(x, y, z);
+                let corner = |corner: usize| cell + corner_offset(corner).as_uvec3();
+                let case = (0..8).filter(|c| field.value(corner(*c)) > iso).fold(0, |case, c| case | 1 << c);
+                for edge in table[case].iter().take_while(|edge| **edge >= 0) {
+                    let edge = *edge as usize;
+                    let (a, b) = edge_corners(edge);
+                    let (a, b) = (corner(a), corner(b));
+                    let index = *shared.entry((a, edge / 4)).or_insert_with(|| {
+                        let (value_a, value_b) = (field.value(a), field.value(b));
+                        let t = ((iso - value_a) / (value_b - value_a)).clamp(0.0, 1.0);
+                        let position = field.position(a).lerp(field.position(b), t);
+                        let normal = -field.gradient(a).lerp(field.gradient(b), t).normalize_or(Vec3::Y);
+                        vertices.push(MeshVertex {
+                            position: position.into(),
+                            normal: normal.into(),
+                            uv: [position.x * 0.5, position.z * 0.5],
+                        });
+                        vertices.len() as u32 - 1
+                    });
+                    indices.push(index);
+                }
+            }
+        }
+    }
+    (vertices, indices)
+}
+
+// Örnek alan: zamanla kaynayan gürültülü küre, içerisi pozitif.
+// marching_cubes.wgsl'deki `blob` ile aynı.
+pub fn blob(p: Vec3, time: f32) -> f32 {
+    let flow = Vec3::new(0.0, time * 0.6, time * 0.3);
+    1.0 - p.length() + 0.45 * noise::perlin3(p * 1.8 + flow, 7)
+}
+
+fn blob_field(time: f32) -> ScalarField {
+    let spacing = 2.0 * BLOB_EXTENT / BLOB_CELLS as f32;
+    ScalarField::sample(UVec3::splat(BLOB_CELLS + 1), Vec3::splat(-BLOB_EXTENT), spacing, |p| blob(p, time))
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    origin: [f32; 3],
+    spacing: f32,
+    cells: [u32; 3],
+    time: f32,
+    iso: f32,
+    _padding: [f32; 3],
+}
+
+struct ParamsBuffer {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+// Compute sürümü: her hücre sabit 15 köşelik yuvasına yazar, kullanılmayan
+// yuvalar sıfırlanır (alanı olmayan üçgenler). Köşe sayısı GPU'dan geri
+// okunmadan çizilebilsin diye index buffer 0..n sıralı doldurulur.
+struct GpuBlob {
+    mesh: MeshHandle,
+    params: PerFrame<ParamsBuffer>,
+}
+
+// Skaler alandan her karede yeniden üretilen örnek mesh'ler: CPU sürümü
+// mesh API'siyle yüklenir, GPU sürümü storage mesh'e compute pass'te yazılır
+pub struct MarchingCubes {
+    pipeline: wgpu::ComputePipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+    table: wgpu::Buffer,
+    cpu: Option<MeshHandle>,
+    gpu: Option<GpuBlob>,
+    start: Instant,
+    // Son CPU yeniden üretiminin süresi ve üçgen sayısı
+    pub cpu_time: Duration,
+    pub cpu_triangles: usize,
+}
+
+impl MarchingCubes {
+    pub fn new(device: &wgpu::Device) -> Result<Self, String> {
+        let buffer = |binding: u32, ty: wgpu::BufferBindingType| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Marching Cubes Bind Group Layout"),
+            entries: &[
+                buffer(0, wgpu::BufferBindingType::Uniform),
+                buffer(1, wgpu::BufferBindingType::Storage { read_only: true }),
+                buffer(2, wgpu::BufferBindingType::Storage { read_only: false }),
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Marching Cubes Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout)?;
+        let table: Vec<i32> = case_table().iter().flatten().map(|edge| *edge as i32).collect();
+        let table = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Marching Cubes Table"),
+            contents: bytemuck::cast_slice(&table),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group_layout,
+            table,
+            cpu: None,
+            gpu: None,
+            start: Instant::now(),
+            cpu_time: Duration::ZERO,
+            cpu_triangles: 0,
+        })
+    }
+
+    fn create_pipeline(device: &wgpu::Device, layout: &wgpu::PipelineLayout) -> Result<wgpu::ComputePipeline, String> {
+        let shader = crate::shaders::create_module(device, "marching_cubes.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Marching Cubes Pipeline"),
+                layout: Some(layout),
+                module: &shader,
+                entry_point: Some("cs_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout)?;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpu.is_none() && self.gpu.is_none()
+    }
+
+    pub fn uses_gpu(&self) -> bool {
+        self.gpu.is_some()
+    }
+
+    // CPU'da üretilen örnek mesh; ilk çağrıda oluşturulur
+    pub fn cpu_blob(&mut self, device: &wgpu::Device, assets: &mut AssetManager) -> MeshHandle {
+        if let Some(mesh) = self.cpu {
+            return mesh;
+        }
+        let (vertices, indices) = polygonize(&blob_field(0.0), 0.0);
+        let mesh = assets.add_mesh(device, "kabarcık", None, &vertices, &indices);
+        self.cpu = Some(mesh);
+        mesh
+    }
+
+    // GPU'da üretilen örnek mesh; ilk çağrıda oluşturulur
+    pub fn gpu_blob(&mut self, device: &wgpu::Device, assets: &mut AssetManager) -> MeshHandle {
+        if let Some(gpu) = &self.gpu {
+            return gpu.mesh;
+        }
+        // İlk kare gelene kadar CPU sonucu gösterilir; sınır kutusu da buradan gelir
+        let slots = (BLOB_CELLS.pow(3) as usize) * MAX_CELL_VERTICES;
+        let (mut vertices, indices) = polygonize(&blob_field(0.0), 0.0);
+        vertices = indices.iter().map(|index| vertices[*index as usize]).collect();
+        vertices.resize(slots, MeshVertex::zeroed());
+        let indices: Vec<u32> = (0..slots as u32).collect();
+        let mesh = assets.add_storage_mesh(device, "kabarcık (GPU)", &vertices, &indices);
+        let output = &assets.mesh(mesh).expect("yeni eklenen mesh").vertex_buffer;
+
+        let params = PerFrame::new(FRAMES_IN_FLIGHT, |_| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Marching Cubes Params"),
+                size: std::mem::size_of::<ParamsRaw>() as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Marching Cubes Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: self.table.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: output.as_entire_binding(),
+                    },
+                ],
+            });
+            ParamsBuffer { buffer, bind_group }
+        });
+        self.gpu = Some(GpuBlob { mesh, params });
+        mesh
+    }
+
+    // CPU mesh'i yeniden üretip yükler, GPU sürümünün parametrelerini yazar
+    pub fn update(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, assets: &mut AssetManager, frame: FrameContext) {
+        let time = self.start.elapsed().as_secs_f32();
+        if let Some(mesh) = self.cpu {
+            let start = Instant::now();
+            let (vertices, indices) = polygonize(&blob_field(time), 0.0);
+            // Mesh tarayıcıdan kaldırıldıysa sessizce durulur
+            if assets.update_mesh(device, queue, mesh, &vertices, &indices).is_err() {
+                self.cpu = None;
+            }
+            self.cpu_time = start.elapsed();
+            self.cpu_triangles = indices.len() / 3;
+        }
+        if let Some(gpu) = &self.gpu {
+            let spacing = 2.0 * BLOB_EXTENT / BLOB_CELLS as f32;
+            let raw = ParamsRaw {
+                origin: [-BLOB_EXTENT; 3],
+                spacing,
+                cells: [BLOB_CELLS; 3],
+                time,
+                iso: 0.0,
+                _padding: [0.0; 3],
+            };
+            queue.write_buffer(&gpu.params.get(frame).buffer, 0, bytemuck::bytes_of(&raw));
+        }
+    }
+
+    // Kaldırılmış mesh atlanır. İşlenen hücre sayısını döndürür.
+    pub fn dispatch(
+        &self,
+        pass: &mut wgpu::ComputePass<'_>,
+        frame: FrameContext,
+        assets: &AssetManager,
+        record: Option<&mut PassRecord>,
+    ) -> u32 {
+        let Some(gpu) = &self.gpu else { return 0 };
+        let Some(mesh) = assets.mesh(gpu.mesh) else { return 0 };
+        let groups = BLOB_CELLS.div_ceil(WORKGROUP_SIZE);
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &gpu.params.get(frame).bind_group, &[]);
+        pass.dispatch_workgroups(groups, groups, groups);
+        if let Some(record) = record {
+            record.push(Command::SetPipeline("Marching Cubes Pipeline".into()));
+            record.push(Command::SetBindGroup {
+                index: 0,
+                name: "Marching Cubes Bind Group".into(),
+            });
+            record.push(Command::Note(format!(
+                "dispatch_workgroups({groups}, {groups}, {groups}): \"{}\" {BLOB_CELLS}³ hücre",
+                mesh.name
+            )));
+        }
+        BLOB_CELLS.pow(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_rows_fit() {
+        for (case, row) in case_table().iter().enumerate() {
+            let count = row.iter().take_while(|edge| **edge >= 0).count();
+            assert!(count <= MAX_CELL_VERTICES, "durum {case}: {count} köşe");
+            assert_eq!(count % 3, 0, "durum {case}: yarım üçgen");
+            assert!(row[count..].iter().all(|edge| *edge == -1), "durum {case}: bitişten sonra kenar");
+            assert!(row[..count].iter().all(|edge| (0..12).contains(edge)), "durum {case}: geçersiz kenar");
+        }
+        assert_eq!(case_table()[0][0], -1);
+        assert_eq!(case_table()[255][0], -1);
+    }
+
+    // Küre ızgaranın içinde kaldığından yüzey kapalı olmalı: her yönlü kenar bir
+    // kez, tersi de başka bir üçgende bir kez görünür
+    #[test]
+    fn sphere_is_closed() {
+        let field = ScalarField::sample(UVec3::splat(17), Vec3::splat(-1.6), 0.2, |p| 1.0 - p.length());
+        let (vertices, indices) = polygonize(&field, 0.0);
+        assert!(!indices.is_empty());
+        assert_eq!(indices.len() % 3, 0);
+
+        let mut edges: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut volume = 0.0;
+        for triangle in indices.chunks(3) {
+            for i in 0..3 {
+                *edges.entry((triangle[i], triangle[(i + 1) % 3])).or_default() += 1;
+            }
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(vertices[triangle[i] as usize].position));
+            volume += a.dot(b.cross(c)) / 6.0;
+        }
+        for (&(a, b), &count) in &edges {
+            assert_eq!(count, 1, "kenar {a}-{b} {count} kez");
+            assert_eq!(edges.get(&(b, a)), Some(&1), "kenar {a}-{b} açık");
+        }
+        // Ön yüzler dışarı bakıyorsa hacim pozitif ve birim küreninkine yakın
+        let sphere = 4.0 / 3.0 * std::f32::consts::PI;
+        assert!((volume - sphere).abs() < sphere * 0.05, "hacim {volume}");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::actions::{Action, KeyBindings};
+use muda::accelerator::Accelerator;
+use muda::{Menu, MenuId, MenuItem, PredefinedMenuItem, Submenu};
+use winit::window::Window;
+#[cfg(windows)]
+use windows_sys::Win32::UI::WindowsAndMessaging::{HACCEL, MSG, TranslateAcceleratorW};
+
+#[cfg(windows)]
+thread_local! {
+    // Mesaj kancası olay döngüsüyle birlikte, menüden önce kurulur; tablo
+    // menü oluşturulunca buraya yazılır
+    static ACCELERATORS: std::cell::Cell<HACCEL> = const { std::cell::Cell::new(std::ptr::null_mut()) };
+}
+
+// Olay döngüsünün Windows mesaj kancası (`AppBuilder::run`): Ctrl+O gibi menü
+// kısayollarını menü olayına çevirir. true dönerse mesaj winit'e iletilmez.
+#[cfg(windows)]
+pub fn translate_accelerator(msg: *const std::ffi::c_void) -> bool {
+    let table = ACCELERATORS.get();
+    if table.is_null() {
+        return false;
+    }
+    let msg = msg.cast::<MSG>();
+    // SAFETY: winit kancaya geçerli bir MSG işaretçisi verir
+    unsafe { TranslateAcceleratorW((*msg).hwnd, table, msg) != 0 }
+}
+
+// Windows ve macOS'un yerel menü çubuğu. Öğeler `Action` tetikler; tıklamalar
+// `AppEvent::Menu` olarak olay döngüsüne gelir.
+// Linux'ta muda GTK penceresi istediği için menü çubuğu yoktur.
+pub struct MenuBar {
+    // Menü düşerse pencereden kaldırılır
+    _menu: Menu,
+    items: Vec<(MenuId, Action)>,
+}
+
+impl MenuBar {
+    pub fn new(window: &Window, bindings: &KeyBindings) -> Result<Self, String> {
+        let mut items = Vec::new();
+        let mut item = |text: &str, action: Action, accelerator: Option<Accelerator>| {
+            // Kısayol verilmediyse menüde eyleme bağlı tuş gösterilir. Windows'ta
+            // hızlandırıcı olarak kaydedilirse mesaj kancası basışı winit'ten önce
+            // yakalar ve konsol açıkken de tetiklerdi; orada tuş sadece etikete
+            // yazılır, basışı `KeyBindings` işler
+            let bound = bindings.key(action).map(|key| format!("{key:?}"));
+            #[cfg(windows)]
+            let (text, accelerator) = match (accelerator, bound) {
+                (None, Some(key)) => (format!("{text}\t{key}"), None),
+                (accelerator, _) => (text.to_string(), accelerator),
+            };
+            #[cfg(not(windows))]
+            let accelerator = accelerator.or_else(|| bound?.parse().ok());
+            let item = MenuItem::new(text, true, accelerator);
+            items.push((item.id().clone(), action));
+            item
+        };
+        let open = item("Aç…", Action::OpenFile, "CmdOrCtrl+O".parse().ok());
+        let screenshot = item("Ekran görüntüsü", Action::Screenshot, None);
+        // Escape konsolu ve seçimleri de kapattığı için menüye bağlanmaz
+        let quit = item("Çıkış", Action::Quit, "CmdOrCtrl+Q".parse().ok());
+        let fullscreen = item("Tam ekran", Action::ToggleFullscreen, None);
+        let stats = item("İstatistikler", Action::ToggleStats, None);
+
+        let error = |e: muda::Error| format!("menü oluşturulamadı: {e}");
+        let file = Submenu::with_items(
+            "Dosya",
+            true,
+            &[&open, &screenshot, &PredefinedMenuItem::separator(), &quit],
+        )
+        .map_err(error)?;
+        let view = Submenu::with_items("Görünüm", true, &[&fullscreen, &stats]).map_err(error)?;
+        let menu = Menu::new();
+        // macOS'ta ilk alt menü uygulama menüsü olarak gösterilir
+        #[cfg(target_os = "macos")]
+        {
+            let app = Submenu::with_items(
+                "winit & wgpu",
+                true,
+                &[
+                    &PredefinedMenuItem::about(None, None),
+                    &PredefinedMenuItem::separator(),
+                    &PredefinedMenuItem::hide(None),
+                    &PredefinedMenuItem::quit(None),
+                ],
+            )
+            .map_err(error)?;
+            menu.append(&app).map_err(error)?;
+        }
+        menu.append_items(&[&file, &view]).map_err(error)?;
+
+        #[cfg(target_os = "macos")]
+        {
+            let _ = window;
+            menu.init_for_nsapp();
+        }
+        #[cfg(windows)]
+        {
+            use winit::raw_window_handle::{HasWindowHandle, RawWindowHandle};
+            let handle = window.window_handle().map_err(|e| e.to_string())?;
+            let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+                return Err("Win32 pencere tutamacı alınamadı".into());
+            };
+            // SAFETY: tutamaç winit penceresine ait ve pencere menüden uzun yaşar
+            unsafe { menu.init_for_hwnd(handle.hwnd.get()) }.map_err(error)?;
+            ACCELERATORS.set(menu.haccel());
+        }
+
+        Ok(Self { _menu: menu, items })
+    }
+
+    pub fn action(&self, id: &MenuId) -> Option<Action> {
+        self.items
+            .iter()
+            .find(|(item, _)| item == id)
+            .map(|(_, action)| *action)
+    }
+}
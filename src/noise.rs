@@ -0,0 +1,214 @@
+// Perlin, simplex ve worley gürültüsü. `shaders/noise.wgsl` aynı
+// fonksiyonların birebir karşılığıdır: permütasyon tablosu yerine tamsayı
+// hash kullanılır, böylece CPU'da üretilen arazi ile GPU'da pişirilen doku
+// aynı tohumla aynı değerleri verir.
+use glam::{Vec2, Vec3};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NoiseKind {
+    Perlin,
+    Simplex,
+    Worley,
+}
+
+impl NoiseKind {
+    // noise.wgsl'deki `sample_noise` ile aynı numaralar
+    pub fn index(self) -> u32 {
+        match self {
+            NoiseKind::Perlin => 0,
+            NoiseKind::Simplex => 1,
+            NoiseKind::Worley => 2,
+        }
+    }
+}
+
+impl fmt::Display for NoiseKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NoiseKind::Perlin => "perlin",
+            NoiseKind::Simplex => "simplex",
+            NoiseKind::Worley => "worley",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for NoiseKind {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "perlin" => Ok(NoiseKind::Perlin),
+            "simplex" => Ok(NoiseKind::Simplex),
+            "worley" => Ok(NoiseKind::Worley),
+            _ => Err(format!("bilinmeyen gürültü: {text} (perlin | simplex | worley)")),
+        }
+    }
+}
+
+// lowbias32 (Chris Wellons)
+pub fn hash(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    x
+}
+
+fn hash2(x: i32, y: i32, seed: u32) -> u32 {
+    hash(x as u32 ^ hash(y as u32 ^ hash(seed)))
+}
+
+fn hash3(x: i32, y: i32, z: i32, seed: u32) -> u32 {
+    hash(x as u32 ^ hash(y as u32 ^ hash(z as u32 ^ hash(seed))))
+}
+
+// Hash'in üst 24 biti; f32'ye kayıpsız sığar, [0, 1)
+fn unit(h: u32) -> f32 {
+    (h >> 8) as f32 / 16_777_216.0
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+// Sekiz yönden biri; köşegenler normalize edilmez (klasik Perlin gibi)
+fn gradient2(h: u32) -> Vec2 {
+    const DIRECTIONS: [Vec2; 8] = [
+        Vec2::new(1.0, 0.0),
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(0.0, -1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(-1.0, 1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(-1.0, -1.0),
+    ];
+    DIRECTIONS[(h & 7) as usize]
+}
+
+// Küpün on iki kenar yönü
+fn gradient3(h: u32) -> Vec3 {
+    const DIRECTIONS: [Vec3; 12] = [
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(-1.0, 1.0, 0.0),
+        Vec3::new(1.0, -1.0, 0.0),
+        Vec3::new(-1.0, -1.0, 0.0),
+        Vec3::new(1.0, 0.0, 1.0),
+        Vec3::new(-1.0, 0.0, 1.0),
+        Vec3::new(1.0, 0.0, -1.0),
+        Vec3::new(-1.0, 0.0, -1.0),
+        Vec3::new(0.0, 1.0, 1.0),
+        Vec3::new(0.0, -1.0, 1.0),
+        Vec3::new(0.0, 1.0, -1.0),
+        Vec3::new(0.0, -1.0, -1.0),
+    ];
+    DIRECTIONS[(h % 12) as usize]
+}
+
+// Yaklaşık [-1, 1]
+pub fn perlin2(p: Vec2, seed: u32) -> f32 {
+    let cell = p.floor();
+    let (x, y) = (cell.x as i32, cell.y as i32);
+    let f = p - cell;
+    let corner = |dx: i32, dy: i32| {
+        gradient2(hash2(x.wrapping_add(dx), y.wrapping_add(dy), seed)).dot(f - Vec2::new(dx as f32, dy as f32))
+    };
+    let (u, v) = (fade(f.x), fade(f.y));
+    lerp(lerp(corner(0, 0), corner(1, 0), u), lerp(corner(0, 1), corner(1, 1), u), v)
+}
+
+// Yaklaşık [-1, 1]; hacim alanları (marching cubes, bulut yoğunluğu) için
+pub fn perlin3(p: Vec3, seed: u32) -> f32 {
+    let cell = p.floor();
+    let (x, y, z) = (cell.x as i32, cell.y as i32, cell.z as i32);
+    let f = p - cell;
+    let corner = |dx: i32, dy: i32, dz: i32| {
+        let offset = Vec3::new(dx as f32, dy as f32, dz as f32);
+        gradient3(hash3(x.wrapping_add(dx), y.wrapping_add(dy), z.wrapping_add(dz), seed)).dot(f - offset)
+    };
+    let (u, v, w) = (fade(f.x), fade(f.y), fade(f.z));
+    let near = lerp(lerp(corner(0, 0, 0), corner(1, 0, 0), u), lerp(corner(0, 1, 0), corner(1, 1, 0), u), v);
+    let far = lerp(lerp(corner(0, 0, 1), corner(1, 0, 1), u), lerp(corner(0, 1, 1), corner(1, 1, 1), u), v);
+    lerp(near, far, w)
+}
+
+// 2B simplex; yaklaşık [-1, 1]
+pub fn simplex2(p: Vec2, seed: u32) -> f32 {
+    const F2: f32 = 0.366_025_42; // (sqrt(3) - 1) / 2
+    const G2: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+
+    let skew = (p.x + p.y) * F2;
+    let cell = (p + Vec2::splat(skew)).floor();
+    let unskew = (cell.x + cell.y) * G2;
+    let d0 = p - (cell - Vec2::splat(unskew));
+    let step = if d0.x > d0.y { Vec2::new(1.0, 0.0) } else { Vec2::new(0.0, 1.0) };
+    let d1 = d0 - step + Vec2::splat(G2);
+    let d2 = d0 - Vec2::ONE + Vec2::splat(2.0 * G2);
+
+    let (x, y) = (cell.x as i32, cell.y as i32);
+    let contribution = |d: Vec2, dx: i32, dy: i32| {
+        let t = 0.5 - d.length_squared();
+        if t <= 0.0 {
+            0.0
+        } else {
+            let t2 = t * t;
+            t2 * t2 * gradient2(hash2(x.wrapping_add(dx), y.wrapping_add(dy), seed)).dot(d)
+        }
+    };
+    70.0 * (contribution(d0, 0, 0) + contribution(d1, step.x as i32, step.y as i32) + contribution(d2, 1, 1))
+}
+
+// En yakın öznitelik noktasına uzaklık (F1); hücre başına bir rastgele nokta, [0, ~1.2]
+pub fn worley2(p: Vec2, seed: u32) -> f32 {
+    let cell = p.floor();
+    let (x, y) = (cell.x as i32, cell.y as i32);
+    let mut nearest = f32::MAX;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let h = hash2(x.wrapping_add(dx), y.wrapping_add(dy), seed);
+            let point = Vec2::new(dx as f32 + unit(h), dy as f32 + unit(hash(h)));
+            nearest = nearest.min((point - (p - cell)).length_squared());
+        }
+    }
+    nearest.sqrt()
+}
+
+// Oktav başına frekans iki katına çıkar, genlik yarıya iner; her oktav
+// farklı tohum kullanır ki aynı desen üst üste binmesin
+pub fn fbm(kind: NoiseKind, p: Vec2, octaves: u32, seed: u32) -> f32 {
+    let mut sum = 0.0;
+    let mut amplitude = 0.5;
+    let mut frequency = 1.0;
+    let mut total = 0.0;
+    for octave in 0..octaves.max(1) {
+        let q = p * frequency;
+        let seed = seed.wrapping_add(octave);
+        let value = match kind {
+            NoiseKind::Perlin => perlin2(q, seed),
+            NoiseKind::Simplex => simplex2(q, seed),
+            NoiseKind::Worley => worley2(q, seed),
+        };
+        sum += value * amplitude;
+        total += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+    sum / total
+}
+
+// Dokuya yazılacak [0, 1] değer; noise.wgsl'deki `sample_noise`
+pub fn sample(kind: NoiseKind, p: Vec2, octaves: u32, seed: u32) -> f32 {
+    let value = fbm(kind, p, octaves, seed);
+    match kind {
+        NoiseKind::Worley => value.clamp(0.0, 1.0),
+        _ => (value * 0.5 + 0.5).clamp(0.0, 1.0),
+    }
+}
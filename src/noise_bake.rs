@@ -0,0 +1,230 @@
+use crate::noise::{self, NoiseKind};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 8;
+pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    kind: u32,
+    octaves: u32,
+    seed: u32,
+    scale: f32,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct NoiseParams {
+    pub kind: NoiseKind,
+    pub octaves: u32,
+    pub seed: u32,
+    // Doku boyunca kaç gürültü hücresi
+    pub scale: f32,
+    pub size: u32,
+}
+
+impl Default for NoiseParams {
+    fn default() -> Self {
+        Self {
+            kind: NoiseKind::Perlin,
+            octaves: 4,
+            seed: 0,
+            scale: 8.0,
+            size: 256,
+        }
+    }
+}
+
+impl NoiseParams {
+    fn raw(&self) -> ParamsRaw {
+        ParamsRaw {
+            kind: self.kind.index(),
+            octaves: self.octaves,
+            seed: self.seed,
+            scale: self.scale,
+        }
+    }
+
+    // noise_bake.wgsl'in bir piksel için yazdığı değerin CPU karşılığı
+    pub fn sample(&self, x: u32, y: u32) -> f32 {
+        let uv = (glam::Vec2::new(x as f32, y as f32) + 0.5) / self.size as f32;
+        noise::sample(self.kind, uv * self.scale, self.octaves, self.seed)
+    }
+}
+
+// Gürültüyü bir compute pass'te dokuya pişirir. Sonuç doğrusal Rgba8Unorm'dur
+// (renk değil veri); malzemelerde doku olarak veya başka pass'lerde girdi olarak kullanılabilir.
+pub struct NoiseBaker {
+    pipeline: wgpu::ComputePipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+}
+
+impl NoiseBaker {
+    pub fn new(device: &wgpu::Device) -> Result<Self, String> {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Noise Bake Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::WriteOnly,
+                        format: FORMAT,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Noise Bake Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout)?;
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group_layout,
+        })
+    }
+
+    fn create_pipeline(device: &wgpu::Device, layout: &wgpu::PipelineLayout) -> Result<wgpu::ComputePipeline, String> {
+        let shader = crate::shaders::create_module(device, "noise_bake.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Noise Bake Pipeline"),
+                layout: Some(layout),
+                module: &shader,
+                entry_point: Some("cs_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout)?;
+        Ok(())
+    }
+
+    // Yeni bir doku oluşturur ve pişirme pass'ini gönderir; GPU'yu beklemez
+    pub fn bake(&self, device: &wgpu::Device, queue: &wgpu::Queue, params: &NoiseParams) -> wgpu::Texture {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Noise Texture"),
+            size: wgpu::Extent3d {
+                width: params.size,
+                height: params.size,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: FORMAT,
+            usage: wgpu::TextureUsages::STORAGE_BINDING
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let uniform = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Noise Bake Params"),
+            contents: bytemuck::bytes_of(&params.raw()),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Noise Bake Bind Group"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: uniform.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Noise Bake Encoder"),
+        });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Noise Bake Pass"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let groups = params.size.div_ceil(WORKGROUP_SIZE);
+            pass.dispatch_workgroups(groups, groups, 1);
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+        texture
+    }
+
+    // Pişirilmiş dokuyu geri okuyup CPU uygulamasıyla karşılaştırır; en büyük
+    // farkı 8 bit adım cinsinden döndürür. GPU'yu bekler; sadece konsoldan çağrılır.
+    pub fn compare(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        texture: &wgpu::Texture,
+        params: &NoiseParams,
+    ) -> Result<u8, String> {
+        let size = params.size;
+        let row_bytes = (size * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Noise Bake Readback"),
+            size: (row_bytes * size) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Noise Bake Readback Encoder"),
+        });
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &readback,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(row_bytes),
+                    rows_per_image: Some(size),
+                },
+            },
+            texture.size(),
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = readback.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::PollType::Wait).map_err(|e| e.to_string())?;
+        let mut max_difference = 0;
+        {
+            let data = slice.get_mapped_range();
+            for y in 0..size {
+                for x in 0..size {
+                    let gpu = data[(y * row_bytes + x * 4) as usize];
+                    let cpu = (params.sample(x, y) * 255.0).round() as u8;
+                    max_difference = max_difference.max(gpu.abs_diff(cpu));
+                }
+            }
+        }
+        readback.unmap();
+        Ok(max_difference)
+    }
+}
@@ -0,0 +1,371 @@
+use crate::color::{Color, Palette};
+use crate::frame_capture::TracedPass;
+use ab_glyph::{Font, FontRef, ScaleFont};
+use std::collections::HashMap;
+use wgpu::util::DeviceExt;
+
+// Atlasa rasterize edilen karakterler. Listede olmayanlar '?' olarak çizilir.
+const GLYPHS: &str = " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~çğıöşüÇĞİÖŞÜ";
+const ATLAS_COLUMNS: u32 = 16;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct OverlayVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+impl OverlayVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+        wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x4];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<OverlayVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Monospace font ile hücre tabanlı glif atlası. Tüm karakterler aynı hücre
+// boyutunda olduğu için metin yerleşimi basit bir çarpmadır.
+struct GlyphAtlas {
+    cell: [f32; 2],
+    size: [u32; 2],
+    glyphs: HashMap<char, [f32; 4]>,
+    white_uv: [f32; 2],
+}
+
+impl GlyphAtlas {
+    fn rasterize(font_px: f32) -> (Self, Vec<u8>) {
+        let font = FontRef::try_from_slice(epaint_default_fonts::HACK_REGULAR)
+            .expect("Gömülü font okunamadı");
+        let scaled = font.as_scaled(font_px);
+        let cell_w = scaled.h_advance(font.glyph_id('M')).ceil() as u32;
+        let cell_h = (scaled.ascent() - scaled.descent()).ceil() as u32;
+
+        let count = GLYPHS.chars().count() as u32 + 1;
+        let rows = count.div_ceil(ATLAS_COLUMNS);
+        let width = ATLAS_COLUMNS * cell_w;
+        let height = rows * cell_h;
+        let mut pixels = vec![0u8; (width * height) as usize];
+
+        // İlk hücre düz dikdörtgenler için tamamen beyaz
+        for y in 0..cell_h {
+            for x in 0..cell_w {
+                pixels[(y * width + x) as usize] = 255;
+            }
+        }
+        let white_uv = [
+            cell_w as f32 * 0.5 / width as f32,
+            cell_h as f32 * 0.5 / height as f32,
+        ];
+
+        let mut glyphs = HashMap::new();
+        for (index, ch) in GLYPHS.chars().enumerate() {
+            let slot = index as u32 + 1;
+            let origin_x = (slot % ATLAS_COLUMNS) * cell_w;
+            let origin_y = (slot / ATLAS_COLUMNS) * cell_h;
+
+            let glyph = font
+                .glyph_id(ch)
+                .with_scale_and_position(font_px, ab_glyph::point(0.0, scaled.ascent()));
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    let x = bounds.min.x as i32 + gx as i32;
+                    let y = bounds.min.y as i32 + gy as i32;
+                    if x >= 0 && y >= 0 && (x as u32) < cell_w && (y as u32) < cell_h {
+                        let index = (origin_y + y as u32) * width + origin_x + x as u32;
+                        pixels[index as usize] = (coverage * 255.0) as u8;
+                    }
+                });
+            }
+
+            glyphs.insert(
+                ch,
+                [
+                    origin_x as f32 / width as f32,
+                    origin_y as f32 / height as f32,
+                    (origin_x + cell_w) as f32 / width as f32,
+                    (origin_y + cell_h) as f32 / height as f32,
+                ],
+            );
+        }
+
+        let atlas = Self {
+            cell: [cell_w as f32, cell_h as f32],
+            size: [width, height],
+            glyphs,
+            white_uv,
+        };
+        (atlas, pixels)
+    }
+}
+
+// Ekran uzayında (piksel, sol üst köşe orijin) dikdörtgen ve metin çizen
+// anlık (immediate-mode) katman. Her kare yeniden doldurulur.
+pub struct Overlay {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group: wgpu::BindGroup,
+    screen_buffer: wgpu::Buffer,
+    vertex_buffer: wgpu::Buffer,
+    vertex_capacity: usize,
+    vertex_count: u32,
+    vertices: Vec<OverlayVertex>,
+    atlas: GlyphAtlas,
+    format: wgpu::TextureFormat,
+    // Geçerli temanın renkleri; paneller ve metinler buradan okur
+    pub palette: &'static Palette,
+}
+
+impl Overlay {
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        format: wgpu::TextureFormat,
+        font_px: f32,
+    ) -> Result<Self, String> {
+        let (atlas, pixels) = GlyphAtlas::rasterize(font_px);
+
+        let atlas_texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Overlay Atlas"),
+                size: wgpu::Extent3d {
+                    width: atlas.size[0],
+                    height: atlas.size[1],
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &pixels,
+        );
+        let atlas_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Overlay Sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let screen_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Overlay Screen"),
+            size: 16,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Overlay Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Overlay Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: screen_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&atlas_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Overlay Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+
+        let vertex_capacity = 4096;
+        let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Overlay Vertices"),
+            size: (vertex_capacity * std::mem::size_of::<OverlayVertex>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group,
+            screen_buffer,
+            vertex_buffer,
+            vertex_capacity,
+            vertex_count: 0,
+            vertices: Vec::new(),
+            atlas,
+            format,
+            palette: &Palette::DARK,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "overlay.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Overlay Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[OverlayVertex::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    pub fn char_size(&self) -> [f32; 2] {
+        self.atlas.cell
+    }
+
+    fn quad(&mut self, min: [f32; 2], max: [f32; 2], uv_min: [f32; 2], uv_max: [f32; 2], color: [f32; 4]) {
+        let corners = [
+            ([min[0], min[1]], [uv_min[0], uv_min[1]]),
+            ([max[0], min[1]], [uv_max[0], uv_min[1]]),
+            ([max[0], max[1]], [uv_max[0], uv_max[1]]),
+            ([min[0], max[1]], [uv_min[0], uv_max[1]]),
+        ];
+        for index in [0, 1, 2, 0, 2, 3] {
+            let (position, uv) = corners[index];
+            self.vertices.push(OverlayVertex { position, uv, color });
+        }
+    }
+
+    pub fn rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        let uv = self.atlas.white_uv;
+        let color = color.for_target(self.format);
+        self.quad([x, y], [x + w, y + h], uv, uv, color);
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, text: &str, color: Color) {
+        let [cell_w, cell_h] = self.atlas.cell;
+        let color = color.for_target(self.format);
+        let mut cursor = x;
+        for ch in text.chars() {
+            if ch != ' ' {
+                let uv = self
+                    .atlas
+                    .glyphs
+                    .get(&ch)
+                    .or_else(|| self.atlas.glyphs.get(&'?'))
+                    .copied()
+                    .unwrap_or_default();
+                self.quad(
+                    [cursor, y],
+                    [cursor + cell_w, y + cell_h],
+                    [uv[0], uv[1]],
+                    [uv[2], uv[3]],
+                    color,
+                );
+            }
+            cursor += cell_w;
+        }
+    }
+
+    // Biriken köşeleri GPU'ya yükler ve bir sonraki kare için listeyi boşaltır.
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        queue.write_buffer(
+            &self.screen_buffer,
+            0,
+            bytemuck::cast_slice(&[width as f32, height as f32, 0.0, 0.0]),
+        );
+
+        if self.vertices.len() > self.vertex_capacity {
+            self.vertex_capacity = self.vertices.len().next_power_of_two();
+            self.vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Overlay Vertices"),
+                size: (self.vertex_capacity * std::mem::size_of::<OverlayVertex>()) as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+        }
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+
+        self.vertex_count = self.vertices.len() as u32;
+        self.vertices.clear();
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>) {
+        if self.vertex_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline, "Overlay Pipeline");
+        render_pass.set_bind_group(0, &self.bind_group, "Overlay Bind Group");
+        render_pass.set_vertex_buffer(0, &self.vertex_buffer, "Overlay Vertices");
+        render_pass.draw(0..self.vertex_count, 0..1);
+    }
+}
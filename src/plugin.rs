@@ -0,0 +1,30 @@
+use crate::State;
+use crate::builder::AppBuilder;
+use std::any::Any;
+use winit::event::WindowEvent;
+
+// İsteğe bağlı bir özelliği `main.rs`'e dokunmadan paketler; `AppBuilder::with_plugin`
+// ile eklenir. Katmanlardan farkı motor düzeyinde olmasıdır: olayları konsol ve
+// egui'den önce görür, duraklatılmışken de güncellenir ve karenin komut
+// kodlayıcısına erişir. Kancalar eklenme sırasıyla çağrılır.
+pub trait Plugin: Any {
+    // `with_plugin` sırasında; katman, konsol komutu veya başka eklenti ekleyebilir
+    fn build(&mut self, _builder: &mut AppBuilder) {}
+
+    // Pencere ve GPU hazır olduktan sonra, katmanlardan önce bir kez
+    fn on_startup(&mut self, _state: &mut State) {}
+
+    // true dönerse olay sonraki eklentilere, uygulamaya ve katmanlara geçmez;
+    // `RedrawRequested`, `CloseRequested`, `Resized` ve imleç/boyut/tema takibi
+    // yine de işlenir. Tüketilen olaylar yeni kare ister; olayı tüketmeden
+    // görüntüyü değiştiren eklenti `state.dirty`'yi işaretler.
+    fn on_event(&mut self, _state: &mut State, _event: &WindowEvent) -> bool {
+        false
+    }
+
+    // Her karede, duraklatılmışken de; overlay ve şekil çağrıları bu karede görünür
+    fn on_update(&mut self, _state: &mut State, _dt: f32) {}
+
+    // Overlay pass'inden sonra, gönderimden önce; kendi pass'lerini `view`'a çizebilir
+    fn on_render(&mut self, _state: &mut State, _encoder: &mut wgpu::CommandEncoder, _view: &wgpu::TextureView) {}
+}
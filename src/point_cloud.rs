@@ -0,0 +1,758 @@
+use crate::frame_capture::TracedPass;
+use crate::frame_context::{FRAMES_IN_FLIGHT, FrameContext, PerFrame};
+use crate::noise;
+use crate::scene::Camera;
+use glam::{DVec3, Vec3};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use wgpu::util::DeviceExt;
+
+// Yüklenen bulut bu boyuta ölçeklenir ve orijine ortalanır
+const FIT_SIZE: f32 = 4.0;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Point {
+    pub position: [f32; 3],
+    // sRGB, alfa kullanılmaz
+    pub color: [u8; 4],
+}
+
+impl Point {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![0 => Float32x3, 1 => Unorm8x4];
+
+    fn layout(step_mode: wgpu::VertexStepMode) -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<Point>() as wgpu::BufferAddress,
+            step_mode,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Quads: her nokta ekranda `point_size` piksellik yuvarlak bir dörtgene
+// genişletilir (örnek başına 4 köşe). Points: donanım nokta primitifi;
+// WebGPU'da boyutu her zaman 1 pikseldir ama en ucuz yoldur.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointMode {
+    Quads,
+    Points,
+}
+
+impl fmt::Display for PointMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PointMode::Quads => "quads",
+            PointMode::Points => "points",
+        })
+    }
+}
+
+impl FromStr for PointMode {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "quads" => Ok(PointMode::Quads),
+            "points" => Ok(PointMode::Points),
+            _ => Err(format!("bilinmeyen mod: {text} (quads | points)")),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Scalar {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl Scalar {
+    fn parse(name: &str) -> Result<Self, String> {
+        Ok(match name {
+            "char" | "int8" => Scalar::I8,
+            "uchar" | "uint8" => Scalar::U8,
+            "short" | "int16" => Scalar::I16,
+            "ushort" | "uint16" => Scalar::U16,
+            "int" | "int32" => Scalar::I32,
+            "uint" | "uint32" => Scalar::U32,
+            "float" | "float32" => Scalar::F32,
+            "double" | "float64" => Scalar::F64,
+            _ => return Err(format!("bilinmeyen PLY tipi: {name}")),
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Scalar::I8 | Scalar::U8 => 1,
+            Scalar::I16 | Scalar::U16 => 2,
+            Scalar::I32 | Scalar::U32 | Scalar::F32 => 4,
+            Scalar::F64 => 8,
+        }
+    }
+
+    fn is_float(self) -> bool {
+        matches!(self, Scalar::F32 | Scalar::F64)
+    }
+}
+
+struct Property {
+    name: String,
+    scalar: Scalar,
+    // Liste özelliklerinde eleman sayısının tipi
+    list: Option<Scalar>,
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+// PLY gövdesini okuyan imleç; ASCII'de kelime kelime, ikilide bayt bayt
+enum PlyReader<'a> {
+    Ascii(std::str::SplitAsciiWhitespace<'a>),
+    Binary {
+        data: &'a [u8],
+        offset: usize,
+        big_endian: bool,
+    },
+}
+
+impl PlyReader<'_> {
+    fn read(&mut self, scalar: Scalar) -> Result<f64, String> {
+        match self {
+            PlyReader::Ascii(words) => words
+                .next()
+                .ok_or("PLY verisi erken bitti")?
+                .parse()
+                .map_err(|_| "geçersiz PLY sayısı".to_string()),
+            PlyReader::Binary {
+                data,
+                offset,
+                big_endian,
+            } => {
+                let size = scalar.size();
+                let bytes = data.get(*offset..*offset + size).ok_or("PLY verisi erken bitti")?;
+                *offset += size;
+                let mut buffer = [0u8; 8];
+                buffer[..size].copy_from_slice(bytes);
+                if *big_endian {
+                    buffer[..size].reverse();
+                }
+                Ok(match scalar {
+                    Scalar::I8 => buffer[0] as i8 as f64,
+                    Scalar::U8 => buffer[0] as f64,
+                    Scalar::I16 => i16::from_le_bytes([buffer[0], buffer[1]]) as f64,
+                    Scalar::U16 => u16::from_le_bytes([buffer[0], buffer[1]]) as f64,
+                    Scalar::I32 => i32::from_le_bytes(buffer[..4].try_into().unwrap()) as f64,
+                    Scalar::U32 => u32::from_le_bytes(buffer[..4].try_into().unwrap()) as f64,
+                    Scalar::F32 => f32::from_le_bytes(buffer[..4].try_into().unwrap()) as f64,
+                    Scalar::F64 => f64::from_le_bytes(buffer),
+                })
+            }
+        }
+    }
+}
+
+// ASCII ve ikili (little/big endian) PLY. `vertex` öğesindeki x/y/z ve
+// varsa red/green/blue okunur; yüzler gibi diğer öğeler atlanır.
+pub fn load_ply(data: &[u8]) -> Result<Vec<(DVec3, [u8; 3])>, String> {
+    let marker = b"end_header";
+    let end = data
+        .windows(marker.len())
+        .position(|window| window == marker)
+        .ok_or("PLY başlığı bulunamadı")?;
+    let mut body = end + marker.len();
+    // Satır sonu \n veya \r\n
+    while data.get(body).is_some_and(|byte| *byte == b'\r' || *byte == b' ') {
+        body += 1;
+    }
+    body += 1;
+    let header = std::str::from_utf8(&data[..end]).map_err(|_| "PLY başlığı UTF-8 değil")?;
+
+    let mut format = None;
+    let mut elements: Vec<Element> = Vec::new();
+    for line in header.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            ["ply"] | [] => {}
+            ["format", name, _] => format = Some(name.to_string()),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse().map_err(|_| format!("geçersiz öğe sayısı: {count}"))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count, item, name] => {
+                elements.last_mut().ok_or("öğesiz özellik")?.properties.push(Property {
+                    name: name.to_string(),
+                    scalar: Scalar::parse(item)?,
+                    list: Some(Scalar::parse(count)?),
+                })
+            }
+            ["property", scalar, name] => elements.last_mut().ok_or("öğesiz özellik")?.properties.push(Property {
+                name: name.to_string(),
+                scalar: Scalar::parse(scalar)?,
+                list: None,
+            }),
+            _ => {}
+        }
+    }
+
+    let mut reader = match format.as_deref() {
+        Some("ascii") => PlyReader::Ascii(
+            std::str::from_utf8(data.get(body..).unwrap_or_default())
+                .map_err(|_| "ASCII PLY verisi UTF-8 değil")?
+                .split_ascii_whitespace(),
+        ),
+        Some(name @ ("binary_little_endian" | "binary_big_endian")) => PlyReader::Binary {
+            data,
+            offset: body,
+            big_endian: name == "binary_big_endian",
+        },
+        other => return Err(format!("desteklenmeyen PLY formatı: {other:?}")),
+    };
+
+    let mut points = Vec::new();
+    let body_len = data.len().saturating_sub(body);
+    for element in &elements {
+        // Özelliksiz bir öğe hiç bayt okumaz; başlıktaki sayı gövdeden büyükse
+        // boş döngü dosyayla sınırlı kalmazdı
+        if element.count > body_len {
+            return Err(format!(
+                "PLY öğe sayısı gövdeyi aşıyor: {} {}",
+                element.name, element.count
+            ));
+        }
+        let is_vertex = element.name == "vertex";
+        if is_vertex {
+            if element.properties.is_empty() {
+                return Err("PLY köşe öğesinde özellik yok".into());
+            }
+            // Her değer en az bir bayt tutar; başlıktaki sayıya güvenilmez
+            points.reserve(element.count.min(data.len() / element.properties.len()));
+        }
+        for _ in 0..element.count {
+            let mut position = DVec3::ZERO;
+            let mut color = [255u8; 3];
+            for property in &element.properties {
+                if let Some(count) = property.list {
+                    for _ in 0..reader.read(count)? as usize {
+                        reader.read(property.scalar)?;
+                    }
+                    continue;
+                }
+                let value = reader.read(property.scalar)?;
+                if !is_vertex {
+                    continue;
+                }
+                // Kayan noktalı renkler 0..1 aralığındadır
+                let channel = || {
+                    (if property.scalar.is_float() {
+                        value * 255.0
+                    } else {
+                        value
+                    })
+                    .clamp(0.0, 255.0) as u8
+                };
+                match property.name.as_str() {
+                    "x" => position.x = value,
+                    "y" => position.y = value,
+                    "z" => position.z = value,
+                    "red" | "r" | "diffuse_red" => color[0] = channel(),
+                    "green" | "g" | "diffuse_green" => color[1] = channel(),
+                    "blue" | "b" | "diffuse_blue" => color[2] = channel(),
+                    _ => {}
+                }
+            }
+            if is_vertex {
+                points.push((position, color));
+            }
+        }
+    }
+    if points.is_empty() {
+        return Err("PLY dosyasında köşe yok".into());
+    }
+    Ok(points)
+}
+
+// ASPRS LAS 1.0-1.4. Koordinatlar ölçek/ofsetle çözülür; Z yukarı olduğundan
+// Y yukarı düzene çevrilir. RGB olmayan formatlarda yoğunluk gri ton olur.
+pub fn load_las(data: &[u8]) -> Result<Vec<(DVec3, [u8; 3])>, String> {
+    fn bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N], String> {
+        data.get(offset..offset + N)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or_else(|| "LAS dosyası kısa".to_string())
+    }
+    let u16_at = |offset| bytes::<2>(data, offset).map(u16::from_le_bytes);
+    let u32_at = |offset| bytes::<4>(data, offset).map(u32::from_le_bytes);
+    let f64_at = |offset| bytes::<8>(data, offset).map(f64::from_le_bytes);
+
+    if data.get(..4) != Some(b"LASF") {
+        return Err("LAS imzası yok".into());
+    }
+    let minor = *data.get(25).ok_or("LAS dosyası kısa")?;
+    let point_offset = u32_at(96)? as usize;
+    // Üst iki bit LAZ sıkıştırmasını işaretler; sıkıştırılmış kayıtlar ham
+    // okunursa anlamsız noktalar çıkar
+    let format = *data.get(104).ok_or("LAS dosyası kısa")?;
+    if format & 0xc0 != 0 {
+        return Err("sıkıştırılmış LAZ desteklenmiyor".into());
+    }
+    let record = u16_at(105)? as usize;
+    let mut count = u32_at(107)? as usize;
+    if count == 0 && minor >= 4 {
+        count = bytes::<8>(data, 247).map(u64::from_le_bytes)? as usize;
+    }
+    let scale = DVec3::new(f64_at(131)?, f64_at(139)?, f64_at(147)?);
+    let offset = DVec3::new(f64_at(155)?, f64_at(163)?, f64_at(171)?);
+    let rgb = match format {
+        2 => Some(20),
+        3 | 5 => Some(28),
+        7 | 8 | 10 => Some(30),
+        0 | 1 | 4 | 6 | 9 => None,
+        _ => return Err(format!("desteklenmeyen LAS nokta formatı: {format}")),
+    };
+    if count == 0 {
+        return Err("LAS dosyasında nokta yok".into());
+    }
+    // Kayıt en az okunan alanları (koordinatlar, yoğunluk veya renk) kapsamalı
+    if record < rgb.map_or(14, |at| at + 6) {
+        return Err(format!("LAS nokta kaydı çok kısa: {record} bayt"));
+    }
+    let end = count
+        .checked_mul(record)
+        .and_then(|size| size.checked_add(point_offset));
+    if end.is_none_or(|end| data.len() < end) {
+        return Err("LAS nokta verisi eksik (sıkıştırılmış LAZ desteklenmiyor)".into());
+    }
+
+    let mut points = Vec::with_capacity(count.min(data.len() / record));
+    // Bazı dosyalar 8 bit rengi 16 bitte saklar; en büyük değerden anlaşılır
+    let mut wide_color = false;
+    for index in 0..count {
+        let base = point_offset + index * record;
+        let coordinate = |axis: usize| bytes::<4>(data, base + axis * 4).map(i32::from_le_bytes);
+        let raw = DVec3::new(coordinate(0)? as f64, coordinate(1)? as f64, coordinate(2)? as f64);
+        let p = raw * scale + offset;
+        let color = match rgb {
+            Some(at) => {
+                let channels = [u16_at(base + at)?, u16_at(base + at + 2)?, u16_at(base + at + 4)?];
+                wide_color |= channels.iter().any(|channel| *channel > 255);
+                channels
+            }
+            None => [u16_at(base + 12)?; 3],
+        };
+        points.push((DVec3::new(p.x, p.z, -p.y), color));
+    }
+    let shift = if wide_color || rgb.is_none() { 8 } else { 0 };
+    Ok(points
+        .into_iter()
+        .map(|(position, color)| (position, color.map(|channel| (channel >> shift) as u8)))
+        .collect())
+}
+
+// Bulutu orijine ortalar, en uzun kenarı FIT_SIZE olacak şekilde ölçekler ve
+// noktaları karıştırır: karışık dizinin her ön eki düzgün bir alt örnek
+// olduğundan ayrıntı düzeyi için sadece çizilen nokta sayısı azaltılır.
+fn prepare_points(source: Vec<(DVec3, [u8; 3])>, seed: u32) -> Vec<Point> {
+    let (min, max) = source.iter().fold((DVec3::MAX, DVec3::MIN), |(min, max), (p, _)| {
+        (min.min(*p), max.max(*p))
+    });
+    let center = (min + max) * 0.5;
+    let scale = FIT_SIZE as f64 / (max - min).max_element().max(f64::EPSILON);
+    let mut points: Vec<Point> = source
+        .into_iter()
+        .map(|(position, [r, g, b])| Point {
+            position: ((position - center) * scale).as_vec3().into(),
+            color: [r, g, b, 255],
+        })
+        .collect();
+    let mut state = seed;
+    for i in (1..points.len()).rev() {
+        state = noise::hash(state.wrapping_add(i as u32));
+        points.swap(i, state as usize % (i + 1));
+    }
+    points
+}
+
+// Yük olmadan denemek için yükseklik alanı üzerinde rastgele noktalar; renk yüksekliğe göre
+pub fn demo_points(count: usize, seed: u32) -> Vec<(DVec3, [u8; 3])> {
+    (0..count as u32)
+        .map(|i| {
+            let h = noise::hash(i ^ noise::hash(seed));
+            let x = (h >> 8) as f64 / 16_777_216.0 * 2.0 - 1.0;
+            let z = (noise::hash(h) >> 8) as f64 / 16_777_216.0 * 2.0 - 1.0;
+            let p = glam::Vec2::new(x as f32, z as f32) * 3.0;
+            let height = noise::fbm(noise::NoiseKind::Perlin, p, 5, seed) as f64;
+            let t = (height * 1.5 + 0.5).clamp(0.0, 1.0);
+            let color = [
+                (40.0 + 200.0 * t) as u8,
+                (110.0 + 100.0 * (1.0 - t)) as u8,
+                (200.0 * (1.0 - t)) as u8,
+            ];
+            (DVec3::new(x, height * 0.6, z), color)
+        })
+        .collect()
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    view_proj: [[f32; 4]; 4],
+    viewport: [f32; 2],
+    point_size: f32,
+    _padding: f32,
+}
+
+struct FrameParams {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+pub struct PointCloud {
+    pub name: String,
+    buffer: wgpu::Buffer,
+    count: u32,
+    radius: f32,
+}
+
+pub struct PointCloudRenderer {
+    quad_pipeline: wgpu::RenderPipeline,
+    point_pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    frames: PerFrame<FrameParams>,
+    format: wgpu::TextureFormat,
+    pub cloud: Option<PointCloud>,
+    pub mode: PointMode,
+    // Quads modunda piksel cinsinden nokta çapı
+    pub point_size: f32,
+    // Bir karede çizilecek en fazla nokta
+    pub budget: u32,
+    // Son karede çizilen nokta sayısı
+    drawn: u32,
+}
+
+impl PointCloudRenderer {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self, String> {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Point Cloud Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Point Cloud Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let (quad_pipeline, point_pipeline) = Self::create_pipelines(device, &pipeline_layout, format)?;
+        let frames = PerFrame::new(FRAMES_IN_FLIGHT, |_| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Point Cloud Params"),
+                size: std::mem::size_of::<ParamsRaw>() as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Point Cloud Bind Group"),
+                layout: &bind_group_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.as_entire_binding(),
+                }],
+            });
+            FrameParams { buffer, bind_group }
+        });
+
+        Ok(Self {
+            quad_pipeline,
+            point_pipeline,
+            pipeline_layout,
+            frames,
+            format,
+            cloud: None,
+            mode: PointMode::Quads,
+            point_size: 3.0,
+            budget: 2_000_000,
+            drawn: 0,
+        })
+    }
+
+    fn create_pipelines(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<(wgpu::RenderPipeline, wgpu::RenderPipeline), String> {
+        let shader = crate::shaders::create_module(device, "point_cloud.wgsl")?;
+        let create = |label: &str, entry_point: &str, step_mode, topology| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    buffers: &[Point::layout(step_mode)],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology,
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: crate::renderer::DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        };
+        crate::shaders::validated(device, || {
+            (
+                create(
+                    "Point Cloud Quad Pipeline",
+                    "vs_quad",
+                    wgpu::VertexStepMode::Instance,
+                    wgpu::PrimitiveTopology::TriangleStrip,
+                ),
+                create(
+                    "Point Cloud Point Pipeline",
+                    "vs_point",
+                    wgpu::VertexStepMode::Vertex,
+                    wgpu::PrimitiveTopology::PointList,
+                ),
+            )
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        (self.quad_pipeline, self.point_pipeline) = Self::create_pipelines(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    // Uzantıya göre PLY veya LAS
+    pub fn load(&mut self, device: &wgpu::Device, path: &Path) -> Result<String, String> {
+        let data = std::fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let points = match extension.as_deref() {
+            Some("ply") => load_ply(&data)?,
+            Some("las") => load_las(&data)?,
+            _ => return Err("desteklenen uzantılar: .ply, .las".into()),
+        };
+        let name = path
+            .file_name()
+            .map_or("bulut".into(), |name| name.to_string_lossy().into_owned());
+        Ok(self.upload(device, &name, points))
+    }
+
+    pub fn upload(&mut self, device: &wgpu::Device, name: &str, points: Vec<(DVec3, [u8; 3])>) -> String {
+        let points = prepare_points(points, 0x5eed);
+        let limit = device.limits().max_buffer_size as usize / std::mem::size_of::<Point>();
+        let count = points.len().min(limit);
+        let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(name),
+            contents: bytemuck::cast_slice(&points[..count]),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let radius = points[..count]
+            .iter()
+            .map(|point| Vec3::from(point.position).length())
+            .fold(0.0, f32::max);
+        self.cloud = Some(PointCloud {
+            name: name.to_string(),
+            buffer,
+            count: count as u32,
+            radius,
+        });
+        let truncated = if count < points.len() {
+            format!(" (buffer sınırı nedeniyle {} nokta atıldı)", points.len() - count)
+        } else {
+            String::new()
+        };
+        format!("{name}: {count} nokta yüklendi{truncated}")
+    }
+
+    // Çizilecek nokta sayısı: bütçe, ve bulut ekranda küçüldükçe mesafenin
+    // karesiyle azalan pay. Seyrelen bulutta noktalar büyütülerek kaplama korunur.
+    pub fn prepare(
+        &mut self,
+        queue: &wgpu::Queue,
+        frame: FrameContext,
+        camera: &Camera,
+        aspect: f32,
+        viewport: [f32; 2],
+    ) {
+        let Some(cloud) = &self.cloud else { return };
+        let distance = camera.eye.length().max(f32::EPSILON);
+        let near = cloud.radius * 4.0;
+        let share = (near / distance).powi(2).clamp(1.0 / 64.0, 1.0);
+        self.drawn = ((cloud.count as f32 * share) as u32)
+            .min(self.budget)
+            .clamp(1.min(cloud.count), cloud.count);
+        let growth = (cloud.count as f32 / self.drawn.max(1) as f32).sqrt().min(4.0);
+
+        let raw = ParamsRaw {
+            view_proj: camera.view_proj(aspect).to_cols_array_2d(),
+            viewport,
+            point_size: self.point_size * growth,
+            _padding: 0.0,
+        };
+        queue.write_buffer(&self.frames.get(frame).buffer, 0, bytemuck::bytes_of(&raw));
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>, frame: FrameContext) {
+        let Some(cloud) = &self.cloud else { return };
+        let (pipeline, name) = match self.mode {
+            PointMode::Quads => (&self.quad_pipeline, "Point Cloud Quad Pipeline"),
+            PointMode::Points => (&self.point_pipeline, "Point Cloud Point Pipeline"),
+        };
+        render_pass.set_pipeline(pipeline, name);
+        render_pass.set_bind_group(0, &self.frames.get(frame).bind_group, "Point Cloud Bind Group");
+        render_pass.set_vertex_buffer(0, &cloud.buffer, &cloud.name);
+        match self.mode {
+            PointMode::Quads => render_pass.draw(0..4, 0..self.drawn),
+            PointMode::Points => render_pass.draw(0..self.drawn, 0..1),
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match &self.cloud {
+            Some(cloud) => format!(
+                "{}: {} / {} nokta çiziliyor, mod {}, boyut {} px, bütçe {}",
+                cloud.name, self.drawn, cloud.count, self.mode, self.point_size, self.budget
+            ),
+            None => "nokta bulutu yok".into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // LAS 1.2 başlığı (227 bayt), ölçek 0.01 ve ofset (100, 200, 0)
+    fn las(format: u8, record: u16, count: u32, points: &[u8]) -> Vec<u8> {
+        let mut data = vec![0u8; 227];
+        data[..4].copy_from_slice(b"LASF");
+        data[25] = 2;
+        data[96..100].copy_from_slice(&227u32.to_le_bytes());
+        data[104] = format;
+        data[105..107].copy_from_slice(&record.to_le_bytes());
+        data[107..111].copy_from_slice(&count.to_le_bytes());
+        for axis in 0..3 {
+            data[131 + axis * 8..139 + axis * 8].copy_from_slice(&0.01f64.to_le_bytes());
+        }
+        data[155..163].copy_from_slice(&100.0f64.to_le_bytes());
+        data[163..171].copy_from_slice(&200.0f64.to_le_bytes());
+        data.extend_from_slice(points);
+        data
+    }
+
+    // Format 2: koordinatlar, yoğunluk ve 16 bit RGB
+    fn las_point(position: [i32; 3], color: [u16; 3]) -> Vec<u8> {
+        let mut record = vec![0u8; 26];
+        for (axis, value) in position.iter().enumerate() {
+            record[axis * 4..axis * 4 + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        for (channel, value) in color.iter().enumerate() {
+            record[20 + channel * 2..22 + channel * 2].copy_from_slice(&value.to_le_bytes());
+        }
+        record
+    }
+
+    #[test]
+    fn ply_ascii() {
+        let data = b"ply\r\nformat ascii 1.0\r\nelement vertex 2\r\nproperty float x\r\nproperty float y\r\n\
+property float z\r\nproperty uchar red\r\nproperty uchar green\r\nproperty uchar blue\r\nelement face 1\r\n\
+property list uchar int vertex_indices\r\nend_header\r\n1 2 3 255 0 10\r\n-1.5 0 0.25 0 128 0\r\n3 0 1 1\r\n";
+        let points = load_ply(data).unwrap();
+        assert_eq!(
+            points,
+            [
+                (DVec3::new(1.0, 2.0, 3.0), [255, 0, 10]),
+                (DVec3::new(-1.5, 0.0, 0.25), [0, 128, 0])
+            ]
+        );
+    }
+
+    #[test]
+    fn ply_binary() {
+        let mut data = b"ply\nformat binary_big_endian 1.0\nelement vertex 2\nproperty float x\nproperty float y\n\
+property float z\nproperty float red\nproperty float green\nproperty float blue\nend_header\n"
+            .to_vec();
+        for values in [[0.5f32, -2.0, 4.0, 1.0, 0.0, 0.5], [8.0, 16.0, -32.0, 0.0, 1.0, 0.0]] {
+            for value in values {
+                data.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+        let points = load_ply(&data).unwrap();
+        assert_eq!(
+            points,
+            [
+                (DVec3::new(0.5, -2.0, 4.0), [255, 0, 127]),
+                (DVec3::new(8.0, 16.0, -32.0), [0, 255, 0])
+            ]
+        );
+        // Gövde eksikse ve başlıktaki sayı gövdeyi aşıyorsa hata döner
+        assert!(load_ply(&data[..data.len() - 1]).is_err());
+        let huge = b"ply\nformat ascii 1.0\nelement face 18446744073709551615\nend_header\n";
+        assert!(load_ply(huge).is_err());
+    }
+
+    #[test]
+    fn las_points() {
+        let mut points = las_point([100, 200, 300], [255, 0, 128]);
+        points.extend(las_point([-100, 0, 0], [0, 255, 0]));
+        let loaded = load_las(&las(2, 26, 2, &points)).unwrap();
+        assert_eq!(loaded.len(), 2);
+        // Z yukarı → Y yukarı: (x, z, -y)
+        assert!(loaded[0].0.abs_diff_eq(DVec3::new(101.0, 3.0, -202.0), 1e-9));
+        assert!(loaded[1].0.abs_diff_eq(DVec3::new(99.0, 0.0, -200.0), 1e-9));
+        assert_eq!(loaded[0].1, [255, 0, 128]);
+        assert_eq!(loaded[1].1, [0, 255, 0]);
+    }
+
+    #[test]
+    fn las_rejects_bad_headers() {
+        let point = las_point([0; 3], [0; 3]);
+        // Başlıktaki sayı kadar kayıt yok
+        assert!(load_las(&las(2, 26, 2, &point)).is_err());
+        assert!(load_las(&las(2, 26, u32::MAX, &point)).is_err());
+        assert!(load_las(&las(2, 26, 1, &point)[..200]).is_err());
+        // Kayıt RGB alanını kapsamıyor ya da boş
+        assert!(load_las(&las(2, 20, 1, &point)).is_err());
+        assert!(load_las(&las(2, 0, 1, &point)).is_err());
+        // LAZ bayrağı: veri tam uzunlukta olsa da okunmaz
+        assert!(load_las(&las(2 | 0x80, 26, 1, &point)).is_err());
+        assert!(load_las(&las(2, 26, 1, &point)).is_ok());
+    }
+}
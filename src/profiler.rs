@@ -0,0 +1,400 @@
+use crate::color::{Color, Palette};
+use crate::overlay::Overlay;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+const HISTORY: usize = 120;
+const MAX_GPU_PASSES: usize = 8;
+// Zaman çizelgesinin en az göstereceği süre (60 Hz bir kare)
+const TARGET_MS: f32 = 1000.0 / 60.0;
+
+#[derive(Clone, Debug)]
+pub struct Span {
+    pub name: &'static str,
+    pub depth: usize,
+    pub start_ms: f32,
+    pub end_ms: f32,
+}
+
+impl Span {
+    pub fn duration_ms(&self) -> f32 {
+        self.end_ms - self.start_ms
+    }
+}
+
+#[derive(Default)]
+pub struct FrameProfile {
+    pub frame_ms: f32,
+    pub cpu: Vec<Span>,
+    pub gpu: Vec<Span>,
+}
+
+enum Readback {
+    Idle,
+    // Sorgular bu karenin encoder'ına çözümlendi, gönderilmeyi bekliyor
+    Resolved(Vec<&'static str>),
+    Mapping(Vec<&'static str>, Arc<AtomicBool>),
+}
+
+// Render pass başına başlangıç/bitiş zaman damgaları. Okuma tek buffer ile
+// yapılır; önceki sonuç henüz okunmadıysa o kare GPU ölçümü atlanır.
+struct GpuTimer {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    period_ns: f32,
+    passes: Vec<&'static str>,
+    readback: Readback,
+}
+
+impl GpuTimer {
+    fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+        let count = MAX_GPU_PASSES as u32 * 2;
+        let size = count as wgpu::BufferAddress * std::mem::size_of::<u64>() as wgpu::BufferAddress;
+
+        Some(Self {
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("Profiler Timestamps"),
+                ty: wgpu::QueryType::Timestamp,
+                count,
+            }),
+            resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Profiler Resolve"),
+                size,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Profiler Readback"),
+                size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            }),
+            period_ns: queue.get_timestamp_period(),
+            passes: Vec::new(),
+            readback: Readback::Idle,
+        })
+    }
+
+    // Pass için ayrılan ilk sorgu indeksi (başlangıç; bitiş bir sonrası)
+    fn slot(&mut self, name: &'static str) -> Option<u32> {
+        if !matches!(self.readback, Readback::Idle) || self.passes.len() >= MAX_GPU_PASSES {
+            return None;
+        }
+        let index = self.passes.len() as u32 * 2;
+        self.passes.push(name);
+        Some(index)
+    }
+
+    fn pass(&mut self, name: &'static str) -> Option<wgpu::RenderPassTimestampWrites<'_>> {
+        let index = self.slot(name)?;
+        Some(wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(index),
+            end_of_pass_write_index: Some(index + 1),
+        })
+    }
+
+    fn compute_pass(&mut self, name: &'static str) -> Option<wgpu::ComputePassTimestampWrites<'_>> {
+        let index = self.slot(name)?;
+        Some(wgpu::ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(index),
+            end_of_pass_write_index: Some(index + 1),
+        })
+    }
+
+    fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if self.passes.is_empty() {
+            return;
+        }
+        let count = self.passes.len() as u32 * 2;
+        let size = count as wgpu::BufferAddress * std::mem::size_of::<u64>() as wgpu::BufferAddress;
+        encoder.resolve_query_set(&self.query_set, 0..count, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, size);
+        self.readback = Readback::Resolved(std::mem::take(&mut self.passes));
+    }
+
+    fn after_submit(&mut self) {
+        if let Readback::Resolved(names) = std::mem::replace(&mut self.readback, Readback::Idle) {
+            let ready = Arc::new(AtomicBool::new(false));
+            let flag = ready.clone();
+            self.readback_buffer
+                .slice(..)
+                .map_async(wgpu::MapMode::Read, move |result| {
+                    flag.store(result.is_ok(), Ordering::Release)
+                });
+            self.readback = Readback::Mapping(names, ready);
+        }
+    }
+
+    // Okuma tamamlandıysa pass sürelerini döndürür; GPU'yu beklemez
+    fn collect(&mut self, device: &wgpu::Device) -> Option<Vec<Span>> {
+        let Readback::Mapping(_, ready) = &self.readback else {
+            return None;
+        };
+        let _ = device.poll(wgpu::PollType::Poll);
+        if !ready.load(Ordering::Acquire) {
+            return None;
+        }
+        let Readback::Mapping(names, _) = std::mem::replace(&mut self.readback, Readback::Idle) else {
+            unreachable!()
+        };
+
+        let spans = {
+            let data = self.readback_buffer.slice(..).get_mapped_range();
+            let ticks: &[u64] = bytemuck::cast_slice(&data);
+            let origin = ticks[0];
+            let to_ms = |tick: u64| tick.saturating_sub(origin) as f32 * self.period_ns / 1_000_000.0;
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| Span {
+                    name,
+                    depth: 0,
+                    start_ms: to_ms(ticks[index * 2]),
+                    end_ms: to_ms(ticks[index * 2 + 1]),
+                })
+                .collect()
+        };
+        self.readback_buffer.unmap();
+        Some(spans)
+    }
+}
+
+// Kare başına CPU bölümlerini (iç içe) ve GPU pass sürelerini toplar,
+// son karenin zaman çizelgesini overlay üzerine çizer.
+pub struct Profiler {
+    pub visible: bool,
+    frame_start: Instant,
+    current: Vec<Span>,
+    stack: Vec<usize>,
+    gpu: Option<GpuTimer>,
+    gpu_spans: Vec<Span>,
+    history: VecDeque<FrameProfile>,
+}
+
+impl Profiler {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        let gpu = GpuTimer::new(device, queue);
+        if gpu.is_none() {
+            log::info!("Zaman damgası sorguları desteklenmiyor, profiler sadece CPU ölçecek");
+        }
+        Self {
+            visible: false,
+            frame_start: Instant::now(),
+            current: Vec::new(),
+            stack: Vec::new(),
+            gpu,
+            gpu_spans: Vec::new(),
+            history: VecDeque::with_capacity(HISTORY),
+        }
+    }
+
+    pub fn has_gpu_timing(&self) -> bool {
+        self.gpu.is_some()
+    }
+
+    fn now_ms(&self) -> f32 {
+        self.frame_start.elapsed().as_secs_f32() * 1000.0
+    }
+
+    // Önceki kareyi geçmişe yazar ve yeni kareyi başlatır
+    pub fn begin_frame(&mut self, device: &wgpu::Device) {
+        if let Some(spans) = self.gpu.as_mut().and_then(|gpu| gpu.collect(device)) {
+            self.gpu_spans = spans;
+        }
+        while !self.stack.is_empty() {
+            self.end();
+        }
+
+        let frame = FrameProfile {
+            frame_ms: self.now_ms(),
+            cpu: std::mem::take(&mut self.current),
+            gpu: self.gpu_spans.clone(),
+        };
+        if self.history.len() == HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(frame);
+        self.frame_start = Instant::now();
+    }
+
+    pub fn begin(&mut self, name: &'static str) {
+        let start_ms = self.now_ms();
+        self.stack.push(self.current.len());
+        self.current.push(Span {
+            name,
+            depth: self.stack.len() - 1,
+            start_ms,
+            end_ms: start_ms,
+        });
+    }
+
+    pub fn end(&mut self) {
+        let end_ms = self.now_ms();
+        if let Some(index) = self.stack.pop() {
+            self.current[index].end_ms = end_ms;
+        }
+    }
+
+    // Render pass tanımına verilecek zaman damgaları; profiler kapalıyken None
+    pub fn gpu_pass(&mut self, name: &'static str) -> Option<wgpu::RenderPassTimestampWrites<'_>> {
+        if !self.visible {
+            return None;
+        }
+        self.gpu.as_mut()?.pass(name)
+    }
+
+    pub fn gpu_compute_pass(&mut self, name: &'static str) -> Option<wgpu::ComputePassTimestampWrites<'_>> {
+        if !self.visible {
+            return None;
+        }
+        self.gpu.as_mut()?.compute_pass(name)
+    }
+
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if let Some(gpu) = self.gpu.as_mut() {
+            gpu.resolve(encoder);
+        }
+    }
+
+    pub fn after_submit(&mut self) {
+        if let Some(gpu) = self.gpu.as_mut() {
+            gpu.after_submit();
+        }
+    }
+
+    pub fn frame_times(&self) -> impl Iterator<Item = f32> + '_ {
+        self.history.iter().map(|frame| frame.frame_ms)
+    }
+
+    pub fn last_frame(&self) -> Option<&FrameProfile> {
+        self.history.back()
+    }
+
+    pub fn draw(&self, overlay: &mut Overlay, width: f32, height: f32) {
+        if !self.visible {
+            return;
+        }
+        let Some(frame) = self.last_frame() else { return };
+
+        let palette = overlay.palette;
+        let [char_w, line_height] = overlay.char_size();
+        let padding = 6.0;
+        let lane_height = line_height + 2.0;
+        let graph_height = line_height * 2.0;
+        let cpu_lanes = frame.cpu.iter().map(|span| span.depth + 1).max().unwrap_or(1);
+        let panel_height = padding * 4.0 + line_height * 3.0 + graph_height + lane_height * (cpu_lanes + 1) as f32;
+        let top = height - panel_height;
+        let left = padding + char_w * 4.0;
+        let timeline_width = width - left - padding;
+
+        overlay.rect(0.0, top, width, panel_height, palette.panel);
+        overlay.rect(0.0, top - 2.0, width, 2.0, palette.accent);
+
+        let cpu_ms: f32 = frame.cpu.iter().filter(|span| span.depth == 0).map(Span::duration_ms).sum();
+        let gpu_ms = match (&self.gpu, frame.gpu.first(), frame.gpu.last()) {
+            (Some(_), Some(first), Some(last)) => format!("{:.2} ms", last.end_ms - first.start_ms),
+            (Some(_), _, _) => "-".to_string(),
+            (None, _, _) => "desteklenmiyor".to_string(),
+        };
+        let mut y = top + padding;
+        overlay.text(
+            padding,
+            y,
+            &format!(
+                "kare {:.2} ms ({:.0} fps)  CPU {cpu_ms:.2} ms  GPU {gpu_ms}",
+                frame.frame_ms,
+                1000.0 / frame.frame_ms.max(0.001)
+            ),
+            palette.text,
+        );
+        y += line_height + padding;
+
+        // Son karelerin süre grafiği; hedef kare süresinin iki katı üst sınır
+        let bar_width = timeline_width / HISTORY as f32;
+        let graph_top = y;
+        overlay.rect(left, graph_top + graph_height * 0.5, timeline_width, 1.0, Color::WHITE.with_alpha(0.25));
+        for (index, profile) in self.history.iter().enumerate() {
+            let ratio = (profile.frame_ms / (TARGET_MS * 2.0)).min(1.0);
+            let bar_height = (graph_height * ratio).max(1.0);
+            let x = left + (HISTORY - self.history.len() + index) as f32 * bar_width;
+            overlay.rect(
+                x,
+                graph_top + graph_height - bar_height,
+                (bar_width - 1.0).max(1.0),
+                bar_height,
+                frame_color(palette, profile.frame_ms),
+            );
+        }
+        y += graph_height + padding;
+
+        // Zaman çizelgesi: en az bir hedef kare genişliğinde
+        let scale_ms = frame.frame_ms.max(TARGET_MS);
+        let to_x = |ms: f32| left + ms / scale_ms * timeline_width;
+        overlay.text(padding, y, "0", palette.text_dim);
+        let target_label = format!("{TARGET_MS:.1} ms");
+        overlay.text(to_x(TARGET_MS) - char_w * target_label.len() as f32, y, &target_label, palette.text_dim);
+        y += line_height;
+        let timeline_top = y;
+
+        overlay.text(padding, y, "CPU", palette.text_dim);
+        for span in &frame.cpu {
+            draw_span(overlay, span, to_x, y + span.depth as f32 * lane_height, line_height, char_w);
+        }
+        y += lane_height * cpu_lanes as f32 + padding;
+
+        overlay.text(padding, y, "GPU", palette.text_dim);
+        for span in &frame.gpu {
+            draw_span(overlay, span, to_x, y, line_height, char_w);
+        }
+        y += lane_height;
+
+        let target_x = to_x(TARGET_MS);
+        overlay.rect(target_x, timeline_top, 1.0, y - timeline_top, palette.bad.with_alpha(0.6));
+    }
+}
+
+fn draw_span(overlay: &mut Overlay, span: &Span, to_x: impl Fn(f32) -> f32, y: f32, height: f32, char_w: f32) {
+    let x = to_x(span.start_ms);
+    let w = (to_x(span.end_ms) - x).max(1.0);
+    overlay.rect(x, y, w, height, span_color(span.name));
+    let label = format!("{} {:.2}", span.name, span.duration_ms());
+    if label.chars().count() as f32 * char_w <= w - 4.0 {
+        overlay.text(x + 2.0, y, &label, Color::BLACK);
+    } else if span.name.chars().count() as f32 * char_w <= w - 4.0 {
+        overlay.text(x + 2.0, y, span.name, Color::BLACK);
+    }
+}
+
+// Aynı isim her karede aynı renge denk gelsin diye isimden türetilir
+fn span_color(name: &str) -> Color {
+    let hash = name.bytes().fold(2166136261u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(16777619));
+    let hue = (hash % 360) as f32 / 60.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+    let [r, g, b] = match hue as u32 {
+        0 => [1.0, x, 0.0],
+        1 => [x, 1.0, 0.0],
+        2 => [0.0, 1.0, x],
+        3 => [0.0, x, 1.0],
+        4 => [x, 0.0, 1.0],
+        _ => [1.0, 0.0, x],
+    };
+    Color::srgba(0.35 + r * 0.6, 0.35 + g * 0.6, 0.35 + b * 0.6, 0.9)
+}
+
+fn frame_color(palette: &Palette, frame_ms: f32) -> Color {
+    if frame_ms <= TARGET_MS * 1.05 {
+        palette.good
+    } else if frame_ms <= TARGET_MS * 2.0 {
+        palette.warn
+    } else {
+        palette.bad
+    }
+}
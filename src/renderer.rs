@@ -0,0 +1,363 @@
+use crate::assets::{AssetManager, MeshHandle, MeshVertex, TextureHandle};
+use crate::color::SELECTION;
+use crate::frame_capture::TracedPass;
+use crate::frame_context::{FRAMES_IN_FLIGHT, FrameContext, PerFrame};
+use crate::scene::Scene;
+use bytemuck::Zeroable;
+use glam::{Mat4, Vec3};
+
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct InstanceRaw {
+    model: [[f32; 4]; 4],
+    color: [f32; 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct PointLightRaw {
+    // xyz: konum, w: menzil
+    position: [f32; 4],
+    // rgb: renk, a: yoğunluk
+    color: [f32; 4],
+}
+
+const MAX_LIGHTS: usize = 8;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct LightsRaw {
+    count: u32,
+    _padding: [u32; 3],
+    lights: [PointLightRaw; MAX_LIGHTS],
+}
+
+impl InstanceRaw {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        3 => Float32x4, 4 => Float32x4, 5 => Float32x4, 6 => Float32x4, 7 => Float32x4
+    ];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<InstanceRaw>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Aynı mesh ve dokuyu paylaşan örnekler tek çizim çağrısında çizilir
+struct DrawBatch {
+    mesh: MeshHandle,
+    texture: Option<TextureHandle>,
+    instances: std::ops::Range<u32>,
+}
+
+// Her karede yeniden yazılan kaynaklar; uçuştaki her kare için bir kopya
+struct FrameResources {
+    camera_buffer: wgpu::Buffer,
+    lights_buffer: wgpu::Buffer,
+    camera_bind_group: wgpu::BindGroup,
+    instance_buffer: wgpu::Buffer,
+    instance_capacity: usize,
+}
+
+impl FrameResources {
+    fn new(device: &wgpu::Device, camera_layout: &wgpu::BindGroupLayout) -> Self {
+        let camera_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Camera Buffer"),
+            size: std::mem::size_of::<[[f32; 4]; 4]>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let lights_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Lights Buffer"),
+            size: std::mem::size_of::<LightsRaw>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let camera_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Camera Bind Group"),
+            layout: camera_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: camera_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: lights_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let instance_capacity = 64;
+        Self {
+            camera_buffer,
+            lights_buffer,
+            camera_bind_group,
+            instance_buffer: SceneRenderer::create_instance_buffer(device, instance_capacity),
+            instance_capacity,
+        }
+    }
+}
+
+pub struct SceneRenderer {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    camera_layout: wgpu::BindGroupLayout,
+    frames: PerFrame<FrameResources>,
+    batches: Vec<DrawBatch>,
+    depth_view: wgpu::TextureView,
+    format: wgpu::TextureFormat,
+}
+
+impl SceneRenderer {
+    pub fn new(
+        device: &wgpu::Device,
+        assets: &AssetManager,
+        format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, String> {
+        let camera_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Camera Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Scene Pipeline Layout"),
+            bind_group_layouts: &[&camera_layout, assets.texture_layout()],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+
+        let frames = PerFrame::new(FRAMES_IN_FLIGHT, |_| FrameResources::new(device, &camera_layout));
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            camera_layout,
+            frames,
+            batches: Vec::new(),
+            depth_view: Self::create_depth_view(device, width, height),
+            format,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "scene.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Scene Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[MeshVertex::layout(), InstanceRaw::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    cull_mode: Some(wgpu::Face::Back),
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Mesh Instances"),
+            size: (capacity * std::mem::size_of::<InstanceRaw>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_depth_view(device: &wgpu::Device, width: u32, height: u32) -> wgpu::TextureView {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Depth Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            // Render hedefi görüntüleyicisi derinliği okuyabilsin diye
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        self.depth_view = Self::create_depth_view(device, width, height);
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        frame: FrameContext,
+        scene: &Scene,
+        aspect: f32,
+    ) {
+        let resources = self.frames.get_mut(frame);
+        let view_proj = scene.camera.view_proj(aspect);
+        queue.write_buffer(&resources.camera_buffer, 0, bytemuck::cast_slice(&view_proj.to_cols_array_2d()));
+
+        let mut lights = LightsRaw::zeroed();
+        for (id, entity) in scene.iter() {
+            let Some(light) = entity.light else { continue };
+            if lights.count as usize == MAX_LIGHTS {
+                break;
+            }
+            let position = scene.world_matrix(id).transform_point3(Vec3::ZERO);
+            lights.lights[lights.count as usize] = PointLightRaw {
+                position: position.extend(light.range).into(),
+                color: [light.color[0], light.color[1], light.color[2], light.intensity],
+            };
+            lights.count += 1;
+        }
+        queue.write_buffer(&resources.lights_buffer, 0, bytemuck::bytes_of(&lights));
+
+        let mut drawables: Vec<(MeshHandle, Option<TextureHandle>, InstanceRaw)> = scene
+            .iter()
+            .filter_map(|(id, entity)| {
+                let material = entity.material.unwrap_or_default();
+                let mut color = material.base_color;
+                // Seçili varlık hafifçe sarıya boyanır
+                if scene.selected == Some(id) {
+                    color = color.lerp(SELECTION, 0.4);
+                }
+                // Shader doğrusal renk bekler
+                let instance = InstanceRaw {
+                    model: scene.world_matrix(id).to_cols_array_2d(),
+                    color: color.to_linear(),
+                };
+                Some((entity.mesh?, material.texture, instance))
+            })
+            .collect();
+        drawables.extend(scene.frame_cubes.iter().map(|cube| {
+            let instance = InstanceRaw {
+                model: Mat4::from_rotation_translation(cube.rotation, cube.position).to_cols_array_2d(),
+                color: cube.color.to_linear(),
+            };
+            (MeshHandle::CUBE, None, instance)
+        }));
+        drawables.sort_by_key(|(mesh, texture, _)| (*mesh, *texture));
+
+        self.batches.clear();
+        for (index, (mesh, texture, _)) in drawables.iter().enumerate() {
+            let index = index as u32;
+            match self.batches.last_mut() {
+                Some(batch) if batch.mesh == *mesh && batch.texture == *texture => batch.instances.end = index + 1,
+                _ => self.batches.push(DrawBatch {
+                    mesh: *mesh,
+                    texture: *texture,
+                    instances: index..index + 1,
+                }),
+            }
+        }
+        let instances: Vec<InstanceRaw> = drawables.into_iter().map(|(_, _, instance)| instance).collect();
+
+        if instances.len() > resources.instance_capacity {
+            resources.instance_capacity = instances.len().next_power_of_two();
+            resources.instance_buffer = Self::create_instance_buffer(device, resources.instance_capacity);
+        }
+        if !instances.is_empty() {
+            queue.write_buffer(&resources.instance_buffer, 0, bytemuck::cast_slice(&instances));
+        }
+    }
+
+    // Kamera ve ışıklar; sahne pass'inde çizen diğer renderer'lar (ör. voksel)
+    // aynı bind group'u 0. gruba bağlar
+    pub fn camera_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.camera_layout
+    }
+
+    pub fn camera_bind_group(&self, frame: FrameContext) -> &wgpu::BindGroup {
+        &self.frames.get(frame).camera_bind_group
+    }
+
+    pub fn depth_view(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>, frame: FrameContext, assets: &AssetManager) {
+        if self.batches.is_empty() {
+            return;
+        }
+        let resources = self.frames.get(frame);
+        render_pass.set_pipeline(&self.pipeline, "Scene Pipeline");
+        render_pass.set_bind_group(0, &resources.camera_bind_group, "Camera Bind Group");
+        render_pass.set_vertex_buffer(1, &resources.instance_buffer, "Mesh Instances");
+
+        for batch in &self.batches {
+            // Kaldırılmış bir mesh'e bağlı varlıklar çizilmez
+            let Some(mesh) = assets.mesh(batch.mesh) else { continue };
+            let texture = assets.texture_or_white(batch.texture);
+            render_pass.set_bind_group(1, &texture.bind_group, &texture.name);
+            render_pass.set_vertex_buffer(0, &mesh.vertex_buffer, &mesh.name);
+            render_pass.set_index_buffer(&mesh.index_buffer, wgpu::IndexFormat::Uint32, &mesh.name);
+            render_pass.draw_indexed(0..mesh.index_count, 0, batch.instances.clone());
+        }
+    }
+}
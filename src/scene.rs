@@ -0,0 +1,272 @@
+use crate::assets::{MeshHandle, TextureHandle};
+use crate::color::Color;
+use glam::{Mat4, Quat, Vec3};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+        }
+    }
+}
+
+impl Transform {
+    pub fn matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Material {
+    pub base_color: Color,
+    pub texture: Option<TextureHandle>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            base_color: Color::srgb(0.8, 0.8, 0.8),
+            texture: None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Light {
+    pub color: [f32; 3],
+    pub intensity: f32,
+    pub range: f32,
+}
+
+impl Default for Light {
+    fn default() -> Self {
+        Self {
+            color: [1.0, 0.95, 0.85],
+            intensity: 4.0,
+            range: 15.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntityId(usize);
+
+pub struct Entity {
+    pub name: String,
+    pub parent: Option<EntityId>,
+    pub children: Vec<EntityId>,
+    pub transform: Transform,
+    pub mesh: Option<MeshHandle>,
+    pub material: Option<Material>,
+    pub light: Option<Light>,
+}
+
+// Her kare yeniden doldurulan, sahne ağacına girmeyen küpler
+// (ör. yeniden yüklenebilir oyun mantığının çizdikleri)
+pub struct Cube {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub color: Color,
+}
+
+// Perspektif izdüşümün yakın ve uzak düzlemleri
+pub const NEAR: f32 = 0.1;
+pub const FAR: f32 = 100.0;
+
+pub struct Camera {
+    pub eye: Vec3,
+    pub target: Vec3,
+    pub fov_y: f32,
+}
+
+impl Camera {
+    pub fn view_proj(&self, aspect: f32) -> Mat4 {
+        let view = Mat4::look_at_rh(self.eye, self.target, Vec3::Y);
+        let proj = Mat4::perspective_rh(self.fov_y, aspect, NEAR, FAR);
+        proj * view
+    }
+
+    // Normalize cihaz koordinatlarındaki (-1..1) bir noktadan geçen dünya uzayı ışını
+    pub fn ray(&self, aspect: f32, ndc_x: f32, ndc_y: f32) -> (Vec3, Vec3) {
+        let inverse = self.view_proj(aspect).inverse();
+        let near = inverse.project_point3(Vec3::new(ndc_x, ndc_y, 0.0));
+        let far = inverse.project_point3(Vec3::new(ndc_x, ndc_y, 1.0));
+        (near, (far - near).normalize())
+    }
+}
+
+pub struct Scene {
+    pub camera: Camera,
+    entities: Vec<Option<Entity>>,
+    pub selected: Option<EntityId>,
+    pub frame_cubes: Vec<Cube>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        let mut scene = Self {
+            camera: Camera {
+                eye: Vec3::new(6.0, 5.0, 9.0),
+                target: Vec3::ZERO,
+                fov_y: 45f32.to_radians(),
+            },
+            entities: Vec::new(),
+            selected: None,
+            frame_cubes: Vec::new(),
+        };
+
+        let light = scene.spawn("Işık", None);
+        let entity = scene.get_mut(light).unwrap();
+        entity.transform.translation = Vec3::new(2.0, 4.0, 3.0);
+        entity.light = Some(Light::default());
+        scene
+    }
+
+    pub fn spawn(&mut self, name: impl Into<String>, parent: Option<EntityId>) -> EntityId {
+        let entity = Entity {
+            name: name.into(),
+            parent,
+            children: Vec::new(),
+            transform: Transform::default(),
+            mesh: None,
+            material: None,
+            light: None,
+        };
+        let id = match self.entities.iter().position(Option::is_none) {
+            Some(index) => {
+                self.entities[index] = Some(entity);
+                EntityId(index)
+            }
+            None => {
+                self.entities.push(Some(entity));
+                EntityId(self.entities.len() - 1)
+            }
+        };
+        if let Some(parent) = parent.and_then(|parent| self.get_mut(parent)) {
+            parent.children.push(id);
+        }
+        id
+    }
+
+    // Varlığı çocuklarıyla birlikte siler
+    pub fn despawn(&mut self, id: EntityId) {
+        let Some(entity) = self.entities.get_mut(id.0).and_then(Option::take) else {
+            return;
+        };
+        if let Some(parent) = entity.parent.and_then(|parent| self.get_mut(parent)) {
+            parent.children.retain(|child| *child != id);
+        }
+        for child in entity.children {
+            self.despawn(child);
+        }
+        if self.selected == Some(id) {
+            self.selected = None;
+        }
+    }
+
+    pub fn get(&self, id: EntityId) -> Option<&Entity> {
+        self.entities.get(id.0).and_then(Option::as_ref)
+    }
+
+    pub fn get_mut(&mut self, id: EntityId) -> Option<&mut Entity> {
+        self.entities.get_mut(id.0).and_then(Option::as_mut)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &Entity)> {
+        self.entities
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entity)| entity.as_ref().map(|entity| (EntityId(index), entity)))
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.iter()
+            .filter(|(_, entity)| entity.parent.is_none())
+            .map(|(id, _)| id)
+    }
+
+    pub fn world_matrix(&self, id: EntityId) -> Mat4 {
+        let mut matrix = Mat4::IDENTITY;
+        let mut current = self.get(id);
+        while let Some(entity) = current {
+            matrix = entity.transform.matrix() * matrix;
+            current = entity.parent.and_then(|parent| self.get(parent));
+        }
+        matrix
+    }
+
+    pub fn spawn_cube(&mut self) -> EntityId {
+        self.spawn_on_spiral("Küp", MeshHandle::CUBE)
+    }
+
+    // Yeni nesneler orijin etrafında bir spiral üzerine dizilir
+    pub fn spawn_on_spiral(&mut self, name: &str, mesh: MeshHandle) -> EntityId {
+        let index = self.iter().filter(|(_, entity)| entity.mesh.is_some()).count() as f32;
+        let angle = index * 2.4;
+        let radius = 1.5 * index.sqrt();
+        let position = Vec3::new(angle.cos() * radius, 0.0, angle.sin() * radius);
+
+        let id = self.spawn_mesh(format!("{name} {}", index as usize + 1), mesh, position);
+        let entity = self.get_mut(id).unwrap();
+        entity.transform.rotation = Quat::from_rotation_y(angle);
+        id
+    }
+
+    pub fn spawn_mesh(&mut self, name: impl Into<String>, mesh: MeshHandle, position: Vec3) -> EntityId {
+        let hue = (self.entities.len() as f32 * 0.618).fract();
+        let id = self.spawn(name, None);
+        let entity = self.get_mut(id).unwrap();
+        entity.transform.translation = position;
+        entity.mesh = Some(mesh);
+        entity.material = Some(Material {
+            base_color: Color::srgb(hue, 1.0 - hue, 0.6),
+            texture: None,
+        });
+        id
+    }
+
+    // Işının çarptığı en yakın mesh'li varlık. Test, mesh'in sınır kutusuna
+    // karşı varlığın yerel uzayında yapılır.
+    pub fn pick(
+        &self,
+        origin: Vec3,
+        direction: Vec3,
+        bounds: impl Fn(MeshHandle) -> Option<(Vec3, Vec3)>,
+    ) -> Option<EntityId> {
+        self.iter()
+            .filter_map(|(id, entity)| {
+                let (min, max) = bounds(entity.mesh?)?;
+                let inverse = self.world_matrix(id).inverse();
+                let local_origin = inverse.transform_point3(origin);
+                let local_direction = inverse.transform_vector3(direction);
+                ray_box(local_origin, local_direction, min, max).map(|t| (id, t))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(id, _)| id)
+    }
+}
+
+// Işının y = 0 düzlemini kestiği nokta; düzleme paralel veya yukarı bakıyorsa None
+#[cfg(feature = "debug-ui")]
+pub fn ground_point(origin: Vec3, direction: Vec3) -> Option<Vec3> {
+    let t = -origin.y / direction.y;
+    (t.is_finite() && t > 0.0).then(|| origin + direction * t)
+}
+
+fn ray_box(origin: Vec3, direction: Vec3, min: Vec3, max: Vec3) -> Option<f32> {
+    let inverse = direction.recip();
+    let t1 = (min - origin) * inverse;
+    let t2 = (max - origin) * inverse;
+    let near = t1.min(t2).max_element();
+    let far = t1.max(t2).min_element();
+    (far >= near.max(0.0)).then_some(near.max(0.0))
+}
@@ -0,0 +1,125 @@
+use crate::color::linear_to_srgb;
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Yüzey dokusunu okunabilir bir buffer'a kopyalayan bekleyen çekim.
+pub struct Capture {
+    buffer: wgpu::Buffer,
+    width: u32,
+    height: u32,
+    padded_bytes_per_row: u32,
+    format: wgpu::TextureFormat,
+    // Float yüzeyde SDR beyazının değeri; PNG'ye yazarken 1.0'a ölçeklenir
+    white_level: f32,
+}
+
+// IEEE 754 yarım duyarlıklı sayıyı f32'ye çevirir
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let mantissa = (bits & 0x3ff) as f32;
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        31 if mantissa == 0.0 => sign * f32::INFINITY,
+        31 => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+impl Capture {
+    pub fn record(
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        white_level: f32,
+    ) -> Self {
+        let width = texture.width();
+        let height = texture.height();
+        let bytes_per_pixel = texture.format().block_copy_size(None).unwrap_or(4);
+        let unpadded = width * bytes_per_pixel;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = unpadded.div_ceil(align) * align;
+
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Screenshot Buffer"),
+            size: (padded_bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size(),
+        );
+
+        Self {
+            buffer,
+            width,
+            height,
+            padded_bytes_per_row,
+            format: texture.format(),
+            white_level,
+        }
+    }
+
+    // Komutlar gönderildikten sonra çağrılmalı; GPU bitene kadar bekler.
+    pub fn save(self, device: &wgpu::Device) -> Result<PathBuf, Box<dyn Error>> {
+        let directory = PathBuf::from("screenshots");
+        std::fs::create_dir_all(&directory)?;
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let path = directory.join(format!("screenshot-{timestamp}.png"));
+        self.save_as(device, path)
+    }
+
+    pub fn save_as(self, device: &wgpu::Device, path: PathBuf) -> Result<PathBuf, Box<dyn Error>> {
+        let slice = self.buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        device.poll(wgpu::PollType::Wait)?;
+
+        let swap_red_blue = matches!(
+            self.format,
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+        let half_float = self.format == wgpu::TextureFormat::Rgba16Float;
+        let bytes_per_pixel = if half_float { 8 } else { 4 };
+        let mut pixels = Vec::with_capacity((self.width * self.height * 4) as usize);
+        {
+            let data = slice.get_mapped_range();
+            for row in data.chunks(self.padded_bytes_per_row as usize) {
+                for texel in row[..(self.width * bytes_per_pixel) as usize].chunks(bytes_per_pixel as usize) {
+                    if half_float {
+                        // scRGB: SDR beyazının üstü kırpılır, sonra sRGB'ye kodlanır
+                        for channel in texel[..6].chunks(2) {
+                            let linear = f16_to_f32(u16::from_le_bytes([channel[0], channel[1]])) / self.white_level;
+                            let encoded = linear_to_srgb(linear.clamp(0.0, 1.0));
+                            pixels.push((encoded * 255.0).round() as u8);
+                        }
+                        pixels.push(255);
+                    } else if swap_red_blue {
+                        pixels.extend_from_slice(&[texel[2], texel[1], texel[0], 255]);
+                    } else {
+                        pixels.extend_from_slice(&[texel[0], texel[1], texel[2], 255]);
+                    }
+                }
+            }
+        }
+        self.buffer.unmap();
+
+        let file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+        let mut encoder = png::Encoder::new(file, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&pixels)?;
+
+        Ok(path)
+    }
+}
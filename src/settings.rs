@@ -0,0 +1,234 @@
+use crate::color::{Color, Palette};
+use std::fmt;
+use std::str::FromStr;
+
+// Olay döngüsünün ne zaman yeni kare çizeceği
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RedrawPolicy {
+    // Her döngü turunda çizer (oyunlar, animasyon)
+    Continuous,
+    // Sadece bir olay durumu değiştirdiğinde (kirli bayrağı) çizer
+    OnDemand,
+    // Saniyede sabit sayıda kare; aradaki sürede döngü bekler
+    FixedRate(f64),
+}
+
+impl fmt::Display for RedrawPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedrawPolicy::Continuous => write!(f, "continuous"),
+            RedrawPolicy::OnDemand => write!(f, "ondemand"),
+            RedrawPolicy::FixedRate(hz) => write!(f, "fixed {hz}"),
+        }
+    }
+}
+
+impl FromStr for RedrawPolicy {
+    type Err = String;
+
+    // "continuous", "ondemand" veya "fixed <hz>"
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut words = text.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("continuous"), None) => Ok(RedrawPolicy::Continuous),
+            (Some("ondemand"), None) => Ok(RedrawPolicy::OnDemand),
+            (Some("fixed"), Some(hz)) => match hz.parse::<f64>() {
+                Ok(hz) if hz > 0.0 && hz.is_finite() => Ok(RedrawPolicy::FixedRate(hz)),
+                _ => Err(format!("geçersiz kare hızı: {hz}")),
+            },
+            _ => Err("kullanım: continuous | ondemand | fixed <hz>".into()),
+        }
+    }
+}
+
+// Continuous politikasında karelerin ne zaman başlatılacağı
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Pacing {
+    // Kare biter bitmez yenisi başlar
+    Off,
+    // Ölçülen sunum aralıklarına göre sabit ya da VRR zamanlaması seçilir
+    Auto,
+    // Kare, bir sonraki dikey boşluktan iş süresi kadar önce başlar
+    Fixed,
+    // Değişken yenileme hızı: kareler tutarlı bir aralıkla sunulur
+    Vrr,
+}
+
+impl fmt::Display for Pacing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Pacing::Off => "off",
+            Pacing::Auto => "auto",
+            Pacing::Fixed => "fixed",
+            Pacing::Vrr => "vrr",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Pacing {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim() {
+            "off" => Ok(Pacing::Off),
+            "auto" => Ok(Pacing::Auto),
+            "fixed" => Ok(Pacing::Fixed),
+            "vrr" => Ok(Pacing::Vrr),
+            _ => Err("kullanım: off | auto | fixed | vrr".into()),
+        }
+    }
+}
+
+// Arayüzün renk teması
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Theme {
+    Dark,
+    Light,
+}
+
+impl Theme {
+    // Sistem teması bilinmiyorsa (ör. bazı X11 masaüstleri) koyu tema
+    pub fn from_system(theme: Option<winit::window::Theme>) -> Self {
+        match theme {
+            Some(winit::window::Theme::Light) => Theme::Light,
+            _ => Theme::Dark,
+        }
+    }
+
+    pub fn palette(self) -> &'static Palette {
+        match self {
+            Theme::Dark => &Palette::DARK,
+            Theme::Light => &Palette::LIGHT,
+        }
+    }
+
+    // Sahnenin varsayılan arka planı
+    pub fn clear_color(self) -> Color {
+        match self {
+            Theme::Dark => Color::BLACK,
+            Theme::Light => Color::srgb(0.9, 0.91, 0.93),
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+        })
+    }
+}
+
+// Tema tercihi: sistemi izle ya da elle sabitle
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThemePreference {
+    System,
+    Dark,
+    Light,
+}
+
+impl ThemePreference {
+    pub fn resolve(self, system: Theme) -> Theme {
+        match self {
+            ThemePreference::System => system,
+            ThemePreference::Dark => Theme::Dark,
+            ThemePreference::Light => Theme::Light,
+        }
+    }
+}
+
+impl fmt::Display for ThemePreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ThemePreference::System => "system",
+            ThemePreference::Dark => "dark",
+            ThemePreference::Light => "light",
+        })
+    }
+}
+
+impl FromStr for ThemePreference {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.trim() {
+            "system" => Ok(ThemePreference::System),
+            "dark" => Ok(ThemePreference::Dark),
+            "light" => Ok(ThemePreference::Light),
+            _ => Err("kullanım: system | dark | light".into()),
+        }
+    }
+}
+
+// Uygulama ayarları; başlangıçta komut satırından okunur, çoğu çalışma
+// anında konsoldan değiştirilebilir
+#[derive(Clone, Debug)]
+pub struct Settings {
+    pub redraw_policy: RedrawPolicy,
+    pub pacing: Pacing,
+    // Yüzey destekliyorsa HDR (scRGB) çıktı; sadece başlangıçta uygulanır
+    pub hdr: bool,
+    pub theme: ThemePreference,
+    // Verilmezse yüzeyin ilk sunum kipi kullanılır; `set_vsync` ile değişir
+    pub vsync: Option<bool>,
+    // Rastgele sistemlerin tohumu; verilmezse her çalıştırmada saatten seçilir
+    pub seed: Option<u64>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            redraw_policy: RedrawPolicy::Continuous,
+            pacing: Pacing::Off,
+            hdr: false,
+            theme: ThemePreference::System,
+            vsync: None,
+            seed: None,
+        }
+    }
+}
+
+impl Settings {
+    // `--hdr`, `--redraw <politika>`, `--pacing <mod>`, `--theme <tema>`, `--vsync on|off`,
+    // `--seed <sayı>`; verilmeyenler `AppBuilder` ile kodda seçilen değerlerde kalır
+    pub fn with_args(self, args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut settings = self;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--hdr" => settings.hdr = true,
+                "--redraw" => {
+                    let policy = args.next().ok_or("--redraw için politika gerekli")?;
+                    // "fixed 30" tek argüman olarak ya da ayrı ayrı verilebilir
+                    let policy = if policy == "fixed" {
+                        format!("fixed {}", args.next().unwrap_or_default())
+                    } else {
+                        policy
+                    };
+                    settings.redraw_policy = policy.parse()?;
+                }
+                "--pacing" => {
+                    settings.pacing = args.next().ok_or("--pacing için mod gerekli")?.parse()?;
+                }
+                "--theme" => {
+                    settings.theme = args.next().ok_or("--theme için tema gerekli")?.parse()?;
+                }
+                "--vsync" => {
+                    settings.vsync = match args.next().as_deref() {
+                        Some("on") => Some(true),
+                        Some("off") => Some(false),
+                        _ => return Err("kullanım: --vsync on|off".into()),
+                    };
+                }
+                "--seed" => {
+                    let seed = args.next().ok_or("--seed için sayı gerekli")?;
+                    settings.seed = Some(seed.parse().map_err(|_| format!("geçersiz tohum: {seed}"))?);
+                }
+                other => return Err(format!("bilinmeyen argüman: {other}")),
+            }
+        }
+        Ok(settings)
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+// Shader'lar derleme sırasında gömülür, ama `reload_shaders` diskteki
+// güncel halini okur. Böylece çalışan uygulamayı kapatmadan WGSL düzenlenebilir.
+const EMBEDDED: &[(&str, &str)] = &[
+    ("bitonic_sort.wgsl", include_str!("shaders/bitonic_sort.wgsl")),
+    ("lines.wgsl", include_str!("shaders/lines.wgsl")),
+    ("marching_cubes.wgsl", include_str!("shaders/marching_cubes.wgsl")),
+    ("noise.wgsl", include_str!("shaders/noise.wgsl")),
+    ("noise_bake.wgsl", include_str!("shaders/noise_bake.wgsl")),
+    ("overlay.wgsl", include_str!("shaders/overlay.wgsl")),
+    ("point_cloud.wgsl", include_str!("shaders/point_cloud.wgsl")),
+    ("scene.wgsl", include_str!("shaders/scene.wgsl")),
+    ("shapes.wgsl", include_str!("shaders/shapes.wgsl")),
+    ("skinning.wgsl", include_str!("shaders/skinning.wgsl")),
+    ("sprites.wgsl", include_str!("shaders/sprites.wgsl")),
+    ("target_view.wgsl", include_str!("shaders/target_view.wgsl")),
+    ("tonemap.wgsl", include_str!("shaders/tonemap.wgsl")),
+    ("voxel.wgsl", include_str!("shaders/voxel.wgsl")),
+];
+
+#[cfg(feature = "debug-ui")]
+pub fn names() -> impl Iterator<Item = &'static str> {
+    EMBEDDED.iter().map(|(name, _)| *name)
+}
+
+pub fn path(name: &str) -> PathBuf {
+    shader_dir().join(name)
+}
+
+fn shader_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src").join("shaders")
+}
+
+// WGSL'de içe aktarma yok; `#include "dosya.wgsl"` satırı o dosyanın
+// içeriğiyle değiştirilir (ör. ortak gürültü fonksiyonları)
+pub fn source(name: &str) -> String {
+    let text = match std::fs::read_to_string(path(name)) {
+        Ok(source) => source,
+        Err(_) => EMBEDDED
+            .iter()
+            .find(|(file, _)| *file == name)
+            .map(|(_, source)| source.to_string())
+            .unwrap_or_else(|| panic!("Bilinmeyen shader: {name}")),
+    };
+    text.lines()
+        .map(|line| match line.trim().strip_prefix("#include ") {
+            Some(include) => source(include.trim().trim_matches('"')),
+            None => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Doğrulama hatalarını panik yerine Err olarak döndürür, böylece bozuk bir
+// shader yeniden yüklenirken eski pipeline kullanılmaya devam eder.
+pub fn validated<T>(device: &wgpu::Device, create: impl FnOnce() -> T) -> Result<T, String> {
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let value = create();
+    match pollster::block_on(device.pop_error_scope()) {
+        Some(error) => Err(error.to_string()),
+        None => Ok(value),
+    }
+}
+
+pub fn create_module(device: &wgpu::Device, name: &str) -> Result<wgpu::ShaderModule, String> {
+    let source = source(name);
+    validated(device, || {
+        device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(name),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        })
+    })
+    .map_err(|error| format!("{name}: {error}"))
+}
@@ -0,0 +1,32 @@
+// Bitonik sıralama ağının tek bir adımı: her eleman `j` uzaklıktaki eşiyle
+// karşılaştırılır, `k` boyutlu bloğun yönüne göre gerekirse yer değiştirir.
+struct Params {
+    j: u32,
+    k: u32,
+    count: u32,
+    // Bir dispatch satırındaki iş parçacığı sayısı (65535 grup sınırı için 2B dispatch)
+    row: u32,
+};
+
+@group(0) @binding(0) var<storage, read_write> keys: array<u32>;
+@group(0) @binding(1) var<storage, read_write> values: array<u32>;
+@group(0) @binding(2) var<uniform> params: Params;
+
+@compute @workgroup_size(256)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.y * params.row + id.x;
+    let partner = i ^ params.j;
+    if i >= params.count || partner <= i {
+        return;
+    }
+    let ascending = (i & params.k) == 0u;
+    let a = keys[i];
+    let b = keys[partner];
+    if (a > b) == ascending {
+        keys[i] = b;
+        keys[partner] = a;
+        let value = values[i];
+        values[i] = values[partner];
+        values[partner] = value;
+    }
+}
@@ -0,0 +1,129 @@
+// Kalın çizgiler: her örnek bir parçadır (veya yuvarlak birleşim/uç için bir
+// disk) ve ekran uzayında genişliği kadar bir dörtgene genişletilir. Kenar
+// yumuşatma için dörtgen bir piksel taşar; kapsama fragment'te mesafeden hesaplanır.
+struct Params {
+    view_proj: mat4x4<f32>,
+    viewport: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> params: Params;
+
+// lines.rs ile aynı bayraklar
+const DISC: u32 = 1u;
+const SQUARE_START: u32 = 2u;
+const SQUARE_END: u32 = 4u;
+const MITER_START: u32 = 8u;
+const MITER_END: u32 = 16u;
+// Gönye en fazla yarım genişliğin bu katı kadar uzar
+const MITER_LIMIT: f32 = 4.0;
+// Kenar yumuşatma için taşma (piksel)
+const FRINGE: f32 = 1.0;
+
+struct LineInput {
+    @location(0) start_width: vec4<f32>,
+    @location(1) end: vec3<f32>,
+    @location(2) flags: u32,
+    @location(3) prev: vec3<f32>,
+    @location(4) next: vec3<f32>,
+    @location(5) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    // Parçada merkez çizgisinden, diskte merkezden uzaklık vektörü (piksel)
+    @location(1) local: vec2<f32>,
+    @location(2) @interpolate(flat) half_width: f32,
+    @location(3) @interpolate(flat) flags: u32,
+};
+
+fn to_screen(clip: vec4<f32>) -> vec2<f32> {
+    return (clip.xy / clip.w * 0.5 + 0.5) * params.viewport;
+}
+
+fn direction(p0: vec2<f32>, p1: vec2<f32>) -> vec2<f32> {
+    let delta = p1 - p0;
+    let len = length(delta);
+    return select(vec2<f32>(1.0, 0.0), delta / len, len > 1e-4);
+}
+
+fn perpendicular(v: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(-v.y, v.x);
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32, line: LineInput) -> VertexOutput {
+    var out: VertexOutput;
+    let width = line.start_width.w;
+    // 1 pikselden ince çizgiler 1 piksel çizilip saydamlaştırılır
+    let half_width = max(width, 1.0) * 0.5;
+    let extent = half_width + FRINGE;
+    out.color = vec4<f32>(line.color.rgb, line.color.a * min(width, 1.0));
+    out.half_width = half_width;
+    out.flags = line.flags;
+
+    var a = params.view_proj * vec4<f32>(line.start_width.xyz, 1.0);
+    var b = params.view_proj * vec4<f32>(line.end, 1.0);
+    // Yakın düzlemin (z = 0) arkasındaki uç düzleme çekilir; ikisi de arkadaysa çizilmez
+    if (a.z < 0.0 && b.z < 0.0) {
+        out.clip_position = vec4<f32>(0.0, 0.0, -1.0, 1.0);
+        return out;
+    }
+    if (a.z < 0.0) {
+        a = mix(a, b, a.z / (a.z - b.z));
+    } else if (b.z < 0.0) {
+        b = mix(b, a, b.z / (b.z - a.z));
+    }
+
+    let at_end = (index & 1u) == 1u;
+    let side = f32(index >> 1u) * 2.0 - 1.0;
+    let clip = select(a, b, at_end);
+    var offset: vec2<f32>;
+
+    if ((line.flags & DISC) != 0u) {
+        let corner = vec2<f32>(select(-1.0, 1.0, at_end), side);
+        offset = corner * extent;
+        out.local = offset;
+    } else {
+        let screen_a = to_screen(a);
+        let screen_b = to_screen(b);
+        let dir = direction(screen_a, screen_b);
+        let normal = perpendicular(dir);
+        offset = normal * side * extent;
+        out.local = vec2<f32>(0.0, side * extent);
+
+        let square = select(SQUARE_START, SQUARE_END, at_end);
+        if ((line.flags & square) != 0u) {
+            offset += dir * select(-half_width, half_width, at_end);
+        }
+
+        // Gönye: köşe noktası iki parçanın normallerinin ortalaması yönünde kaydırılır
+        let miter = select(MITER_START, MITER_END, at_end);
+        if ((line.flags & miter) != 0u) {
+            let neighbour = params.view_proj * vec4<f32>(select(line.prev, line.next, at_end), 1.0);
+            if (neighbour.z >= 0.0) {
+                let screen_n = to_screen(neighbour);
+                let other = select(direction(screen_n, screen_a), direction(screen_b, screen_n), at_end);
+                let bisector = normal + perpendicular(other);
+                if (length(bisector) > 1e-3) {
+                    let miter_dir = normalize(bisector);
+                    let scale = 1.0 / max(dot(miter_dir, normal), 1.0 / MITER_LIMIT);
+                    offset = miter_dir * side * extent * scale;
+                }
+            }
+        }
+    }
+
+    out.clip_position = vec4<f32>(clip.xy + offset / params.viewport * 2.0 * clip.w, clip.zw);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let distance = select(abs(in.local.y), length(in.local), (in.flags & DISC) != 0u);
+    let coverage = clamp(in.half_width + 0.5 - distance, 0.0, 1.0);
+    if (coverage <= 0.0) {
+        discard;
+    }
+    return vec4<f32>(in.color.rgb, in.color.a * coverage);
+}
@@ -0,0 +1,88 @@
+// Her iş parçacığı bir hücreyi işler ve sabit 15 köşelik yuvasına üçgen
+// yazar; kullanılmayan yuvalar sıfırlanır. Alan marching_cubes.rs'deki
+// `blob` ile, kenar numaraları `edge_corners` ile aynı.
+#include "noise.wgsl"
+
+struct Params {
+    origin: vec3<f32>,
+    spacing: f32,
+    cells: vec3<u32>,
+    time: f32,
+    iso: f32,
+};
+
+// MeshVertex düzeni (konum, normal, uv) düz f32 dizisi olarak yazılır
+const VERTEX_FLOATS: u32 = 8u;
+const MAX_CELL_VERTICES: u32 = 15u;
+const TABLE_ROW: u32 = 16u;
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> table: array<i32>;
+@group(0) @binding(2) var<storage, read_write> vertices: array<f32>;
+
+fn blob(p: vec3<f32>, time: f32) -> f32 {
+    let flow = vec3<f32>(0.0, time * 0.6, time * 0.3);
+    return 1.0 - length(p) + 0.45 * perlin3(p * 1.8 + flow, 7u);
+}
+
+fn corner_offset(corner: u32) -> vec3<f32> {
+    return vec3<f32>(f32(corner & 1u), f32((corner >> 1u) & 1u), f32((corner >> 2u) & 1u));
+}
+
+fn edge_corners(edge: u32) -> vec2<u32> {
+    let axis = edge / 4u;
+    let k = edge % 4u;
+    let lower = (k & ((1u << axis) - 1u)) | ((k >> axis) << (axis + 1u));
+    return vec2<u32>(lower, lower | (1u << axis));
+}
+
+fn write_vertex(slot: u32, position: vec3<f32>, normal: vec3<f32>, uv: vec2<f32>) {
+    let base = slot * VERTEX_FLOATS;
+    vertices[base] = position.x;
+    vertices[base + 1u] = position.y;
+    vertices[base + 2u] = position.z;
+    vertices[base + 3u] = normal.x;
+    vertices[base + 4u] = normal.y;
+    vertices[base + 5u] = normal.z;
+    vertices[base + 6u] = uv.x;
+    vertices[base + 7u] = uv.y;
+}
+
+@compute @workgroup_size(4, 4, 4)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if any(id >= params.cells) {
+        return;
+    }
+    let cell = (id.z * params.cells.y + id.y) * params.cells.x + id.x;
+    let base = params.origin + vec3<f32>(id) * params.spacing;
+
+    var values: array<f32, 8>;
+    var case_index = 0u;
+    for (var corner = 0u; corner < 8u; corner++) {
+        values[corner] = blob(base + corner_offset(corner) * params.spacing, params.time);
+        if values[corner] > params.iso {
+            case_index |= 1u << corner;
+        }
+    }
+
+    let epsilon = params.spacing * 0.5;
+    for (var i = 0u; i < MAX_CELL_VERTICES; i++) {
+        let slot = cell * MAX_CELL_VERTICES + i;
+        let edge = table[case_index * TABLE_ROW + i];
+        if edge < 0 {
+            write_vertex(slot, vec3<f32>(0.0), vec3<f32>(0.0), vec2<f32>(0.0));
+            continue;
+        }
+        let corners = edge_corners(u32(edge));
+        let a = values[corners.x];
+        let b = values[corners.y];
+        let t = clamp((params.iso - a) / (b - a), 0.0, 1.0);
+        let position = base + mix(corner_offset(corners.x), corner_offset(corners.y), t) * params.spacing;
+        let gradient = vec3<f32>(
+            blob(position + vec3<f32>(epsilon, 0.0, 0.0), params.time) - blob(position - vec3<f32>(epsilon, 0.0, 0.0), params.time),
+            blob(position + vec3<f32>(0.0, epsilon, 0.0), params.time) - blob(position - vec3<f32>(0.0, epsilon, 0.0), params.time),
+            blob(position + vec3<f32>(0.0, 0.0, epsilon), params.time) - blob(position - vec3<f32>(0.0, 0.0, epsilon), params.time),
+        );
+        write_vertex(slot, position, -normalize(gradient), position.xz * 0.5);
+    }
+}
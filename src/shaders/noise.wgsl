@@ -0,0 +1,185 @@
+// Perlin, simplex ve worley gürültüsü; src/noise.rs ile birebir aynı hash ve
+// gradyanlar. Diğer shader'lar `#include "noise.wgsl"` ile kullanır.
+
+// lowbias32 (Chris Wellons); u32 çarpımı WGSL'de taşarak sarar
+fn noise_hash(value: u32) -> u32 {
+    var x = value;
+    x ^= x >> 16u;
+    x *= 0x7feb352du;
+    x ^= x >> 15u;
+    x *= 0x846ca68bu;
+    x ^= x >> 16u;
+    return x;
+}
+
+fn noise_hash2(x: i32, y: i32, seed: u32) -> u32 {
+    return noise_hash(bitcast<u32>(x) ^ noise_hash(bitcast<u32>(y) ^ noise_hash(seed)));
+}
+
+fn noise_hash3(x: i32, y: i32, z: i32, seed: u32) -> u32 {
+    return noise_hash(bitcast<u32>(x) ^ noise_hash(bitcast<u32>(y) ^ noise_hash(bitcast<u32>(z) ^ noise_hash(seed))));
+}
+
+// Hash'in üst 24 biti, [0, 1)
+fn noise_unit(h: u32) -> f32 {
+    return f32(h >> 8u) / 16777216.0;
+}
+
+fn noise_fade(t: f32) -> f32 {
+    return t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+}
+
+fn noise_gradient2(h: u32) -> vec2<f32> {
+    switch h & 7u {
+        case 0u: { return vec2<f32>(1.0, 0.0); }
+        case 1u: { return vec2<f32>(-1.0, 0.0); }
+        case 2u: { return vec2<f32>(0.0, 1.0); }
+        case 3u: { return vec2<f32>(0.0, -1.0); }
+        case 4u: { return vec2<f32>(1.0, 1.0); }
+        case 5u: { return vec2<f32>(-1.0, 1.0); }
+        case 6u: { return vec2<f32>(1.0, -1.0); }
+        default: { return vec2<f32>(-1.0, -1.0); }
+    }
+}
+
+fn noise_gradient3(h: u32) -> vec3<f32> {
+    switch h % 12u {
+        case 0u: { return vec3<f32>(1.0, 1.0, 0.0); }
+        case 1u: { return vec3<f32>(-1.0, 1.0, 0.0); }
+        case 2u: { return vec3<f32>(1.0, -1.0, 0.0); }
+        case 3u: { return vec3<f32>(-1.0, -1.0, 0.0); }
+        case 4u: { return vec3<f32>(1.0, 0.0, 1.0); }
+        case 5u: { return vec3<f32>(-1.0, 0.0, 1.0); }
+        case 6u: { return vec3<f32>(1.0, 0.0, -1.0); }
+        case 7u: { return vec3<f32>(-1.0, 0.0, -1.0); }
+        case 8u: { return vec3<f32>(0.0, 1.0, 1.0); }
+        case 9u: { return vec3<f32>(0.0, -1.0, 1.0); }
+        case 10u: { return vec3<f32>(0.0, 1.0, -1.0); }
+        default: { return vec3<f32>(0.0, -1.0, -1.0); }
+    }
+}
+
+fn perlin_corner2(cell: vec2<i32>, f: vec2<f32>, dx: i32, dy: i32, seed: u32) -> f32 {
+    let gradient = noise_gradient2(noise_hash2(cell.x + dx, cell.y + dy, seed));
+    return dot(gradient, f - vec2<f32>(f32(dx), f32(dy)));
+}
+
+// Yaklaşık [-1, 1]
+fn perlin2(p: vec2<f32>, seed: u32) -> f32 {
+    let floored = floor(p);
+    let cell = vec2<i32>(floored);
+    let f = p - floored;
+    let u = noise_fade(f.x);
+    let v = noise_fade(f.y);
+    let bottom = mix(perlin_corner2(cell, f, 0, 0, seed), perlin_corner2(cell, f, 1, 0, seed), u);
+    let top = mix(perlin_corner2(cell, f, 0, 1, seed), perlin_corner2(cell, f, 1, 1, seed), u);
+    return mix(bottom, top, v);
+}
+
+fn perlin_corner3(cell: vec3<i32>, f: vec3<f32>, offset: vec3<i32>, seed: u32) -> f32 {
+    let corner = cell + offset;
+    let gradient = noise_gradient3(noise_hash3(corner.x, corner.y, corner.z, seed));
+    return dot(gradient, f - vec3<f32>(offset));
+}
+
+// Yaklaşık [-1, 1]
+fn perlin3(p: vec3<f32>, seed: u32) -> f32 {
+    let floored = floor(p);
+    let cell = vec3<i32>(floored);
+    let f = p - floored;
+    let u = noise_fade(f.x);
+    let v = noise_fade(f.y);
+    let w = noise_fade(f.z);
+    let near = mix(
+        mix(perlin_corner3(cell, f, vec3<i32>(0, 0, 0), seed), perlin_corner3(cell, f, vec3<i32>(1, 0, 0), seed), u),
+        mix(perlin_corner3(cell, f, vec3<i32>(0, 1, 0), seed), perlin_corner3(cell, f, vec3<i32>(1, 1, 0), seed), u),
+        v,
+    );
+    let far = mix(
+        mix(perlin_corner3(cell, f, vec3<i32>(0, 0, 1), seed), perlin_corner3(cell, f, vec3<i32>(1, 0, 1), seed), u),
+        mix(perlin_corner3(cell, f, vec3<i32>(0, 1, 1), seed), perlin_corner3(cell, f, vec3<i32>(1, 1, 1), seed), u),
+        v,
+    );
+    return mix(near, far, w);
+}
+
+fn simplex_contribution(d: vec2<f32>, x: i32, y: i32, seed: u32) -> f32 {
+    let t = 0.5 - dot(d, d);
+    if t <= 0.0 {
+        return 0.0;
+    }
+    let t2 = t * t;
+    return t2 * t2 * dot(noise_gradient2(noise_hash2(x, y, seed)), d);
+}
+
+// 2B simplex; yaklaşık [-1, 1]
+fn simplex2(p: vec2<f32>, seed: u32) -> f32 {
+    let F2 = 0.36602542; // (sqrt(3) - 1) / 2
+    let G2 = 0.21132487; // (3 - sqrt(3)) / 6
+
+    let skew = (p.x + p.y) * F2;
+    let floored = floor(p + vec2<f32>(skew));
+    let unskew = (floored.x + floored.y) * G2;
+    let d0 = p - (floored - vec2<f32>(unskew));
+    var step = vec2<f32>(0.0, 1.0);
+    if d0.x > d0.y {
+        step = vec2<f32>(1.0, 0.0);
+    }
+    let d1 = d0 - step + vec2<f32>(G2);
+    let d2 = d0 - vec2<f32>(1.0) + vec2<f32>(2.0 * G2);
+
+    let cell = vec2<i32>(floored);
+    let offset = vec2<i32>(step);
+    return 70.0 * (simplex_contribution(d0, cell.x, cell.y, seed)
+        + simplex_contribution(d1, cell.x + offset.x, cell.y + offset.y, seed)
+        + simplex_contribution(d2, cell.x + 1, cell.y + 1, seed));
+}
+
+// En yakın öznitelik noktasına uzaklık (F1), [0, ~1.2]
+fn worley2(p: vec2<f32>, seed: u32) -> f32 {
+    let floored = floor(p);
+    let cell = vec2<i32>(floored);
+    let local = p - floored;
+    var nearest = 3.4028235e38;
+    for (var dy = -1; dy <= 1; dy++) {
+        for (var dx = -1; dx <= 1; dx++) {
+            let h = noise_hash2(cell.x + dx, cell.y + dy, seed);
+            let point = vec2<f32>(f32(dx) + noise_unit(h), f32(dy) + noise_unit(noise_hash(h)));
+            let d = point - local;
+            nearest = min(nearest, dot(d, d));
+        }
+    }
+    return sqrt(nearest);
+}
+
+// `kind`: 0 perlin, 1 simplex, 2 worley (NoiseKind::index)
+fn noise_fbm(kind: u32, p: vec2<f32>, octaves: u32, seed: u32) -> f32 {
+    var sum = 0.0;
+    var amplitude = 0.5;
+    var frequency = 1.0;
+    var total = 0.0;
+    for (var octave = 0u; octave < max(octaves, 1u); octave++) {
+        let q = p * frequency;
+        let octave_seed = seed + octave;
+        var value: f32;
+        switch kind {
+            case 0u: { value = perlin2(q, octave_seed); }
+            case 1u: { value = simplex2(q, octave_seed); }
+            default: { value = worley2(q, octave_seed); }
+        }
+        sum += value * amplitude;
+        total += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+    return sum / total;
+}
+
+// Dokuya yazılacak [0, 1] değer; noise::sample
+fn sample_noise(kind: u32, p: vec2<f32>, octaves: u32, seed: u32) -> f32 {
+    let value = noise_fbm(kind, p, octaves, seed);
+    if kind == 2u {
+        return clamp(value, 0.0, 1.0);
+    }
+    return clamp(value * 0.5 + 0.5, 0.0, 1.0);
+}
@@ -0,0 +1,25 @@
+// Seçilen gürültüyü fBm ile bir dokuya pişirir; sonuç gri tonlamalı ve tam opak
+#include "noise.wgsl"
+
+struct Params {
+    kind: u32,
+    octaves: u32,
+    seed: u32,
+    // Doku boyunca kaç gürültü hücresi
+    scale: f32,
+};
+
+@group(0) @binding(0) var output: texture_storage_2d<rgba8unorm, write>;
+@group(0) @binding(1) var<uniform> params: Params;
+
+@compute @workgroup_size(8, 8)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let size = textureDimensions(output);
+    if id.x >= size.x || id.y >= size.y {
+        return;
+    }
+    // Piksel merkezi; noise_bake.rs'deki CPU karşılaştırması aynı noktayı örnekler
+    let uv = (vec2<f32>(id.xy) + vec2<f32>(0.5)) / vec2<f32>(size);
+    let value = sample_noise(params.kind, uv * params.scale, params.octaves, params.seed);
+    textureStore(output, vec2<i32>(id.xy), vec4<f32>(value, value, value, 1.0));
+}
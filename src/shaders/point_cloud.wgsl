@@ -0,0 +1,61 @@
+// Nokta bulutu. vs_quad her noktayı ekran uzayında sabit piksel boyutlu bir
+// dörtgene genişletir (örnek başına 4 köşe, triangle strip); vs_point donanım
+// nokta primitifini kullanır (WebGPU'da her zaman 1 piksel).
+struct Params {
+    view_proj: mat4x4<f32>,
+    viewport: vec2<f32>,
+    point_size: f32,
+};
+
+@group(0) @binding(0) var<uniform> params: Params;
+
+struct PointInput {
+    @location(0) position: vec3<f32>,
+    // sRGB
+    @location(1) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec3<f32>,
+    // Dörtgen içindeki konum, -1..1; nokta modunda sıfır
+    @location(1) corner: vec2<f32>,
+};
+
+fn srgb_to_linear(color: vec3<f32>) -> vec3<f32> {
+    let low = color / 12.92;
+    let high = pow((color + 0.055) / 1.055, vec3<f32>(2.4));
+    return select(high, low, color <= vec3<f32>(0.04045));
+}
+
+@vertex
+fn vs_quad(@builtin(vertex_index) index: u32, point: PointInput) -> VertexOutput {
+    let corner = vec2<f32>(f32(index & 1u), f32(index >> 1u)) * 2.0 - 1.0;
+    var clip = params.view_proj * vec4<f32>(point.position, 1.0);
+    // Piksel -> NDC: 2 / viewport; yarım boyut için point_size / viewport
+    clip = vec4<f32>(clip.xy + corner * params.point_size / params.viewport * clip.w, clip.zw);
+
+    var out: VertexOutput;
+    out.clip_position = clip;
+    out.color = srgb_to_linear(point.color.rgb);
+    out.corner = corner;
+    return out;
+}
+
+@vertex
+fn vs_point(point: PointInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = params.view_proj * vec4<f32>(point.position, 1.0);
+    out.color = srgb_to_linear(point.color.rgb);
+    out.corner = vec2<f32>(0.0);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    // Yuvarlak noktalar
+    if (dot(in.corner, in.corner) > 1.0) {
+        discard;
+    }
+    return vec4<f32>(in.color, 1.0);
+}
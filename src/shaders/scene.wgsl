@@ -0,0 +1,75 @@
+struct Camera {
+    view_proj: mat4x4<f32>,
+};
+
+struct PointLight {
+    // xyz: konum, w: menzil
+    position: vec4<f32>,
+    // rgb: renk, a: yoğunluk
+    color: vec4<f32>,
+};
+
+struct Lights {
+    count: u32,
+    lights: array<PointLight, 8>,
+};
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<uniform> lights: Lights;
+@group(1) @binding(0) var base_texture: texture_2d<f32>;
+@group(1) @binding(1) var base_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+};
+
+struct InstanceInput {
+    @location(3) model_0: vec4<f32>,
+    @location(4) model_1: vec4<f32>,
+    @location(5) model_2: vec4<f32>,
+    @location(6) model_3: vec4<f32>,
+    @location(7) color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) normal: vec3<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) world_position: vec3<f32>,
+    @location(3) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let model = mat4x4<f32>(instance.model_0, instance.model_1, instance.model_2, instance.model_3);
+    var out: VertexOutput;
+    let world_position = model * vec4<f32>(vertex.position, 1.0);
+    out.clip_position = camera.view_proj * world_position;
+    out.world_position = world_position.xyz;
+    out.normal = normalize((model * vec4<f32>(vertex.normal, 0.0)).xyz);
+    out.color = instance.color;
+    out.uv = vertex.uv;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let normal = normalize(in.normal);
+    // Sahnede ışık olmasa da nesneler görünsün diye zayıf bir yönlü dolgu ışığı
+    let fill_dir = normalize(vec3<f32>(0.4, 1.0, 0.6));
+    var lighting = vec3<f32>(0.15) + vec3<f32>(0.25) * max(dot(normal, fill_dir), 0.0);
+
+    for (var i = 0u; i < lights.count; i = i + 1u) {
+        let light = lights.lights[i];
+        let to_light = light.position.xyz - in.world_position;
+        let distance = length(to_light);
+        let falloff = clamp(1.0 - distance / light.position.w, 0.0, 1.0);
+        let diffuse = max(dot(normal, to_light / distance), 0.0);
+        lighting += light.color.rgb * light.color.a * diffuse * falloff * falloff;
+    }
+
+    let albedo = in.color * textureSample(base_texture, base_sampler, in.uv);
+    return vec4<f32>(albedo.rgb * lighting, albedo.a);
+}
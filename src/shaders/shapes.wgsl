@@ -0,0 +1,69 @@
+// SDF şekilleri: yuvarlak köşeli dikdörtgen (daire, yarıçapı yarım boyuta eşit
+// olanıdır) ve halka. Koordinatlar piksel olduğundan yarım piksellik geçiş
+// kenar yumuşatma için yeterlidir.
+struct Screen {
+    size: vec2<f32>,
+};
+
+@group(0) @binding(0) var<uniform> screen: Screen;
+
+struct ShapeInput {
+    @location(0) center: vec2<f32>,
+    @location(1) half_size: vec2<f32>,
+    // x: köşe yarıçapı, y: halka kalınlığı, z: kenarlık kalınlığı
+    @location(2) params: vec4<f32>,
+    @location(3) fill: vec4<f32>,
+    @location(4) border: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    // Şeklin merkezine göre piksel konumu
+    @location(0) local: vec2<f32>,
+    @location(1) @interpolate(flat) half_size: vec2<f32>,
+    @location(2) @interpolate(flat) params: vec4<f32>,
+    @location(3) @interpolate(flat) fill: vec4<f32>,
+    @location(4) @interpolate(flat) border: vec4<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32, shape: ShapeInput) -> VertexOutput {
+    let corner = vec2<f32>(f32(index & 1u), f32(index >> 1u)) * 2.0 - 1.0;
+    // Kenar yumuşatması kırpılmasın diye bir piksel taşar
+    let local = corner * (shape.half_size + 1.0);
+    let position = shape.center + local;
+
+    var out: VertexOutput;
+    // Piksel koordinatlarından NDC'ye (y aşağı doğru)
+    let ndc = position / screen.size * 2.0 - 1.0;
+    out.clip_position = vec4<f32>(ndc.x, -ndc.y, 0.0, 1.0);
+    out.local = local;
+    out.half_size = shape.half_size;
+    out.params = shape.params;
+    out.fill = shape.fill;
+    out.border = shape.border;
+    return out;
+}
+
+fn rounded_box(p: vec2<f32>, half_size: vec2<f32>, radius: f32) -> f32 {
+    let q = abs(p) - half_size + radius;
+    return length(max(q, vec2<f32>(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    var distance = rounded_box(in.local, in.half_size, in.params.x);
+    let thickness = in.params.y;
+    if (thickness > 0.0) {
+        // Dış kenardan içe doğru `thickness` genişliğinde bant
+        distance = abs(distance + thickness * 0.5) - thickness * 0.5;
+    }
+    let coverage = clamp(0.5 - distance, 0.0, 1.0);
+    if (coverage <= 0.0) {
+        discard;
+    }
+    let border_width = in.params.z;
+    let border = select(0.0, clamp(distance + border_width + 0.5, 0.0, 1.0), border_width > 0.0);
+    let color = mix(in.fill, in.border, border);
+    return vec4<f32>(color.rgb, color.a * coverage);
+}
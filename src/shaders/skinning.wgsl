@@ -0,0 +1,46 @@
+// Köşe başına en fazla dört eklem etkisi
+struct Influence {
+    joints: vec4<u32>,
+    weights: vec4<f32>,
+};
+
+// MeshVertex düzeni (konum, normal, uv) vec3 hizalaması olmadan düz f32 dizisi olarak okunur
+const VERTEX_FLOATS: u32 = 8u;
+
+@group(0) @binding(0) var<storage, read> bind_pose: array<f32>;
+@group(0) @binding(1) var<storage, read> influences: array<Influence>;
+@group(0) @binding(2) var<storage, read> joint_matrices: array<mat4x4<f32>>;
+@group(0) @binding(3) var<storage, read_write> skinned: array<f32>;
+
+fn read_vec3(base: u32) -> vec3<f32> {
+    return vec3<f32>(bind_pose[base], bind_pose[base + 1u], bind_pose[base + 2u]);
+}
+
+fn write_vec3(base: u32, value: vec3<f32>) {
+    skinned[base] = value.x;
+    skinned[base + 1u] = value.y;
+    skinned[base + 2u] = value.z;
+}
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if index >= arrayLength(&influences) {
+        return;
+    }
+    let influence = influences[index];
+    var skin = mat4x4<f32>(vec4<f32>(0.0), vec4<f32>(0.0), vec4<f32>(0.0), vec4<f32>(0.0));
+    for (var i = 0u; i < 4u; i++) {
+        skin += joint_matrices[influence.joints[i]] * influence.weights[i];
+    }
+
+    let base = index * VERTEX_FLOATS;
+    let position = skin * vec4<f32>(read_vec3(base), 1.0);
+    // Eklemlerde düzgün olmayan ölçek olmadığı varsayılır; üst 3x3 normal için yeterli
+    let normal = normalize((skin * vec4<f32>(read_vec3(base + 3u), 0.0)).xyz);
+
+    write_vec3(base, position.xyz);
+    write_vec3(base + 3u, normal);
+    skinned[base + 6u] = bind_pose[base + 6u];
+    skinned[base + 7u] = bind_pose[base + 7u];
+}
@@ -0,0 +1,43 @@
+struct Params {
+    // 0: RGB, 1-4: tek kanal (R, G, B, A) gri olarak
+    channel: u32,
+    exposure: f32,
+    // Derinliği doğrusal mesafeye çevirmek için kameranın yakın ve uzak düzlemi
+    near: f32,
+    far: f32,
+};
+
+@group(0) @binding(0) var color_texture: texture_2d<f32>;
+// Derinlik `texture_depth_2d` yerine float olarak bağlanır; GL arka ucu
+// derinlik dokusundan `textureLoad` desteklemiyor
+@group(0) @binding(1) var depth_texture: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> params: Params;
+
+// Köşe buffer'ı olmadan ekranı kaplayan tek üçgen
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+// Görüntü dokusu kaynakla aynı boyutta, bu yüzden piksel doğrudan okunur
+@fragment
+fn fs_color(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let value = textureLoad(color_texture, vec2<i32>(position.xy), 0);
+    if params.channel == 0u {
+        return vec4<f32>(clamp(value.rgb * params.exposure, vec3<f32>(0.0), vec3<f32>(1.0)), 1.0);
+    }
+    let channel = value[params.channel - 1u];
+    // Alfa pozlamadan etkilenmez
+    let scale = select(params.exposure, 1.0, params.channel == 4u);
+    return vec4<f32>(vec3<f32>(clamp(channel * scale, 0.0, 1.0)), 1.0);
+}
+
+// Yakın beyaz, uzak siyah; karekök yakındaki farkları açar
+@fragment
+fn fs_depth(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let depth = textureLoad(depth_texture, vec2<i32>(position.xy), 0).r;
+    let distance = params.near * params.far / (params.far - depth * (params.far - params.near));
+    let t = clamp((distance - params.near) / (params.far - params.near), 0.0, 1.0);
+    return vec4<f32>(vec3<f32>(1.0 - sqrt(t)), 1.0);
+}
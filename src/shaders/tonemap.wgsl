@@ -0,0 +1,61 @@
+struct Params {
+    exposure: f32,
+    // 0: sRGB yüzey, 1: doğrusal olmayan unorm yüzey, 2: scRGB (Rgba16Float)
+    mode: u32,
+    // scRGB'de 1.0 = 80 nit; SDR beyazının ve ekranın tepe parlaklığının nit değeri
+    paper_white: f32,
+    max_white: f32,
+};
+
+@group(0) @binding(0) var hdr_texture: texture_2d<f32>;
+@group(0) @binding(1) var<uniform> params: Params;
+
+// Köşe buffer'ı olmadan ekranı kaplayan tek üçgen
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+// Narkowicz'in ACES yaklaşımı
+fn aces(x: vec3<f32>) -> vec3<f32> {
+    let a = 2.51;
+    let b = 0.03;
+    let c = 2.43;
+    let d = 0.59;
+    let e = 0.14;
+    return clamp((x * (a * x + b)) / (x * (c * x + d) + e), vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+fn linear_to_srgb(x: vec3<f32>) -> vec3<f32> {
+    let low = x * 12.92;
+    let high = 1.055 * pow(x, vec3<f32>(1.0 / 2.4)) - 0.055;
+    return select(high, low, x <= vec3<f32>(0.0031308));
+}
+
+// SDR beyazının üstünü ekranın tepe parlaklığına yumuşakça sıkıştırır
+fn soft_clip(x: vec3<f32>, limit: f32) -> vec3<f32> {
+    let knee = min(1.0, limit);
+    let range = max(limit - knee, 1e-4);
+    let over = max(x - knee, vec3<f32>(0.0));
+    let compressed = knee + range * (1.0 - exp(-over / range));
+    return select(x, compressed, x > vec3<f32>(knee));
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let hdr = textureLoad(hdr_texture, vec2<i32>(position.xy), 0).rgb * params.exposure;
+    switch params.mode {
+        case 0u: {
+            return vec4<f32>(aces(hdr), 1.0);
+        }
+        case 1u: {
+            return vec4<f32>(linear_to_srgb(aces(hdr)), 1.0);
+        }
+        default: {
+            let white = params.paper_white / 80.0;
+            let limit = params.max_white / params.paper_white;
+            return vec4<f32>(soft_clip(max(hdr, vec3<f32>(0.0)), limit) * white, 1.0);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// Voksel parçaları; kamera ve ışıklar scene.wgsl ile aynı bind group'tan gelir
+struct Camera {
+    view_proj: mat4x4<f32>,
+};
+
+struct PointLight {
+    // xyz: konum, w: menzil
+    position: vec4<f32>,
+    // rgb: renk, a: yoğunluk
+    color: vec4<f32>,
+};
+
+struct Lights {
+    count: u32,
+    lights: array<PointLight, 8>,
+};
+
+@group(0) @binding(0) var<uniform> camera: Camera;
+@group(0) @binding(1) var<uniform> lights: Lights;
+@group(1) @binding(0) var block_textures: texture_2d_array<f32>;
+@group(1) @binding(1) var block_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) layer: u32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) normal: vec3<f32>,
+    @location(1) world_position: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+    @location(3) @interpolate(flat) layer: u32,
+};
+
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = camera.view_proj * vec4<f32>(vertex.position, 1.0);
+    out.world_position = vertex.position;
+    out.normal = vertex.normal;
+    out.uv = vertex.uv;
+    out.layer = vertex.layer;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let normal = normalize(in.normal);
+    // Güneş gibi güçlü bir yönlü ışık; yüzler arasındaki fark blokları okunur kılar
+    let sun_dir = normalize(vec3<f32>(0.4, 1.0, 0.6));
+    var lighting = vec3<f32>(0.25) + vec3<f32>(0.65) * max(dot(normal, sun_dir), 0.0);
+
+    for (var i = 0u; i < lights.count; i = i + 1u) {
+        let light = lights.lights[i];
+        let to_light = light.position.xyz - in.world_position;
+        let distance = length(to_light);
+        let falloff = clamp(1.0 - distance / light.position.w, 0.0, 1.0);
+        let diffuse = max(dot(normal, to_light / distance), 0.0);
+        lighting += light.color.rgb * light.color.a * diffuse * falloff * falloff;
+    }
+
+    let albedo = textureSample(block_textures, block_sampler, in.uv, in.layer);
+    return vec4<f32>(albedo.rgb * lighting, 1.0);
+}
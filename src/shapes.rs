@@ -0,0 +1,234 @@
+use crate::color::Color;
+use crate::frame_capture::TracedPass;
+
+// Daire, yuvarlak köşeli dikdörtgen veya halka. Her şekil tek bir dörtgendir;
+// kenar fragment'te işaretli uzaklık alanından (SDF) hesaplanır, üçgenlere
+// bölünmez ve her boyutta pürüzsüz kalır.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ShapeInstance {
+    center: [f32; 2],
+    half_size: [f32; 2],
+    corner_radius: f32,
+    // 0 ise dolu şekil, değilse bu kalınlıkta bir halka
+    thickness: f32,
+    border_width: f32,
+    _padding: f32,
+    fill: [f32; 4],
+    border: [f32; 4],
+}
+
+impl ShapeInstance {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        0 => Float32x2, 1 => Float32x2, 2 => Float32x4, 3 => Float32x4, 4 => Float32x4
+    ];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<ShapeInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ShapeStyle {
+    pub fill: Color,
+    pub border: Color,
+    // Piksel; kenarlık şeklin içine doğru çizilir
+    pub border_width: f32,
+}
+
+impl ShapeStyle {
+    pub fn fill(color: Color) -> Self {
+        Self {
+            fill: color,
+            border: color,
+            border_width: 0.0,
+        }
+    }
+
+    pub fn with_border(self, color: Color, width: f32) -> Self {
+        Self {
+            border: color,
+            border_width: width,
+            ..self
+        }
+    }
+}
+
+// Overlay ile aynı ekran uzayında (piksel, sol üst köşe orijin) SDF şekilleri.
+// Overlay pass'inde metinden önce çizilir, böylece panellerin üstüne yazı gelir.
+pub struct ShapeRenderer {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group: wgpu::BindGroup,
+    screen_buffer: wgpu::Buffer,
+    instance_buffer: wgpu::Buffer,
+    instance_capacity: usize,
+    instance_count: u32,
+    instances: Vec<ShapeInstance>,
+    format: wgpu::TextureFormat,
+}
+
+impl ShapeRenderer {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self, String> {
+        let screen_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Shape Screen"),
+            size: 16,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Shape Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Shape Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: screen_buffer.as_entire_binding(),
+            }],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shape Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+
+        let instance_capacity = 64;
+        let instance_buffer = Self::create_instance_buffer(device, instance_capacity);
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group,
+            screen_buffer,
+            instance_buffer,
+            instance_capacity,
+            instance_count: 0,
+            instances: Vec::new(),
+            format,
+        })
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Shape Instances"),
+            size: (capacity * std::mem::size_of::<ShapeInstance>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "shapes.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Shape Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[ShapeInstance::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleStrip,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    fn push(&mut self, center: [f32; 2], half_size: [f32; 2], corner_radius: f32, thickness: f32, style: ShapeStyle) {
+        let max_radius = half_size[0].min(half_size[1]).max(0.0);
+        self.instances.push(ShapeInstance {
+            center,
+            half_size,
+            corner_radius: corner_radius.clamp(0.0, max_radius),
+            thickness,
+            border_width: style.border_width,
+            _padding: 0.0,
+            fill: style.fill.for_target(self.format),
+            border: style.border.for_target(self.format),
+        });
+    }
+
+    pub fn circle(&mut self, x: f32, y: f32, radius: f32, style: ShapeStyle) {
+        self.push([x, y], [radius, radius], radius, 0.0, style);
+    }
+
+    // Overlay::rect gibi sol üst köşe ve boyutla
+    pub fn rounded_rect(&mut self, x: f32, y: f32, w: f32, h: f32, corner_radius: f32, style: ShapeStyle) {
+        self.push([x + w * 0.5, y + h * 0.5], [w * 0.5, h * 0.5], corner_radius, 0.0, style);
+    }
+
+    // `radius` dış yarıçaptır; halka içe doğru `thickness` kadar kalınlaşır
+    pub fn ring(&mut self, x: f32, y: f32, radius: f32, thickness: f32, style: ShapeStyle) {
+        self.push([x, y], [radius, radius], radius, thickness.max(0.5), style);
+    }
+
+    // Biriken şekilleri GPU'ya yükler ve bir sonraki kare için listeyi boşaltır
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        queue.write_buffer(
+            &self.screen_buffer,
+            0,
+            bytemuck::cast_slice(&[width as f32, height as f32, 0.0, 0.0]),
+        );
+        if self.instances.len() > self.instance_capacity {
+            self.instance_capacity = self.instances.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.instance_capacity);
+        }
+        if !self.instances.is_empty() {
+            queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(&self.instances));
+        }
+        self.instance_count = self.instances.len() as u32;
+        self.instances.clear();
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>) {
+        if self.instance_count == 0 {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline, "Shape Pipeline");
+        render_pass.set_bind_group(0, &self.bind_group, "Shape Bind Group");
+        render_pass.set_vertex_buffer(0, &self.instance_buffer, "Shape Instances");
+        render_pass.draw(0..4, 0..self.instance_count);
+    }
+}
@@ -0,0 +1,352 @@
+use crate::assets::{AssetManager, MeshHandle, MeshVertex};
+use crate::frame_capture::{Command, PassRecord};
+use crate::frame_context::{FRAMES_IN_FLIGHT, FrameContext, PerFrame};
+use glam::{Mat4, Vec3};
+use std::time::Instant;
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+// Köşe başına en fazla dört eklem ve ağırlıkları
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct Influence {
+    pub joints: [u32; 4],
+    pub weights: [f32; 4],
+}
+
+pub struct Joint {
+    pub parent: Option<usize>,
+    // Ebeveyne göre dinlenme dönüşümü
+    pub rest: Mat4,
+}
+
+// Eklem hiyerarşisi. Ebeveynler çocuklarından önce gelmelidir.
+pub struct Skeleton {
+    joints: Vec<Joint>,
+    inverse_bind: Vec<Mat4>,
+}
+
+impl Skeleton {
+    pub fn new(joints: Vec<Joint>) -> Self {
+        let rest: Vec<Mat4> = joints.iter().map(|joint| joint.rest).collect();
+        let mut skeleton = Self {
+            joints,
+            inverse_bind: Vec::new(),
+        };
+        skeleton.inverse_bind = skeleton.globals(&rest).iter().map(Mat4::inverse).collect();
+        skeleton
+    }
+
+    pub fn joint_count(&self) -> usize {
+        self.joints.len()
+    }
+
+    pub fn rest(&self, joint: usize) -> Mat4 {
+        self.joints[joint].rest
+    }
+
+    fn globals(&self, local: &[Mat4]) -> Vec<Mat4> {
+        let mut globals: Vec<Mat4> = Vec::with_capacity(self.joints.len());
+        for (joint, local) in self.joints.iter().zip(local) {
+            let global = match joint.parent {
+                Some(parent) => globals[parent] * *local,
+                None => *local,
+            };
+            globals.push(global);
+        }
+        globals
+    }
+
+    // Yerel pozdan shader'a gidecek matrisler: dinlenme pozundaki köşeyi
+    // pozdaki konumuna taşır
+    pub fn skin_matrices(&self, pose: &[Mat4]) -> Vec<Mat4> {
+        self.globals(pose)
+            .iter()
+            .zip(&self.inverse_bind)
+            .map(|(global, inverse_bind)| *global * *inverse_bind)
+            .collect()
+    }
+}
+
+// Dinlenme pozundaki köşeler, her köşenin eklem etkileri ve iskelet
+pub struct SkinnedMeshData {
+    pub vertices: Vec<MeshVertex>,
+    pub influences: Vec<Influence>,
+    pub indices: Vec<u32>,
+    pub skeleton: Skeleton,
+}
+
+// Her karede yazılan eklem matrisleri ve onları bağlayan bind group
+struct JointBuffer {
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+// Köşeleri compute pass'te iskelete göre deforme edilen mesh. Sonuç mesh'in
+// kendi vertex buffer'ına yazılır; sahne, gölge veya derinlik pass'leri
+// onu sıradan bir mesh gibi okur, skinning her karede bir kez yapılır.
+pub struct SkinnedMesh {
+    pub mesh: MeshHandle,
+    pub skeleton: Skeleton,
+    // Eklemlerin yerel dönüşümleri; `prepare` her karede GPU'ya yazar
+    pub pose: Vec<Mat4>,
+    vertex_count: u32,
+    joints: PerFrame<JointBuffer>,
+}
+
+pub struct Skinning {
+    pipeline: wgpu::ComputePipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+    meshes: Vec<SkinnedMesh>,
+    start: Instant,
+}
+
+impl Skinning {
+    pub fn new(device: &wgpu::Device) -> Result<Self, String> {
+        let storage = |binding: u32, read_only: bool| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Skinning Bind Group Layout"),
+            entries: &[storage(0, true), storage(1, true), storage(2, true), storage(3, false)],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Skinning Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout)?;
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group_layout,
+            meshes: Vec::new(),
+            start: Instant::now(),
+        })
+    }
+
+    fn create_pipeline(device: &wgpu::Device, layout: &wgpu::PipelineLayout) -> Result<wgpu::ComputePipeline, String> {
+        let shader = crate::shaders::create_module(device, "skinning.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some("Skinning Pipeline"),
+                layout: Some(layout),
+                module: &shader,
+                entry_point: Some("cs_main"),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout)?;
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.meshes.is_empty()
+    }
+
+    // Dinlenme pozundaki köşelerden storage mesh oluşturur ve skinning'e ekler
+    pub fn add(
+        &mut self,
+        device: &wgpu::Device,
+        assets: &mut AssetManager,
+        name: &str,
+        data: SkinnedMeshData,
+    ) -> MeshHandle {
+        let SkinnedMeshData {
+            vertices,
+            influences,
+            indices,
+            skeleton,
+        } = data;
+        let mesh = assets.add_storage_mesh(device, name, &vertices, &indices);
+        let output = &assets.mesh(mesh).expect("yeni eklenen mesh").vertex_buffer;
+
+        let bind_pose = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Skinning Bind Pose"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        let influence_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Skinning Influences"),
+            contents: bytemuck::cast_slice(&influences),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        let joints = PerFrame::new(FRAMES_IN_FLIGHT, |_| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Skinning Joints"),
+                size: (skeleton.joint_count() * std::mem::size_of::<Mat4>()) as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Skinning Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: bind_pose.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: influence_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: output.as_entire_binding(),
+                    },
+                ],
+            });
+            JointBuffer { buffer, bind_group }
+        });
+
+        let pose = (0..skeleton.joint_count()).map(|joint| skeleton.rest(joint)).collect();
+        self.meshes.push(SkinnedMesh {
+            mesh,
+            skeleton,
+            pose,
+            vertex_count: vertices.len() as u32,
+            joints,
+        });
+        mesh
+    }
+
+    // Örnek dokunaç; ilk çağrıda oluşturulur, sonrakiler aynı mesh'i döndürür
+    pub fn tentacle(&mut self, device: &wgpu::Device, assets: &mut AssetManager) -> MeshHandle {
+        if let Some(existing) = self.meshes.first() {
+            return existing.mesh;
+        }
+        self.add(device, assets, "dokunaç", tentacle_mesh(24, 12, 6))
+    }
+
+    // Örnek animasyon: her eklem bir öncekinden gecikmeli olarak salınır
+    fn animate(&mut self) {
+        let time = self.start.elapsed().as_secs_f32();
+        for skinned in &mut self.meshes {
+            for (joint, local) in skinned.pose.iter_mut().enumerate().skip(1) {
+                let angle = (time * 1.7 - joint as f32 * 0.8).sin() * 0.35;
+                *local = skinned.skeleton.rest(joint) * Mat4::from_rotation_z(angle);
+            }
+        }
+    }
+
+    pub fn prepare(&mut self, queue: &wgpu::Queue, frame: FrameContext) {
+        self.animate();
+        for skinned in &self.meshes {
+            let matrices = skinned.skeleton.skin_matrices(&skinned.pose);
+            queue.write_buffer(&skinned.joints.get(frame).buffer, 0, bytemuck::cast_slice(&matrices));
+        }
+    }
+
+    // Kaldırılmış mesh'ler atlanır. Deforme edilen köşe sayısını döndürür.
+    pub fn dispatch(
+        &self,
+        pass: &mut wgpu::ComputePass<'_>,
+        frame: FrameContext,
+        assets: &AssetManager,
+        mut record: Option<&mut PassRecord>,
+    ) -> u32 {
+        pass.set_pipeline(&self.pipeline);
+        if let Some(record) = record.as_mut() {
+            record.push(Command::SetPipeline("Skinning Pipeline".into()));
+        }
+        let mut vertices = 0;
+        for skinned in &self.meshes {
+            let Some(mesh) = assets.mesh(skinned.mesh) else { continue };
+            let workgroups = skinned.vertex_count.div_ceil(WORKGROUP_SIZE);
+            pass.set_bind_group(0, &skinned.joints.get(frame).bind_group, &[]);
+            pass.dispatch_workgroups(workgroups, 1, 1);
+            vertices += skinned.vertex_count;
+            if let Some(record) = record.as_mut() {
+                record.push(Command::SetBindGroup {
+                    index: 0,
+                    name: "Skinning Bind Group".into(),
+                });
+                record.push(Command::Note(format!(
+                    "dispatch_workgroups({workgroups}, 1, 1): \"{}\" {} köşe, {} eklem",
+                    mesh.name,
+                    skinned.vertex_count,
+                    skinned.skeleton.joint_count()
+                )));
+            }
+        }
+        vertices
+    }
+}
+
+// Y ekseni boyunca incelen, uçları açık bir tüp ve tabanından ucuna eklem
+// zinciri. Her köşe en yakın iki ekleme yüksekliğe göre bağlanır.
+fn tentacle_mesh(rings: u32, sides: u32, joint_count: usize) -> SkinnedMeshData {
+    let height = 2.0;
+    let segment = height / (joint_count - 1) as f32;
+    let joints = (0..joint_count)
+        .map(|joint| Joint {
+            parent: joint.checked_sub(1),
+            rest: if joint == 0 {
+                Mat4::IDENTITY
+            } else {
+                Mat4::from_translation(Vec3::new(0.0, segment, 0.0))
+            },
+        })
+        .collect();
+
+    let mut vertices = Vec::new();
+    let mut influences = Vec::new();
+    for ring in 0..=rings {
+        let v = ring as f32 / rings as f32;
+        let y = v * height;
+        let radius = 0.25 - 0.2 * v;
+        let t = v * (joint_count - 1) as f32;
+        let joint = (t.floor() as usize).min(joint_count - 2);
+        let blend = t - joint as f32;
+        for side in 0..=sides {
+            let u = side as f32 / sides as f32;
+            let angle = u * std::f32::consts::TAU;
+            let (sin, cos) = angle.sin_cos();
+            vertices.push(MeshVertex {
+                position: [cos * radius, y, sin * radius],
+                normal: [cos, 0.0, sin],
+                uv: [u, v],
+            });
+            influences.push(Influence {
+                joints: [joint as u32, joint as u32 + 1, 0, 0],
+                weights: [1.0 - blend, blend, 0.0, 0.0],
+            });
+        }
+    }
+
+    let mut indices = Vec::new();
+    let stride = sides + 1;
+    for ring in 0..rings {
+        for side in 0..sides {
+            let a = ring * stride + side;
+            let b = a + 1;
+            let c = a + stride;
+            let d = c + 1;
+            indices.extend_from_slice(&[a, c, b, b, c, d]);
+        }
+    }
+    SkinnedMeshData {
+        vertices,
+        influences,
+        indices,
+        skeleton: Skeleton::new(joints),
+    }
+}
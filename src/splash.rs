@@ -0,0 +1,181 @@
+use crate::color::{Color, Palette};
+use crate::settings::{Theme, ThemePreference};
+use ab_glyph::{Font, FontRef, ScaleFont};
+use softbuffer::{Context, SoftBufferError, Surface};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use winit::dpi::{LogicalSize, PhysicalPosition};
+use winit::event_loop::ActiveEventLoop;
+use winit::window::{Window, WindowAttributes, WindowId};
+
+// Açılışın aşamaları; ilerleme çubuğu aşama sırasına göre dolar
+#[derive(Copy, Clone, Debug)]
+pub enum Stage {
+    Adapter,
+    Device,
+    Assets,
+}
+
+impl Stage {
+    const COUNT: usize = 3;
+
+    fn label(self) -> &'static str {
+        match self {
+            Stage::Adapter => "GPU adaptörü aranıyor…",
+            Stage::Device => "GPU cihazı oluşturuluyor…",
+            Stage::Assets => "Varlıklar ve shader'lar yükleniyor…",
+        }
+    }
+}
+
+// GPU hazır olana kadar gösterilen çerçevesiz açılış penceresi. wgpu henüz
+// kullanılamadığı için softbuffer ile işlemcide çizilir; metin overlay ile
+// aynı gömülü fonttan ab_glyph ile rasterleştirilir.
+pub struct Splash {
+    window: Arc<Window>,
+    surface: Surface<Arc<Window>, Arc<Window>>,
+    font: FontRef<'static>,
+    palette: &'static Palette,
+    title: String,
+    stage: Stage,
+}
+
+impl Splash {
+    pub fn new(event_loop: &ActiveEventLoop, title: &str, theme: ThemePreference) -> Result<Self, String> {
+        let size = LogicalSize::new(420.0, 120.0);
+        let mut attributes = WindowAttributes::default()
+            .with_title(title)
+            .with_inner_size(size)
+            .with_decorations(false)
+            .with_resizable(false);
+        // Ekranın ortasına yerleştirilir; bazı pencere yöneticileri konumu yok sayar
+        if let Some(monitor) = event_loop
+            .primary_monitor()
+            .or_else(|| event_loop.available_monitors().next())
+        {
+            let inner = size.to_physical::<f64>(monitor.scale_factor());
+            let (origin, screen) = (monitor.position(), monitor.size());
+            attributes = attributes.with_position(PhysicalPosition::new(
+                origin.x as f64 + (screen.width as f64 - inner.width) / 2.0,
+                origin.y as f64 + (screen.height as f64 - inner.height) / 2.0,
+            ));
+        }
+
+        let window = Arc::new(event_loop.create_window(attributes).map_err(|e| e.to_string())?);
+        let context = Context::new(window.clone()).map_err(|e| e.to_string())?;
+        let surface = Surface::new(&context, window.clone()).map_err(|e| e.to_string())?;
+        let font = FontRef::try_from_slice(epaint_default_fonts::HACK_REGULAR).map_err(|e| e.to_string())?;
+        let palette = theme.resolve(Theme::from_system(window.theme())).palette();
+        Ok(Self {
+            window,
+            surface,
+            font,
+            palette,
+            title: title.to_string(),
+            stage: Stage::Adapter,
+        })
+    }
+
+    pub fn id(&self) -> WindowId {
+        self.window.id()
+    }
+
+    pub fn set_stage(&mut self, stage: Stage) {
+        self.stage = stage;
+        self.window.request_redraw();
+    }
+
+    pub fn draw(&mut self) {
+        if let Err(e) = self.paint() {
+            log::warn!("açılış penceresi çizilemedi: {e}");
+        }
+    }
+
+    fn paint(&mut self) -> Result<(), SoftBufferError> {
+        let size = self.window.inner_size();
+        let (Some(width), Some(height)) = (NonZeroU32::new(size.width), NonZeroU32::new(size.height)) else {
+            return Ok(());
+        };
+        self.surface.resize(width, height)?;
+        let mut buffer = self.surface.buffer_mut()?;
+        let mut canvas = Canvas {
+            pixels: &mut buffer,
+            width: size.width,
+            height: size.height,
+        };
+
+        let palette = self.palette;
+        let scale = self.window.scale_factor() as f32;
+        let [w, h] = [size.width as f32, size.height as f32];
+        let margin = 20.0 * scale;
+        canvas.fill([0.0, 0.0, w, h], palette.accent);
+        canvas.fill([1.0, 1.0, w - 2.0, h - 2.0], palette.panel.with_alpha(1.0));
+        canvas.text(&self.font, 22.0 * scale, [margin, margin], &self.title, palette.text);
+        canvas.text(
+            &self.font,
+            14.0 * scale,
+            [margin, 56.0 * scale],
+            self.stage.label(),
+            palette.text_dim,
+        );
+
+        let bar = [margin, h - margin - 6.0 * scale, w - 2.0 * margin, 6.0 * scale];
+        let progress = (self.stage as usize + 1) as f32 / (Stage::COUNT + 1) as f32;
+        canvas.fill(bar, palette.text_dim.with_alpha(0.25));
+        canvas.fill([bar[0], bar[1], bar[2] * progress, bar[3]], palette.accent);
+
+        buffer.present()
+    }
+}
+
+// softbuffer'ın 0x00RRGGBB piksellerine sRGB uzayında karıştırarak çizer
+struct Canvas<'a> {
+    pixels: &'a mut [u32],
+    width: u32,
+    height: u32,
+}
+
+impl Canvas<'_> {
+    fn blend(&mut self, x: i32, y: i32, color: Color, coverage: f32) {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return;
+        }
+        let [r, g, b, a] = color.to_srgb();
+        let alpha = a * coverage;
+        let pixel = &mut self.pixels[(y as u32 * self.width + x as u32) as usize];
+        let mix = |shift: u32, source: f32| {
+            let target = ((*pixel >> shift) & 0xff) as f32 / 255.0;
+            ((source * alpha + target * (1.0 - alpha)) * 255.0).round() as u32
+        };
+        *pixel = (mix(16, r) << 16) | (mix(8, g) << 8) | mix(0, b);
+    }
+
+    fn fill(&mut self, [x, y, w, h]: [f32; 4], color: Color) {
+        for py in y.round() as i32..(y + h).round() as i32 {
+            for px in x.round() as i32..(x + w).round() as i32 {
+                self.blend(px, py, color, 1.0);
+            }
+        }
+    }
+
+    fn text(&mut self, font: &FontRef, font_px: f32, [x, y]: [f32; 2], text: &str, color: Color) {
+        let scaled = font.as_scaled(font_px);
+        let mut caret = x;
+        for ch in text.chars() {
+            let id = font.glyph_id(ch);
+            let glyph = id.with_scale_and_position(font_px, ab_glyph::point(caret, y + scaled.ascent()));
+            caret += scaled.h_advance(id);
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    self.blend(
+                        bounds.min.x as i32 + gx as i32,
+                        bounds.min.y as i32 + gy as i32,
+                        color,
+                        coverage,
+                    );
+                });
+            }
+        }
+    }
+}
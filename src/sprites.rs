@@ -0,0 +1,250 @@
+use crate::assets::{AssetManager, TextureHandle};
+use crate::color::Color;
+use crate::frame_capture::TracedPass;
+use std::ops::Range;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct SpriteVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    // Doğrusal renk; doku örneğiyle çarpılır
+    color: [f32; 4],
+}
+
+impl SpriteVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 3] =
+        wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Float32x4];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<SpriteVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Dokudan dokuz parçalı çizim: köşeler ölçeklenmeden kopyalanır, kenarlar tek
+// eksende, orta bölge iki eksende gerilir. Kenar payları doku pikseli cinsindendir.
+#[derive(Copy, Clone, Debug)]
+pub struct NineSlice {
+    pub texture: TextureHandle,
+    // Sol, üst, sağ, alt
+    pub insets: [f32; 4],
+    // Ekranda köşelerin doku pikseline göre büyütmesi (ör. ölçek faktörü)
+    pub scale: f32,
+}
+
+impl NineSlice {
+    pub fn new(texture: TextureHandle, inset: f32) -> Self {
+        Self {
+            texture,
+            insets: [inset; 4],
+            scale: 1.0,
+        }
+    }
+}
+
+// Ekran uzayında (piksel, sol üst köşe orijin) dokulu dörtgenler. Aynı dokuyu
+// kullanan ardışık sprite'lar tek çizim çağrısında toplanır; çizim sırası korunur.
+pub struct SpriteBatch {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group: wgpu::BindGroup,
+    screen_buffer: wgpu::Buffer,
+    vertex_buffer: wgpu::Buffer,
+    vertex_capacity: usize,
+    vertices: Vec<SpriteVertex>,
+    // Biriken ve son yüklenen çizimler: doku ve köşe aralığı
+    batches: Vec<(TextureHandle, Range<u32>)>,
+    drawn: Vec<(TextureHandle, Range<u32>)>,
+    format: wgpu::TextureFormat,
+}
+
+impl SpriteBatch {
+    pub fn new(device: &wgpu::Device, assets: &AssetManager, format: wgpu::TextureFormat) -> Result<Self, String> {
+        let screen_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sprite Screen"),
+            size: 16,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Sprite Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Sprite Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: screen_buffer.as_entire_binding(),
+            }],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Sprite Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout, assets.texture_layout()],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+
+        let vertex_capacity = 1024;
+        let vertex_buffer = Self::create_vertex_buffer(device, vertex_capacity);
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group,
+            screen_buffer,
+            vertex_buffer,
+            vertex_capacity,
+            vertices: Vec::new(),
+            batches: Vec::new(),
+            drawn: Vec::new(),
+            format,
+        })
+    }
+
+    fn create_vertex_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Sprite Vertices"),
+            size: (capacity * std::mem::size_of::<SpriteVertex>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "sprites.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Sprite Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[SpriteVertex::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    // `uv`: [u0, v0, u1, v1]
+    pub fn sprite(&mut self, texture: TextureHandle, rect: [f32; 4], uv: [f32; 4], tint: Color) {
+        let [x, y, w, h] = rect;
+        if w <= 0.0 || h <= 0.0 {
+            return;
+        }
+        let color = tint.to_linear();
+        let corners = [
+            ([x, y], [uv[0], uv[1]]),
+            ([x + w, y], [uv[2], uv[1]]),
+            ([x + w, y + h], [uv[2], uv[3]]),
+            ([x, y + h], [uv[0], uv[3]]),
+        ];
+        let start = self.vertices.len() as u32;
+        for index in [0, 1, 2, 0, 2, 3] {
+            let (position, uv) = corners[index];
+            self.vertices.push(SpriteVertex { position, uv, color });
+        }
+        let end = self.vertices.len() as u32;
+        match self.batches.last_mut() {
+            Some((last, range)) if *last == texture => range.end = end,
+            _ => self.batches.push((texture, start..end)),
+        }
+    }
+
+    // Hedef dikdörtgen kenar paylarının toplamından küçükse paylar orantılı küçültülür
+    pub fn nine_slice(&mut self, assets: &AssetManager, slice: &NineSlice, rect: [f32; 4], tint: Color) {
+        let [width, height] = assets.texture_or_white(Some(slice.texture)).size.map(|size| size as f32);
+        let [x, y, w, h] = rect;
+        let [left, top, right, bottom] = slice.insets;
+        let fit_x = (w / ((left + right) * slice.scale)).min(1.0);
+        let fit_y = (h / ((top + bottom) * slice.scale)).min(1.0);
+
+        // Her eksende üç bant: ekran konumu ve doku koordinatı sınırları
+        let xs = [x, x + left * slice.scale * fit_x, x + w - right * slice.scale * fit_x, x + w];
+        let ys = [y, y + top * slice.scale * fit_y, y + h - bottom * slice.scale * fit_y, y + h];
+        let us = [0.0, left / width, 1.0 - right / width, 1.0];
+        let vs = [0.0, top / height, 1.0 - bottom / height, 1.0];
+        for row in 0..3 {
+            for column in 0..3 {
+                self.sprite(
+                    slice.texture,
+                    [xs[column], ys[row], xs[column + 1] - xs[column], ys[row + 1] - ys[row]],
+                    [us[column], vs[row], us[column + 1], vs[row + 1]],
+                    tint,
+                );
+            }
+        }
+    }
+
+    // Biriken köşeleri GPU'ya yükler ve bir sonraki kare için listeyi boşaltır
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, width: u32, height: u32) {
+        // Doku örnekleri doğrusaldır; sRGB olmayan 8 bit yüzeyde shader kodlar
+        let encode = !self.format.is_srgb() && self.format != wgpu::TextureFormat::Rgba16Float;
+        queue.write_buffer(
+            &self.screen_buffer,
+            0,
+            bytemuck::cast_slice(&[width as f32, height as f32, if encode { 1.0 } else { 0.0 }, 0.0]),
+        );
+        if self.vertices.len() > self.vertex_capacity {
+            self.vertex_capacity = self.vertices.len().next_power_of_two();
+            self.vertex_buffer = Self::create_vertex_buffer(device, self.vertex_capacity);
+        }
+        if !self.vertices.is_empty() {
+            queue.write_buffer(&self.vertex_buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+        self.vertices.clear();
+        self.drawn = std::mem::take(&mut self.batches);
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>, assets: &AssetManager) {
+        if self.drawn.is_empty() {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline, "Sprite Pipeline");
+        render_pass.set_bind_group(0, &self.bind_group, "Sprite Bind Group");
+        render_pass.set_vertex_buffer(0, &self.vertex_buffer, "Sprite Vertices");
+        for (texture, range) in &self.drawn {
+            let texture = assets.texture_or_white(Some(*texture));
+            render_pass.set_bind_group(1, &texture.bind_group, &texture.name);
+            render_pass.draw(range.clone(), 0..1);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::frame_context::FrameRing;
+use crate::overlay::Overlay;
+use crate::profiler::Profiler;
+use crate::shapes::{ShapeRenderer, ShapeStyle};
+use std::ops::AddAssign;
+
+// Bir pass'in (veya çizim fonksiyonunun) kare içindeki çizim sayıları
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DrawStats {
+    pub draw_calls: u32,
+    pub triangles: u64,
+    pub instances: u32,
+}
+
+impl DrawStats {
+    pub fn draw(&mut self, triangles_per_instance: u32, instances: u32) {
+        self.draw_calls += 1;
+        self.triangles += triangles_per_instance as u64 * instances as u64;
+        self.instances += instances;
+    }
+}
+
+impl AddAssign for DrawStats {
+    fn add_assign(&mut self, other: Self) {
+        self.draw_calls += other.draw_calls;
+        self.triangles += other.triangles;
+        self.instances += other.instances;
+    }
+}
+
+// Pass başına çizim istatistiklerini toplar ve son tamamlanan kareyi,
+// wgpu'nun canlı nesne sayaçları ve kare süreleriyle birlikte gösterir.
+#[derive(Default)]
+pub struct RenderStats {
+    pub visible: bool,
+    current: Vec<(&'static str, DrawStats)>,
+    last: Vec<(&'static str, DrawStats)>,
+}
+
+impl RenderStats {
+    pub fn begin_frame(&mut self) {
+        self.last = std::mem::take(&mut self.current);
+    }
+
+    pub fn record(&mut self, pass: &'static str, stats: DrawStats) {
+        match self.current.iter_mut().find(|(name, _)| *name == pass) {
+            Some((_, total)) => *total += stats,
+            None => self.current.push((pass, stats)),
+        }
+    }
+
+    // Son tamamlanan karenin pass'leri
+    pub fn last(&self) -> &[(&'static str, DrawStats)] {
+        &self.last
+    }
+
+    pub fn total(&self) -> DrawStats {
+        let mut total = DrawStats::default();
+        for (_, stats) in &self.last {
+            total += *stats;
+        }
+        total
+    }
+
+    pub fn draw(
+        &self,
+        overlay: &mut Overlay,
+        shapes: &mut ShapeRenderer,
+        width: f32,
+        device: &wgpu::Device,
+        profiler: &Profiler,
+        frame_ring: &FrameRing,
+    ) {
+        if !self.visible {
+            return;
+        }
+        let total = self.total();
+        let mut lines = vec![format!(
+            "çizim {:>5}  üçgen {:>8}  örnek {:>5}",
+            total.draw_calls, total.triangles, total.instances
+        )];
+        for (name, stats) in &self.last {
+            lines.push(format!(
+                "  {name:<10} {:>4} çizim {:>8} üçgen",
+                stats.draw_calls, stats.triangles
+            ));
+        }
+
+        let (count, sum, min, max) = profiler
+            .frame_times()
+            .fold((0, 0.0, f32::MAX, 0.0f32), |(count, sum, min, max), ms| {
+                (count + 1, sum + ms, min.min(ms), max.max(ms))
+            });
+        if count > 0 {
+            let average = sum / count as f32;
+            lines.push(format!(
+                "kare ort {average:.2} ms ({:.0} fps)  min {min:.2}  max {max:.2}",
+                1000.0 / average.max(0.001)
+            ));
+        }
+
+        let ring_line = lines.len();
+        lines.push(format!(
+            "uçuştaki kare {}/{}  GPU bekleme {}",
+            frame_ring.in_flight(),
+            frame_ring.capacity(),
+            frame_ring.stalls()
+        ));
+
+        // `counters` özelliği olmadan wgpu bu değerleri 0 döndürür
+        let hal = device.get_internal_counters().hal;
+        lines.push(format!(
+            "buffer {:>4} ({:.1} MB)  doku {:>4} ({:.1} MB)",
+            hal.buffers.read(),
+            hal.buffer_memory.read() as f64 / (1024.0 * 1024.0),
+            hal.textures.read(),
+            hal.texture_memory.read() as f64 / (1024.0 * 1024.0),
+        ));
+        lines.push(format!(
+            "görünüm {:>4}  bind group {:>4}  pipeline {:>3}  sampler {:>3}",
+            hal.texture_views.read(),
+            hal.bind_groups.read(),
+            hal.render_pipelines.read() + hal.compute_pipelines.read(),
+            hal.samplers.read(),
+        ));
+
+        let palette = overlay.palette;
+        let [char_w, line_height] = overlay.char_size();
+        let padding = 6.0;
+        let columns = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        // Kare halkası satırının sonunda yuva başına iki karakterlik yer
+        let columns = columns.max(lines[ring_line].chars().count() + 1 + frame_ring.capacity() * 2);
+        let panel_width = columns as f32 * char_w + padding * 2.0;
+        let panel_height = lines.len() as f32 * line_height + padding * 2.0;
+        let left = width - panel_width - padding;
+        let top = padding;
+
+        let style = ShapeStyle::fill(palette.panel).with_border(palette.accent.with_alpha(0.6), 1.0);
+        shapes.rounded_rect(left, top, panel_width, panel_height, padding, style);
+        for (index, line) in lines.iter().enumerate() {
+            let color = if line.starts_with("  ") {
+                palette.text_dim
+            } else {
+                palette.text
+            };
+            overlay.text(left + padding, top + padding + index as f32 * line_height, line, color);
+        }
+
+        // Kare halkasının yuvaları: GPU'da olanlar dolu, boşlar halka
+        let radius = line_height * 0.3;
+        let y = top + padding + (ring_line as f32 + 0.5) * line_height;
+        let x = left + panel_width - padding - radius;
+        for slot in 0..frame_ring.capacity() {
+            let x = x - slot as f32 * radius * 2.5;
+            if slot < frame_ring.in_flight() {
+                shapes.circle(x, y, radius, ShapeStyle::fill(palette.accent));
+            } else {
+                shapes.ring(x, y, radius, 1.5, ShapeStyle::fill(palette.text_dim));
+            }
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::debug_ui::DebugUi;
+use crate::frame_capture::{FrameCapture, TracedPass};
+use crate::renderer::DEPTH_FORMAT;
+use crate::stats::DrawStats;
+use crate::tonemap::HDR_FORMAT;
+
+const THUMBNAIL_WIDTH: f32 = 192.0;
+// egui yerel dokuları sRGB olarak örnekler; shader doğrusal yazar
+const DISPLAY_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+// Renk hedefinin hangi kanalının gösterileceği
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Channel {
+    Rgb,
+    R,
+    G,
+    B,
+    A,
+}
+
+impl Channel {
+    const ALL: [Channel; 5] = [Channel::Rgb, Channel::R, Channel::G, Channel::B, Channel::A];
+
+    fn label(self) -> &'static str {
+        match self {
+            Channel::Rgb => "RGB",
+            Channel::R => "R",
+            Channel::G => "G",
+            Channel::B => "B",
+            Channel::A => "A",
+        }
+    }
+}
+
+// Görüntülenebilen ara hedefler. Yeni bir hedef (ör. gölge haritası, bloom
+// mip'leri) bir varyant, `render`'a bir kaynak görünümü ve shader'a bir
+// giriş noktası olarak eklenir.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Target {
+    Color,
+    Depth,
+}
+
+impl Target {
+    const ALL: [Target; 2] = [Target::Color, Target::Depth];
+
+    fn label(self) -> &'static str {
+        match self {
+            Target::Color => "HDR renk",
+            Target::Depth => "Derinlik",
+        }
+    }
+
+    fn format(self) -> wgpu::TextureFormat {
+        match self {
+            Target::Color => HDR_FORMAT,
+            Target::Depth => DEPTH_FORMAT,
+        }
+    }
+
+    fn entry_point(self) -> &'static str {
+        match self {
+            Target::Color => "fs_color",
+            Target::Depth => "fs_depth",
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    channel: u32,
+    exposure: f32,
+    near: f32,
+    far: f32,
+}
+
+// Bir hedefin egui'de gösterilen 8 bit kopyası
+struct Display {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    params: wgpu::Buffer,
+    texture_id: Option<egui::TextureId>,
+}
+
+// Ara render hedeflerini (HDR renk, derinlik) küçük resim olarak gösteren
+// hata ayıklama penceresi. Her hedef kaynakla aynı boyuttaki bir görüntü
+// dokusuna görselleştirme pass'iyle çizilir: HDR renk pozlanıp 0..1'e
+// kırpılır, derinlik doğrusal mesafeye çevrilir. Tıklanan hedef ekranı kaplar.
+pub struct TargetViewer {
+    pipelines: Vec<wgpu::RenderPipeline>,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+    displays: Vec<Display>,
+    size: [u32; 2],
+    pub open: bool,
+    // Ekranı kaplayan hedef
+    inspect: Option<Target>,
+    channel: Channel,
+    exposure: f32,
+}
+
+impl TargetViewer {
+    pub fn new(device: &wgpu::Device) -> Result<Self, String> {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Target Viewer Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Target Viewer Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipelines = Self::create_pipelines(device, &pipeline_layout)?;
+
+        Ok(Self {
+            pipelines,
+            pipeline_layout,
+            bind_group_layout,
+            displays: Vec::new(),
+            size: [0, 0],
+            open: false,
+            inspect: None,
+            channel: Channel::Rgb,
+            exposure: 1.0,
+        })
+    }
+
+    fn create_pipelines(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+    ) -> Result<Vec<wgpu::RenderPipeline>, String> {
+        let shader = crate::shaders::create_module(device, "target_view.wgsl")?;
+        Target::ALL
+            .iter()
+            .map(|target| {
+                crate::shaders::validated(device, || {
+                    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                        label: Some("Target Viewer Pipeline"),
+                        layout: Some(layout),
+                        vertex: wgpu::VertexState {
+                            module: &shader,
+                            entry_point: Some("vs_main"),
+                            buffers: &[],
+                            compilation_options: Default::default(),
+                        },
+                        fragment: Some(wgpu::FragmentState {
+                            module: &shader,
+                            entry_point: Some(target.entry_point()),
+                            targets: &[Some(wgpu::ColorTargetState {
+                                format: DISPLAY_FORMAT,
+                                blend: None,
+                                write_mask: wgpu::ColorWrites::ALL,
+                            })],
+                            compilation_options: Default::default(),
+                        }),
+                        primitive: wgpu::PrimitiveState::default(),
+                        depth_stencil: None,
+                        multisample: wgpu::MultisampleState::default(),
+                        multiview: None,
+                        cache: None,
+                    })
+                })
+            })
+            .collect()
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipelines = Self::create_pipelines(device, &self.pipeline_layout)?;
+        Ok(())
+    }
+
+    fn create_display(device: &wgpu::Device, [width, height]: [u32; 2]) -> Display {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Target Viewer Display"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DISPLAY_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let params = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Target Viewer Params"),
+            size: std::mem::size_of::<ParamsRaw>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Display {
+            texture,
+            view,
+            params,
+            texture_id: None,
+        }
+    }
+
+    // Görüntü dokularını hedef boyutunda tutar ve egui'ye tanıtır. Doku yeniden
+    // oluşturulduğunda aynı egui kimliği yeni görünüme yönlendirilir.
+    pub fn prepare(&mut self, device: &wgpu::Device, queue: &wgpu::Queue, size: [u32; 2], debug_ui: &mut DebugUi) {
+        if self.size != size || self.displays.is_empty() {
+            self.size = size;
+            let previous = std::mem::take(&mut self.displays);
+            self.displays = (0..Target::ALL.len())
+                .map(|index| {
+                    let mut display = Self::create_display(device, size);
+                    let texture_id = previous.get(index).and_then(|display| display.texture_id);
+                    display.texture_id = Some(debug_ui.register_texture(device, &display.view, texture_id));
+                    display
+                })
+                .collect();
+        }
+
+        for (target, display) in Target::ALL.iter().zip(&self.displays) {
+            let channel = match target {
+                Target::Color => Channel::ALL
+                    .iter()
+                    .position(|channel| *channel == self.channel)
+                    .unwrap_or(0),
+                Target::Depth => 0,
+            };
+            let params = ParamsRaw {
+                channel: channel as u32,
+                exposure: self.exposure,
+                near: crate::scene::NEAR,
+                far: crate::scene::FAR,
+            };
+            queue.write_buffer(&display.params, 0, bytemuck::bytes_of(&params));
+        }
+    }
+
+    pub fn render(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        color: &wgpu::TextureView,
+        depth: &wgpu::TextureView,
+        mut frame_capture: Option<&mut FrameCapture>,
+    ) -> DrawStats {
+        let mut stats = DrawStats::default();
+        for ((target, display), pipeline) in Target::ALL.iter().zip(&self.displays).zip(&self.pipelines) {
+            // Kaynak görünümleri boyut değişince yeniden oluşturulduğu için bind group her karede kurulur
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Target Viewer Bind Group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(color),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(depth),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: display.params.as_entire_binding(),
+                    },
+                ],
+            });
+            let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Target Viewer Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &display.view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            let record = frame_capture.as_deref_mut().map(|capture| {
+                let [width, height] = [display.texture.width(), display.texture.height()];
+                capture.pass(
+                    "Target Viewer Pass",
+                    vec![format!(
+                        "renk: {} görüntüsü {width}x{height} {DISPLAY_FORMAT:?}, clear",
+                        target.label()
+                    )],
+                )
+            });
+            let mut traced = TracedPass::new(&mut pass, record);
+            traced.set_pipeline(pipeline, "Target Viewer Pipeline");
+            traced.set_bind_group(0, &bind_group, "Target Viewer Bind Group");
+            traced.draw(0..3, 0..1);
+            stats += traced.finish();
+        }
+        stats
+    }
+
+    pub fn show(&mut self, context: &egui::Context) {
+        let Self {
+            displays,
+            size,
+            open,
+            inspect,
+            channel,
+            exposure,
+            ..
+        } = self;
+
+        let inspected = inspect.and_then(|target| {
+            let index = Target::ALL.iter().position(|other| *other == target)?;
+            displays.get(index)?.texture_id
+        });
+        if let Some(texture_id) = inspected {
+            egui::Area::new(egui::Id::new("target_viewer_inspect"))
+                .order(egui::Order::Background)
+                .fixed_pos(egui::Pos2::ZERO)
+                .show(context, |ui| {
+                    let image = egui::load::SizedTexture::new(texture_id, context.screen_rect().size());
+                    let response = ui.add(egui::Image::new(image).sense(egui::Sense::click()));
+                    if response.on_hover_text("Kapatmak için tıkla").clicked() {
+                        *inspect = None;
+                    }
+                });
+        }
+
+        egui::Window::new("Render Hedefleri")
+            .open(open)
+            .default_width(THUMBNAIL_WIDTH + 24.0)
+            .show(context, |ui| {
+                let [width, height] = *size;
+                let thumbnail = [THUMBNAIL_WIDTH, THUMBNAIL_WIDTH * height as f32 / width.max(1) as f32];
+                for (target, display) in Target::ALL.iter().zip(displays.iter()) {
+                    ui.label(format!("{} - {width}x{height} {:?}", target.label(), target.format()));
+                    if let Some(texture_id) = display.texture_id {
+                        let image = egui::load::SizedTexture::new(texture_id, thumbnail);
+                        let response = ui.add(egui::Image::new(image).sense(egui::Sense::click()));
+                        if response.on_hover_text("Tam ekran incele").clicked() {
+                            *inspect = Some(*target);
+                        }
+                    }
+                    if *target == Target::Color {
+                        ui.horizontal(|ui| {
+                            for option in Channel::ALL {
+                                ui.selectable_value(channel, option, option.label());
+                            }
+                        });
+                        ui.add(
+                            egui::Slider::new(exposure, 0.05..=16.0)
+                                .logarithmic(true)
+                                .text("pozlama"),
+                        );
+                    }
+                    ui.separator();
+                }
+            });
+        if !*open {
+            *inspect = None;
+        }
+    }
+}
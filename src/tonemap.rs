@@ -0,0 +1,247 @@
+use crate::frame_capture::TracedPass;
+
+// Sahne bu formatta çizilir; 1.0'ın üstündeki değerler ton eşlemeye kadar korunur
+pub const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+// Ton eşleme çıktısının yüzeye nasıl kodlanacağı
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    // Donanım sRGB kodlamasını yapar, shader doğrusal yazar
+    Srgb,
+    // sRGB olmayan 8 bit yüzey; kodlama shader'da yapılır
+    Unorm,
+    // Genişletilmiş doğrusal sRGB (scRGB): 1.0 = 80 nit, değerler 1.0'ı aşabilir
+    ScRgb,
+}
+
+impl Output {
+    pub fn for_surface(format: wgpu::TextureFormat) -> Self {
+        match format {
+            wgpu::TextureFormat::Rgba16Float => Output::ScRgb,
+            format if format.is_srgb() => Output::Srgb,
+            _ => Output::Unorm,
+        }
+    }
+
+    pub fn is_hdr(self) -> bool {
+        self == Output::ScRgb
+    }
+
+    fn mode(self) -> u32 {
+        match self {
+            Output::Srgb => 0,
+            Output::Unorm => 1,
+            Output::ScRgb => 2,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct ParamsRaw {
+    exposure: f32,
+    mode: u32,
+    paper_white: f32,
+    max_white: f32,
+}
+
+// Sahnenin çizildiği HDR hedefini yüzey formatına çeviren tam ekran pass.
+// SDR yüzeylerde ACES ile 0..1'e sıkıştırır; scRGB yüzeyde SDR beyazını
+// `paper_white` nite ölçekler ve tepe değeri `max_white` ile sınırlar.
+pub struct Tonemapper {
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    bind_group_layout: wgpu::BindGroupLayout,
+    bind_group: wgpu::BindGroup,
+    params_buffer: wgpu::Buffer,
+    target: wgpu::Texture,
+    target_view: wgpu::TextureView,
+    surface_format: wgpu::TextureFormat,
+    pub output: Output,
+    pub exposure: f32,
+    pub paper_white: f32,
+    pub max_white: f32,
+}
+
+impl Tonemapper {
+    pub fn new(
+        device: &wgpu::Device,
+        surface_format: wgpu::TextureFormat,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, String> {
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Tonemap Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Tonemap Params"),
+            size: std::mem::size_of::<ParamsRaw>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Tonemap Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, surface_format)?;
+
+        let (target, target_view) = Self::create_target(device, width, height);
+        let bind_group = Self::create_bind_group(device, &bind_group_layout, &target_view, &params_buffer);
+
+        Ok(Self {
+            pipeline,
+            pipeline_layout,
+            bind_group_layout,
+            bind_group,
+            params_buffer,
+            target,
+            target_view,
+            surface_format,
+            output: Output::for_surface(surface_format),
+            exposure: 1.0,
+            paper_white: 200.0,
+            max_white: 1000.0,
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "tonemap.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Tonemap Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    fn create_target(device: &wgpu::Device, width: u32, height: u32) -> (wgpu::Texture, wgpu::TextureView) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("HDR Target"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        (texture, view)
+    }
+
+    fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        view: &wgpu::TextureView,
+        params: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Tonemap Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: params.as_entire_binding(),
+                },
+            ],
+        })
+    }
+
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        (self.target, self.target_view) = Self::create_target(device, width, height);
+        self.bind_group =
+            Self::create_bind_group(device, &self.bind_group_layout, &self.target_view, &self.params_buffer);
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.surface_format)?;
+        Ok(())
+    }
+
+    pub fn target_view(&self) -> &wgpu::TextureView {
+        &self.target_view
+    }
+
+    pub fn target_size(&self) -> [u32; 2] {
+        [self.target.width(), self.target.height()]
+    }
+
+    // Float yüzeyde SDR beyazının piksel değeri; SDR yüzeylerde 1.0
+    pub fn white_level(&self) -> f32 {
+        if self.output.is_hdr() { self.paper_white / 80.0 } else { 1.0 }
+    }
+
+    pub fn prepare(&self, queue: &wgpu::Queue) {
+        let params = ParamsRaw {
+            exposure: self.exposure,
+            mode: self.output.mode(),
+            paper_white: self.paper_white,
+            max_white: self.max_white.max(self.paper_white),
+        };
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::bytes_of(&params));
+    }
+
+    pub fn render(&self, pass: &mut TracedPass<'_, '_>) {
+        pass.set_pipeline(&self.pipeline, "Tonemap Pipeline");
+        pass.set_bind_group(0, &self.bind_group, "Tonemap Bind Group");
+        pass.draw(0..3, 0..1);
+    }
+}
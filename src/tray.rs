@@ -0,0 +1,82 @@
+use crate::actions::Action;
+use crate::assets::panel_pixels;
+use muda::{CheckMenuItem, Menu, MenuId, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+const SIZE: u32 = 32;
+const TITLE: &str = "winit & wgpu";
+
+// Bildirim alanı (Windows) veya menü çubuğu (macOS) simgesi. Sağ tık menüsü
+// pencereyi gizler/gösterir, duraklatır ve çıkar; sol tık pencereyi gizler/gösterir.
+// Tıklamalar `AppEvent::Menu` ve `AppEvent::Tray` olarak olay döngüsüne gelir.
+pub struct Tray {
+    icon: TrayIcon,
+    items: Vec<(MenuId, Action)>,
+    window: MenuItem,
+    pause: CheckMenuItem,
+    // Menüye en son yansıtılan (görünür, duraklatıldı) durumu
+    shown: Option<(bool, bool)>,
+}
+
+impl Tray {
+    pub fn new() -> Result<Self, String> {
+        let error = |e: muda::Error| format!("bildirim alanı menüsü oluşturulamadı: {e}");
+        let window = MenuItem::new("Pencereyi gizle", true, None);
+        let pause = CheckMenuItem::new("Duraklat", true, false, None);
+        let quit = MenuItem::new("Çıkış", true, None);
+        let menu = Menu::with_items(&[&window, &pause, &PredefinedMenuItem::separator(), &quit]).map_err(error)?;
+        let items = vec![
+            (window.id().clone(), Action::ToggleWindow),
+            (pause.id().clone(), Action::TogglePause),
+            (quit.id().clone(), Action::Quit),
+        ];
+
+        // Simge, koyu temanın dokuz parçalı paneliyle aynı çizimden üretilir
+        let pixels = panel_pixels(SIZE, [40.0, 100.0, 215.0, 255.0], [230.0, 235.0, 245.0, 255.0]);
+        let icon = Icon::from_rgba(pixels, SIZE, SIZE).map_err(|e| e.to_string())?;
+        let icon = TrayIconBuilder::new()
+            .with_icon(icon)
+            .with_tooltip(TITLE)
+            .with_menu(Box::new(menu))
+            .with_menu_on_left_click(false)
+            .build()
+            .map_err(|e| format!("bildirim alanı simgesi oluşturulamadı: {e}"))?;
+
+        Ok(Self {
+            icon,
+            items,
+            window,
+            pause,
+            shown: None,
+        })
+    }
+
+    pub fn action(&self, id: &MenuId) -> Option<Action> {
+        self.items
+            .iter()
+            .find(|(item, _)| item == id)
+            .map(|(_, action)| *action)
+    }
+
+    // Eylemler kısayollardan da gelebildiği için menü metinleri her karede karşılaştırılır
+    pub fn sync(&mut self, visible: bool, paused: bool) {
+        if self.shown == Some((visible, paused)) {
+            return;
+        }
+        self.shown = Some((visible, paused));
+        self.window.set_text(if visible {
+            "Pencereyi gizle"
+        } else {
+            "Pencereyi göster"
+        });
+        self.pause.set_checked(paused);
+        let tooltip = if paused {
+            format!("{TITLE} (duraklatıldı)")
+        } else {
+            TITLE.to_string()
+        };
+        if let Err(e) = self.icon.set_tooltip(Some(tooltip)) {
+            log::warn!("bildirim alanı ipucu güncellenemedi: {e}");
+        }
+    }
+}
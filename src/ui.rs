@@ -0,0 +1,615 @@
+use crate::assets::{AssetManager, PANEL_INSET, TextureHandle};
+use crate::color::{Color, Palette};
+use crate::overlay::Overlay;
+use crate::sprites::{NineSlice, SpriteBatch};
+use std::str::FromStr;
+
+// egui katmanından daha hafif, oyun içi HUD ve menüler için kalıcı (retained)
+// düzen. Ağaç bir kez kurulur, sonra sadece metinler güncellenir; yerleşim
+// ağaç veya ekran boyutu değiştiğinde yeniden hesaplanır. Çizim sprite batch
+// (arka planlar) ve overlay (metin) üzerinden yapılır. Koordinatlar overlay ile
+// aynıdır: fiziksel piksel, sol üst köşe orijin.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+// Kök düğümün ekrandaki yeri
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    // Her eksende 0 (sol/üst), 0.5 (orta) veya 1 (sağ/alt)
+    fn factor(self) -> [f32; 2] {
+        match self {
+            Anchor::TopLeft => [0.0, 0.0],
+            Anchor::Top => [0.5, 0.0],
+            Anchor::TopRight => [1.0, 0.0],
+            Anchor::Left => [0.0, 0.5],
+            Anchor::Center => [0.5, 0.5],
+            Anchor::Right => [1.0, 0.5],
+            Anchor::BottomLeft => [0.0, 1.0],
+            Anchor::Bottom => [0.5, 1.0],
+            Anchor::BottomRight => [1.0, 1.0],
+        }
+    }
+}
+
+impl FromStr for Anchor {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "top-left" => Ok(Anchor::TopLeft),
+            "top" => Ok(Anchor::Top),
+            "top-right" => Ok(Anchor::TopRight),
+            "left" => Ok(Anchor::Left),
+            "center" => Ok(Anchor::Center),
+            "right" => Ok(Anchor::Right),
+            "bottom-left" => Ok(Anchor::BottomLeft),
+            "bottom" => Ok(Anchor::Bottom),
+            "bottom-right" => Ok(Anchor::BottomRight),
+            _ => Err(format!(
+                "bilinmeyen konum: {text} (top-left | top | top-right | left | center | right | bottom-left | bottom | bottom-right)"
+            )),
+        }
+    }
+}
+
+// Çocukların dizildiği ana eksen
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Row,
+    Column,
+}
+
+impl Direction {
+    fn main(self) -> usize {
+        match self {
+            Direction::Row => 0,
+            Direction::Column => 1,
+        }
+    }
+}
+
+// Çocukların çapraz eksendeki hizası
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    // Çocuk, ebeveynin iç genişliğini (veya yüksekliğini) doldurur
+    Stretch,
+}
+
+// Yatay kaydırıcı. Değer değişince düğümün komutu değer eklenerek çalıştırılır
+// (ör. "tonemap exposure" → "tonemap exposure 1.5").
+#[derive(Copy, Clone, Debug)]
+pub struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+impl Slider {
+    // Değeri aralığa sıkıştırır ve en yakın adıma yuvarlar
+    fn snap(&self, value: f32) -> f32 {
+        let steps = ((value - self.min) / self.step).round();
+        (self.min + steps * self.step).clamp(self.min, self.max)
+    }
+
+    fn fraction(&self) -> f32 {
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+// Metin rengi çizimde geçerli temanın paletinden seçilir; tema değişince
+// ağacı yeniden kurmak gerekmez
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Tone {
+    Text,
+    Dim,
+    Accent,
+}
+
+impl Tone {
+    fn color(self, palette: &Palette) -> Color {
+        match self {
+            Tone::Text => palette.text,
+            Tone::Dim => palette.text_dim,
+            Tone::Accent => palette.accent,
+        }
+    }
+}
+
+pub struct Node {
+    pub direction: Direction,
+    pub align: Align,
+    pub padding: f32,
+    pub spacing: f32,
+    pub min_size: [f32; 2],
+    pub background: Option<NineSlice>,
+    pub text: Option<(String, Tone)>,
+    // Tıklanınca çalıştırılacak konsol komutu; varsa düğüm buton gibi davranır
+    pub command: Option<String>,
+    pub slider: Option<Slider>,
+    children: Vec<NodeId>,
+    // Son ölçüm ve yerleşim: [x, y, genişlik, yükseklik]
+    size: [f32; 2],
+    rect: [f32; 4],
+}
+
+impl Node {
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    // Son yerleşimdeki [x, y, genişlik, yükseklik]
+    pub fn rect(&self) -> [f32; 4] {
+        self.rect
+    }
+
+    fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            align: Align::Start,
+            padding: 0.0,
+            spacing: 0.0,
+            min_size: [0.0; 2],
+            background: None,
+            text: None,
+            command: None,
+            slider: None,
+            children: Vec::new(),
+            size: [0.0; 2],
+            rect: [0.0; 4],
+        }
+    }
+
+    pub fn row() -> Self {
+        Self::new(Direction::Row)
+    }
+
+    pub fn column() -> Self {
+        Self::new(Direction::Column)
+    }
+
+    pub fn label(text: impl Into<String>, tone: Tone) -> Self {
+        Self {
+            text: Some((text.into(), tone)),
+            ..Self::row()
+        }
+    }
+
+    // Panel arka planlı, ortalanmış metinli buton
+    pub fn button(text: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            command: Some(command.into()),
+            ..Self::label(text, Tone::Text).with_padding(6.0).with_panel()
+        }
+    }
+
+    // Arka plan iz, vurgu renkli kutu değeri gösterir; `text` kaydırıcının adıdır
+    pub fn slider(text: impl Into<String>, command: impl Into<String>, slider: Slider) -> Self {
+        Self {
+            slider: Some(slider),
+            ..Self::button(text, command).with_min_size(220.0, 0.0)
+        }
+    }
+
+    pub fn with_padding(self, padding: f32) -> Self {
+        Self { padding, ..self }
+    }
+
+    pub fn with_spacing(self, spacing: f32) -> Self {
+        Self { spacing, ..self }
+    }
+
+    pub fn with_align(self, align: Align) -> Self {
+        Self { align, ..self }
+    }
+
+    pub fn with_min_size(self, width: f32, height: f32) -> Self {
+        Self {
+            min_size: [width, height],
+            ..self
+        }
+    }
+
+    // Yerleşik dokuz parçalı panel dokusu; çizimde temanın paneline dönüşür
+    pub fn with_panel(self) -> Self {
+        Self {
+            background: Some(NineSlice::new(TextureHandle::PANEL, PANEL_INSET)),
+            ..self
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Ui {
+    nodes: Vec<Node>,
+    roots: Vec<(NodeId, Anchor, [f32; 2])>,
+    hovered: Option<NodeId>,
+    // Ekran okuyucunun odakladığı düğüm
+    focused: Option<NodeId>,
+    // Yapı, odak, kaydırıcı değeri veya ekran boyutu değiştiğinde artar;
+    // erişilebilirlik ağacı buna göre yenilenir. Her karede değişen metinler
+    // (ör. kare süresi) ağacı ekran okuyucuya yeniden göndermesin diye saymaz.
+    revision: u64,
+    // Yerleşimin hesaplandığı ekran boyutu; ağaç değişince sıfırlanır
+    laid_out: Option<[f32; 2]>,
+    // Son yerleşimin ekran boyutu; `laid_out` metin yüzünden sıfırlansa da kalır
+    screen: [f32; 2],
+}
+
+impl Ui {
+    // `parent` yoksa düğüm köktür ve `anchor` ile yerleştirilmelidir
+    pub fn add(&mut self, parent: Option<NodeId>, node: Node) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(node);
+        if let Some(parent) = parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        self.laid_out = None;
+        self.revision += 1;
+        id
+    }
+
+    // `margin`: kök düğümün bağlandığı ekran kenarlarından içe doğru uzaklık
+    pub fn anchor(&mut self, root: NodeId, anchor: Anchor, margin: [f32; 2]) {
+        self.roots.push((root, anchor, margin));
+        self.laid_out = None;
+        self.revision += 1;
+    }
+
+    pub fn set_anchor(&mut self, root: NodeId, anchor: Anchor) {
+        if let Some(entry) = self.roots.iter_mut().find(|(id, _, _)| *id == root) {
+            entry.1 = anchor;
+            self.laid_out = None;
+            self.revision += 1;
+        }
+    }
+
+    // Genişlik (karakter sayısı) değişmediyse yerleşim korunur
+    pub fn set_text(&mut self, id: NodeId, text: &str) {
+        if let Some((current, _)) = self.nodes[id.0].text.as_mut()
+            && current != text
+        {
+            if current.chars().count() != text.chars().count() {
+                self.laid_out = None;
+            }
+            current.clear();
+            current.push_str(text);
+        }
+    }
+
+    // Önce yapraklardan köke boyutlar ölçülür, sonra kökten yapraklara konumlar verilir
+    pub fn layout(&mut self, char_size: [f32; 2], screen: [f32; 2]) {
+        if self.laid_out == Some(screen) {
+            return;
+        }
+        for index in 0..self.roots.len() {
+            let (root, anchor, margin) = self.roots[index];
+            let size = self.measure(root, char_size);
+            let factor = anchor.factor();
+            let position: [f32; 2] = std::array::from_fn(|axis| {
+                factor[axis] * (screen[axis] - size[axis]) + margin[axis] * (1.0 - 2.0 * factor[axis])
+            });
+            self.arrange(root, [position[0], position[1], size[0], size[1]]);
+        }
+        self.laid_out = Some(screen);
+        if self.screen != screen {
+            self.screen = screen;
+            self.revision += 1;
+        }
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn roots(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.roots.iter().map(|(root, _, _)| *root)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    // Dışarıdan gelen (ör. erişilebilirlik) bir indeksi doğrular
+    pub fn id(&self, index: usize) -> Option<NodeId> {
+        (index < self.nodes.len()).then_some(NodeId(index))
+    }
+
+    pub fn focused(&self) -> Option<NodeId> {
+        self.focused
+    }
+
+    pub fn focus(&mut self, id: Option<NodeId>) {
+        if self.focused != id {
+            self.focused = id;
+            self.revision += 1;
+        }
+    }
+
+    // Kaydırıcı değerini değiştirir ve çalıştırılacak komut satırını döndürür
+    pub fn set_value(&mut self, id: NodeId, value: f32) -> Option<String> {
+        let node = &mut self.nodes[id.0];
+        let slider = node.slider.as_mut()?;
+        let value = slider.snap(value);
+        if slider.value != value {
+            slider.value = value;
+            self.laid_out = None;
+            self.revision += 1;
+        }
+        Some(format!("{} {value}", node.command.as_deref()?))
+    }
+
+    // Buton veya kaydırıcıya tıklama; `point` verilirse kaydırıcı değeri oradan alınır
+    pub fn activate(&mut self, id: NodeId, point: Option<[f32; 2]>) -> Option<String> {
+        let node = &self.nodes[id.0];
+        match (node.slider, point) {
+            (Some(slider), Some(point)) => {
+                let [x, _, w, _] = node.rect;
+                let fraction = ((point[0] - x) / w).clamp(0.0, 1.0);
+                self.set_value(id, slider.min + fraction * (slider.max - slider.min))
+            }
+            (Some(slider), None) => self.set_value(id, slider.value),
+            (None, _) => node.command.clone(),
+        }
+    }
+
+    fn measure(&mut self, id: NodeId, char_size: [f32; 2]) -> [f32; 2] {
+        let children = self.nodes[id.0].children.clone();
+        let node = &self.nodes[id.0];
+        let (main, spacing) = (node.direction.main(), node.spacing);
+        let mut inner = match &node.text {
+            Some((text, _)) => [text.chars().count() as f32 * char_size[0], char_size[1]],
+            None => [0.0; 2],
+        };
+
+        let mut along = 0.0;
+        let mut across: f32 = 0.0;
+        for child in &children {
+            let size = self.measure(*child, char_size);
+            along += size[main];
+            across = across.max(size[1 - main]);
+        }
+        along += spacing * children.len().saturating_sub(1) as f32;
+        inner[main] = inner[main].max(along);
+        inner[1 - main] = inner[1 - main].max(across);
+
+        let node = &mut self.nodes[id.0];
+        node.size = std::array::from_fn(|axis| (inner[axis] + node.padding * 2.0).max(node.min_size[axis]));
+        node.size
+    }
+
+    fn arrange(&mut self, id: NodeId, rect: [f32; 4]) {
+        let node = &mut self.nodes[id.0];
+        node.rect = rect;
+        let (main, align, spacing, padding) = (node.direction.main(), node.align, node.spacing, node.padding);
+        let inner_origin = [rect[0] + padding, rect[1] + padding];
+        let inner_size = [rect[2] - padding * 2.0, rect[3] - padding * 2.0];
+
+        let mut cursor = inner_origin[main];
+        for child in node.children.clone() {
+            let measured = self.nodes[child.0].size;
+            let cross_size = if align == Align::Stretch {
+                inner_size[1 - main]
+            } else {
+                measured[1 - main]
+            };
+            let cross_offset = match align {
+                Align::Start | Align::Stretch => 0.0,
+                Align::Center => (inner_size[1 - main] - cross_size) * 0.5,
+                Align::End => inner_size[1 - main] - cross_size,
+            };
+            let mut child_rect = [0.0; 4];
+            child_rect[main] = cursor;
+            child_rect[1 - main] = inner_origin[1 - main] + cross_offset;
+            child_rect[2 + main] = measured[main];
+            child_rect[3 - main] = cross_size;
+            self.arrange(child, child_rect);
+            cursor += measured[main] + spacing;
+        }
+    }
+
+    // Noktanın altındaki en derin düğüm; arka planı veya metni olmayan kaplar sayılmaz
+    pub fn hit(&self, point: [f32; 2]) -> Option<NodeId> {
+        fn visit(ui: &Ui, id: NodeId, point: [f32; 2]) -> Option<NodeId> {
+            let node = &ui.nodes[id.0];
+            let [x, y, w, h] = node.rect;
+            if point[0] < x || point[1] < y || point[0] >= x + w || point[1] >= y + h {
+                return None;
+            }
+            let child = node.children.iter().rev().find_map(|child| visit(ui, *child, point));
+            let solid = node.background.is_some() || node.text.is_some();
+            child.or(solid.then_some(id))
+        }
+        self.roots
+            .iter()
+            .rev()
+            .find_map(|(root, _, _)| visit(self, *root, point))
+    }
+
+    // Vurgulanan düğüm değiştiyse true
+    pub fn hover(&mut self, point: Option<[f32; 2]>) -> bool {
+        let hovered = point
+            .and_then(|point| self.hit(point))
+            .filter(|id| self.nodes[id.0].command.is_some());
+        std::mem::replace(&mut self.hovered, hovered) != hovered
+    }
+
+    pub fn draw(&self, sprites: &mut SpriteBatch, overlay: &mut Overlay, assets: &AssetManager) {
+        for (root, _, _) in &self.roots {
+            self.draw_node(*root, sprites, overlay, assets);
+        }
+    }
+
+    fn draw_node(&self, id: NodeId, sprites: &mut SpriteBatch, overlay: &mut Overlay, assets: &AssetManager) {
+        let node = &self.nodes[id.0];
+        let palette = overlay.palette;
+        let highlighted = self.hovered == Some(id) || self.focused == Some(id);
+        let background = node.background.map(|background| match background.texture {
+            TextureHandle::PANEL => NineSlice {
+                texture: palette.panel_texture,
+                ..background
+            },
+            _ => background,
+        });
+        if let Some(background) = &background {
+            let tint = if highlighted && node.slider.is_none() {
+                palette.accent
+            } else {
+                Color::WHITE
+            };
+            sprites.nine_slice(assets, background, node.rect, tint);
+        }
+        if let (Some(slider), Some(background)) = (&node.slider, &background) {
+            let [x, y, w, h] = node.rect;
+            let knob = h.min(w);
+            let tint = if highlighted {
+                palette.accent
+            } else {
+                palette.text_dim
+            };
+            sprites.nine_slice(
+                assets,
+                background,
+                [x + slider.fraction() * (w - knob), y, knob, h],
+                tint,
+            );
+            if let Some((text, tone)) = &node.text {
+                let text = format!("{text}: {:.2}", slider.value);
+                let [char_w, char_h] = overlay.char_size();
+                let text_w = text.chars().count() as f32 * char_w;
+                overlay.text(x + (w - text_w) * 0.5, y + (h - char_h) * 0.5, &text, tone.color(palette));
+            }
+        } else if let Some((text, tone)) = &node.text {
+            let [char_w, char_h] = overlay.char_size();
+            let [x, y, w, h] = node.rect;
+            let text_w = text.chars().count() as f32 * char_w;
+            // Buton metinleri ortalanır, etiketler sola yaslanır
+            let x = if node.command.is_some() {
+                x + (w - text_w) * 0.5
+            } else {
+                x + node.padding
+            };
+            overlay.text(x, y + (h - char_h) * 0.5, text, tone.color(palette));
+        }
+        for child in &node.children {
+            self.draw_node(*child, sprites, overlay, assets);
+        }
+    }
+}
+
+// `ui` komutunun örneği: sol üstte her kare güncellenen bir HUD, ortada
+// butonları konsol komutlarını çalıştıran bir menü
+pub struct DemoUi {
+    pub ui: Ui,
+    menu: NodeId,
+    exposure: NodeId,
+    frame: NodeId,
+    entities: NodeId,
+    selected: NodeId,
+}
+
+impl DemoUi {
+    pub fn new() -> Self {
+        let mut ui = Ui::default();
+
+        // İki sütun: adlar sola, değerler sağa yaslı
+        let hud = ui.add(None, Node::row().with_panel().with_padding(10.0).with_spacing(16.0));
+        ui.anchor(hud, Anchor::TopLeft, [12.0, 12.0]);
+        let names = ui.add(Some(hud), Node::column().with_spacing(2.0));
+        let values = ui.add(Some(hud), Node::column().with_spacing(2.0).with_align(Align::End));
+        let field = |ui: &mut Ui, name: &str| {
+            ui.add(Some(names), Node::label(name, Tone::Dim));
+            ui.add(Some(values), Node::label("-", Tone::Text))
+        };
+        let frame = field(&mut ui, "kare");
+        let entities = field(&mut ui, "nesne");
+        let selected = field(&mut ui, "seçili");
+
+        let menu = ui.add(
+            None,
+            Node::column()
+                .with_panel()
+                .with_padding(14.0)
+                .with_spacing(6.0)
+                .with_align(Align::Stretch)
+                .with_min_size(260.0, 0.0),
+        );
+        ui.anchor(menu, Anchor::Center, [0.0, 0.0]);
+        let title = ui.add(Some(menu), Node::column().with_align(Align::Center).with_padding(4.0));
+        ui.add(Some(title), Node::label("Menü", Tone::Accent));
+        for (text, command) in [
+            ("İstatistikler", "stats"),
+            ("Hata ayıklama çizgileri", "lines"),
+            ("Nokta bulutu", "points demo 500000"),
+            ("Voksel arazisi", "voxels generate"),
+        ] {
+            ui.add(Some(menu), Node::button(text, command));
+        }
+        let exposure = ui.add(
+            Some(menu),
+            Node::slider(
+                "Pozlama",
+                "tonemap exposure",
+                Slider {
+                    value: 1.0,
+                    min: 0.25,
+                    max: 4.0,
+                    step: 0.25,
+                },
+            ),
+        );
+        ui.add(Some(menu), Node::button("Kapat", "ui off"));
+
+        Self {
+            ui,
+            menu,
+            exposure,
+            frame,
+            entities,
+            selected,
+        }
+    }
+
+    pub fn set_menu_anchor(&mut self, anchor: Anchor) {
+        self.ui.set_anchor(self.menu, anchor);
+    }
+
+    // Kaydırıcı konsoldan değiştirilen pozlamayı da yansıtır
+    pub fn update(&mut self, frame_ms: Option<f32>, entities: usize, selected: Option<&str>, exposure: f32) {
+        let frame = frame_ms.map_or("-".into(), |ms| {
+            format!("{ms:.2} ms ({:.0} fps)", 1000.0 / ms.max(0.001))
+        });
+        self.ui.set_text(self.frame, &frame);
+        self.ui.set_text(self.entities, &entities.to_string());
+        self.ui.set_text(self.selected, selected.unwrap_or("-"));
+        if let Some(slider) = self.ui.nodes[self.exposure.0].slider.as_mut()
+            && slider.value != exposure
+        {
+            slider.value = exposure;
+            self.ui.laid_out = None;
+            self.ui.revision += 1;
+        }
+    }
+}
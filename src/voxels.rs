@@ -0,0 +1,587 @@
+use crate::frame_capture::TracedPass;
+use crate::noise::{self, NoiseKind};
+use glam::{IVec3, Vec2, Vec3};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use wgpu::util::DeviceExt;
+
+pub const CHUNK_SIZE: i32 = 32;
+const CHUNK_VOLUME: usize = (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize;
+// Bir bloğun dünya birimindeki kenarı ve blok (0, 0, 0)'ın köşesi. Yüzey
+// yüksekliği (SURFACE) y = -0.5'e, yani küplerin tabanına denk gelir.
+const BLOCK_SIZE: f32 = 0.25;
+const SURFACE: i32 = 24;
+const ORIGIN: Vec3 = Vec3::new(0.0, -0.5 - SURFACE as f32 * BLOCK_SIZE, 0.0);
+// Doku dizisindeki her katmanın boyutu
+const TILE: u32 = 16;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[repr(u8)]
+pub enum Block {
+    #[default]
+    Air,
+    Stone,
+    Dirt,
+    Grass,
+    Sand,
+    Snow,
+}
+
+impl Block {
+    const ALL: [Block; 6] = [Block::Air, Block::Stone, Block::Dirt, Block::Grass, Block::Sand, Block::Snow];
+
+    fn from_u8(value: u8) -> Self {
+        Self::ALL.get(value as usize).copied().unwrap_or_default()
+    }
+
+    pub fn is_solid(self) -> bool {
+        self != Block::Air
+    }
+
+    // Doku dizisi katmanı: üst, yan, alt yüzler
+    fn layers(self) -> [u16; 3] {
+        match self {
+            Block::Air => [0; 3],
+            Block::Stone => [0; 3],
+            Block::Dirt => [1; 3],
+            Block::Grass => [2, 3, 1],
+            Block::Sand => [4; 3],
+            Block::Snow => [5, 5, 1],
+        }
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Block::Air => "air",
+            Block::Stone => "stone",
+            Block::Dirt => "dirt",
+            Block::Grass => "grass",
+            Block::Sand => "sand",
+            Block::Snow => "snow",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Block {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|block| block.to_string() == text)
+            .ok_or_else(|| format!("bilinmeyen blok: {text} (air | stone | dirt | grass | sand | snow)"))
+    }
+}
+
+pub struct Chunk {
+    blocks: Box<[u8; CHUNK_VOLUME]>,
+}
+
+impl Chunk {
+    fn new() -> Self {
+        Self {
+            blocks: Box::new([0; CHUNK_VOLUME]),
+        }
+    }
+
+    fn index(local: IVec3) -> usize {
+        (local.x + CHUNK_SIZE * (local.y + CHUNK_SIZE * local.z)) as usize
+    }
+
+    fn get(&self, local: IVec3) -> Block {
+        Block::from_u8(self.blocks[Self::index(local)])
+    }
+
+    fn set(&mut self, local: IVec3, block: Block) {
+        self.blocks[Self::index(local)] = block as u8;
+    }
+
+    fn is_empty(&self) -> bool {
+        self.blocks.iter().all(|block| *block == 0)
+    }
+}
+
+// Blok koordinatını parça koordinatına ve parça içi konuma ayırır
+fn split(position: IVec3) -> (IVec3, IVec3) {
+    (
+        position.div_euclid(IVec3::splat(CHUNK_SIZE)),
+        position.rem_euclid(IVec3::splat(CHUNK_SIZE)),
+    )
+}
+
+#[derive(Default)]
+pub struct VoxelWorld {
+    chunks: HashMap<IVec3, Chunk>,
+    // Mesh'i yeniden üretilecek parçalar
+    dirty: HashSet<IVec3>,
+}
+
+impl VoxelWorld {
+    pub fn block(&self, position: IVec3) -> Block {
+        let (chunk, local) = split(position);
+        self.chunks.get(&chunk).map_or(Block::Air, |chunk| chunk.get(local))
+    }
+
+    // Parça sınırındaki değişiklik komşu parçanın gizli yüzlerini de etkiler
+    pub fn set_block(&mut self, position: IVec3, block: Block) {
+        let (key, local) = split(position);
+        if !block.is_solid() && !self.chunks.contains_key(&key) {
+            return;
+        }
+        self.chunks.entry(key).or_insert_with(Chunk::new).set(local, block);
+        self.dirty.insert(key);
+        for axis in 0..3 {
+            let mut offset = IVec3::ZERO;
+            if local[axis] == 0 {
+                offset[axis] = -1;
+            } else if local[axis] == CHUNK_SIZE - 1 {
+                offset[axis] = 1;
+            } else {
+                continue;
+            }
+            if self.chunks.contains_key(&(key + offset)) {
+                self.dirty.insert(key + offset);
+            }
+        }
+    }
+
+    // Gürültülü yükseklik haritası; merkez etrafında (2 * radius)² parça sütunu
+    pub fn generate(radius: i32, seed: u32) -> Self {
+        let mut world = Self::default();
+        let span = radius * CHUNK_SIZE;
+        for z in -span..span {
+            for x in -span..span {
+                let p = Vec2::new(x as f32, z as f32) * 0.015;
+                let height = SURFACE + (noise::fbm(NoiseKind::Perlin, p, 4, seed) * 28.0) as i32;
+                for y in 0..=height {
+                    let block = match y {
+                        y if y < height - 3 => Block::Stone,
+                        y if y < height => Block::Dirt,
+                        y if y <= SURFACE - 2 => Block::Sand,
+                        y if y >= SURFACE + 9 => Block::Snow,
+                        _ => Block::Grass,
+                    };
+                    let (key, local) = split(IVec3::new(x, y, z));
+                    world.chunks.entry(key).or_insert_with(Chunk::new).set(local, block);
+                }
+            }
+        }
+        world.chunks.retain(|_, chunk| !chunk.is_empty());
+        world.dirty = world.chunks.keys().copied().collect();
+        world
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct VoxelVertex {
+    position: [f32; 3],
+    normal: [f32; 3],
+    // Blok biriminde; birleştirilmiş yüzlerde doku tekrar eder
+    uv: [f32; 2],
+    layer: u32,
+}
+
+impl VoxelVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] =
+        wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3, 2 => Float32x2, 3 => Uint32];
+
+    fn layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<VoxelVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+// Açgözlü birleştirme: her eksen ve yön için parçayı dilimler, her dilimde
+// görünen yüzleri malzemeye göre maskeler ve aynı katmandaki komşu yüzleri
+// en büyük dikdörtgenlere toplar. Komşu blok başka parçadaysa dünyadan
+// okunur, böylece parça sınırlarındaki gizli yüzler de atlanır.
+fn mesh_chunk(world: &VoxelWorld, key: IVec3) -> (Vec<VoxelVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let Some(chunk) = world.chunks.get(&key) else { return (vertices, indices) };
+    let base = key * CHUNK_SIZE;
+    let size = CHUNK_SIZE as usize;
+    let mut mask = vec![0u16; size * size];
+
+    for axis in 0..3 {
+        let (u, v) = ((axis + 1) % 3, (axis + 2) % 3);
+        for direction in [-1, 1] {
+            let mut step = IVec3::ZERO;
+            step[axis] = direction;
+            let face = match (axis, direction) {
+                (1, 1) => 0,
+                (1, _) => 2,
+                _ => 1,
+            };
+            let mut normal = Vec3::ZERO;
+            normal[axis] = direction as f32;
+
+            for slice in 0..CHUNK_SIZE {
+                for j in 0..CHUNK_SIZE {
+                    for i in 0..CHUNK_SIZE {
+                        let mut local = IVec3::ZERO;
+                        local[axis] = slice;
+                        local[u] = i;
+                        local[v] = j;
+                        let block = chunk.get(local);
+                        let neighbor = local + step;
+                        let hidden = if neighbor.cmpge(IVec3::ZERO).all() && neighbor.cmplt(IVec3::splat(CHUNK_SIZE)).all() {
+                            chunk.get(neighbor).is_solid()
+                        } else {
+                            world.block(base + neighbor).is_solid()
+                        };
+                        // 0 boş; dolu yüzlerde katman + 1
+                        mask[j as usize * size + i as usize] =
+                            if block.is_solid() && !hidden { block.layers()[face] + 1 } else { 0 };
+                    }
+                }
+
+                for j in 0..size {
+                    let mut i = 0;
+                    while i < size {
+                        let layer = mask[j * size + i];
+                        if layer == 0 {
+                            i += 1;
+                            continue;
+                        }
+                        let mut width = 1;
+                        while i + width < size && mask[j * size + i + width] == layer {
+                            width += 1;
+                        }
+                        let mut height = 1;
+                        while j + height < size && mask[(j + height) * size + i..][..width].iter().all(|m| *m == layer) {
+                            height += 1;
+                        }
+                        for row in j..j + height {
+                            mask[row * size + i..][..width].fill(0);
+                        }
+
+                        let mut corner = base.as_vec3();
+                        corner[axis] += (slice + i32::from(direction > 0)) as f32;
+                        corner[u] += i as f32;
+                        corner[v] += j as f32;
+                        let mut du = Vec3::ZERO;
+                        du[u] = width as f32;
+                        let mut dv = Vec3::ZERO;
+                        dv[v] = height as f32;
+                        // e_u x e_v = e_axis; negatif yönde sıra ters çevrilir ki ön yüz dışarı baksın
+                        let mut quad = [corner, corner + du, corner + du + dv, corner + dv];
+                        if direction < 0 {
+                            quad.reverse();
+                        }
+
+                        let first = vertices.len() as u32;
+                        for position in quad {
+                            // Yan yüzlerde v aşağı doğru artar, böylece çimen kenarı üstte kalır
+                            let uv = match axis {
+                                0 => [position.z, -position.y],
+                                1 => [position.x, position.z],
+                                _ => [position.x, -position.y],
+                            };
+                            vertices.push(VoxelVertex {
+                                position: (ORIGIN + position * BLOCK_SIZE).into(),
+                                normal: normal.into(),
+                                uv,
+                                layer: u32::from(layer - 1),
+                            });
+                        }
+                        indices.extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+                        i += width;
+                    }
+                }
+            }
+        }
+    }
+    (vertices, indices)
+}
+
+struct ChunkMesh {
+    vertex_buffer: wgpu::Buffer,
+    index_buffer: wgpu::Buffer,
+    index_count: u32,
+}
+
+// Katman başına 16x16 piksel; gürültü hash'iyle renk oynaması verilir
+fn block_tiles() -> Vec<u8> {
+    let colors: [[u8; 3]; 6] = [
+        [120, 120, 125], // taş
+        [121, 85, 58],   // toprak
+        [86, 156, 58],   // çimen üstü
+        [121, 85, 58],   // çimen yanı (üst şerit yeşil)
+        [219, 206, 150], // kum
+        [235, 240, 245], // kar
+    ];
+    let mut pixels = Vec::new();
+    for (layer, color) in colors.iter().enumerate() {
+        for y in 0..TILE {
+            for x in 0..TILE {
+                let h = noise::hash(x | y << 8 | (layer as u32) << 16);
+                let grass_edge = 3 + (h >> 30);
+                let color = if layer == 3 && y < grass_edge { colors[2] } else { *color };
+                let shade = 0.85 + 0.15 * (h & 0xff) as f32 / 255.0;
+                pixels.extend(color.map(|c| (c as f32 * shade) as u8));
+                pixels.push(255);
+            }
+        }
+    }
+    pixels
+}
+
+pub struct VoxelStats {
+    pub chunks: usize,
+    pub remeshed: usize,
+    pub quads: usize,
+    pub mesh_time: Duration,
+}
+
+// Parçalı voksel dünyası ve çizimi. Sahne pass'inde, sahnenin kamera/ışık
+// bind group'uyla çizilir; bloklar doku dizisinden yüzlerine göre katman seçer.
+pub struct Voxels {
+    pub world: VoxelWorld,
+    meshes: HashMap<IVec3, ChunkMesh>,
+    pipeline: wgpu::RenderPipeline,
+    pipeline_layout: wgpu::PipelineLayout,
+    texture_bind_group: wgpu::BindGroup,
+    format: wgpu::TextureFormat,
+    pub stats: VoxelStats,
+}
+
+impl Voxels {
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        camera_layout: &wgpu::BindGroupLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<Self, String> {
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Voxel Texture Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2Array,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let pixels = block_tiles();
+        let layers = pixels.len() as u32 / (TILE * TILE * 4);
+        let texture = device.create_texture_with_data(
+            queue,
+            &wgpu::TextureDescriptor {
+                label: Some("Voxel Block Textures"),
+                size: wgpu::Extent3d {
+                    width: TILE,
+                    height: TILE,
+                    depth_or_array_layers: layers,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &pixels,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+        // Birleştirilmiş yüzlerde blok dokusu tekrar eder; pikselli görünüm için en yakın örnekleme
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Voxel Sampler"),
+            address_mode_u: wgpu::AddressMode::Repeat,
+            address_mode_v: wgpu::AddressMode::Repeat,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+        let texture_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Voxel Texture Bind Group"),
+            layout: &texture_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Voxel Pipeline Layout"),
+            bind_group_layouts: &[camera_layout, &texture_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = Self::create_pipeline(device, &pipeline_layout, format)?;
+
+        Ok(Self {
+            world: VoxelWorld::default(),
+            meshes: HashMap::new(),
+            pipeline,
+            pipeline_layout,
+            texture_bind_group,
+            format,
+            stats: VoxelStats {
+                chunks: 0,
+                remeshed: 0,
+                quads: 0,
+                mesh_time: Duration::ZERO,
+            },
+        })
+    }
+
+    fn create_pipeline(
+        device: &wgpu::Device,
+        layout: &wgpu::PipelineLayout,
+        format: wgpu::TextureFormat,
+    ) -> Result<wgpu::RenderPipeline, String> {
+        let shader = crate::shaders::create_module(device, "voxel.wgsl")?;
+        crate::shaders::validated(device, || {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Voxel Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[VoxelVertex::layout()],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    cull_mode: Some(wgpu::Face::Back),
+                    ..Default::default()
+                },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: crate::renderer::DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        })
+    }
+
+    pub fn reload_shaders(&mut self, device: &wgpu::Device) -> Result<(), String> {
+        self.pipeline = Self::create_pipeline(device, &self.pipeline_layout, self.format)?;
+        Ok(())
+    }
+
+    pub fn generate(&mut self, radius: i32, seed: u32) {
+        self.world = VoxelWorld::generate(radius, seed);
+        self.meshes.clear();
+    }
+
+    pub fn clear(&mut self) {
+        self.world = VoxelWorld::default();
+        self.meshes.clear();
+    }
+
+    // Değişen parçaların mesh'lerini yeniden üretir; boş parçaların mesh'i silinir
+    pub fn prepare(&mut self, device: &wgpu::Device) {
+        if self.world.dirty.is_empty() {
+            return;
+        }
+        let start = Instant::now();
+        let dirty: Vec<IVec3> = self.world.dirty.drain().collect();
+        for key in &dirty {
+            let (vertices, indices) = mesh_chunk(&self.world, *key);
+            if indices.is_empty() {
+                self.meshes.remove(key);
+                continue;
+            }
+            let label = format!("Voxel Chunk {key}");
+            let mesh = ChunkMesh {
+                vertex_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(&label),
+                    contents: bytemuck::cast_slice(&vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                }),
+                index_buffer: device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(&label),
+                    contents: bytemuck::cast_slice(&indices),
+                    usage: wgpu::BufferUsages::INDEX,
+                }),
+                index_count: indices.len() as u32,
+            };
+            self.meshes.insert(*key, mesh);
+        }
+        self.stats = VoxelStats {
+            chunks: self.world.chunk_count(),
+            remeshed: dirty.len(),
+            quads: self.meshes.values().map(|mesh| mesh.index_count as usize / 6).sum(),
+            mesh_time: start.elapsed(),
+        };
+    }
+
+    pub fn render(&self, render_pass: &mut TracedPass<'_, '_>, camera_bind_group: &wgpu::BindGroup) {
+        if self.meshes.is_empty() {
+            return;
+        }
+        render_pass.set_pipeline(&self.pipeline, "Voxel Pipeline");
+        render_pass.set_bind_group(0, camera_bind_group, "Camera Bind Group");
+        render_pass.set_bind_group(1, &self.texture_bind_group, "Voxel Texture Bind Group");
+        for mesh in self.meshes.values() {
+            render_pass.set_vertex_buffer(0, &mesh.vertex_buffer, "Voxel Chunk");
+            render_pass.set_index_buffer(&mesh.index_buffer, wgpu::IndexFormat::Uint32, "Voxel Chunk");
+            render_pass.draw_indexed(0..mesh.index_count, 0, 0..1);
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        let stats = &self.stats;
+        format!(
+            "{} parça, {} dörtgen; son güncelleme {} parça, {:.1} ms",
+            stats.chunks,
+            stats.quads,
+            stats.remeshed,
+            stats.mesh_time.as_secs_f64() * 1000.0
+        )
+    }
+
+    // Dünya konumundaki bloğun koordinatı
+    pub fn block_at(point: Vec3) -> IVec3 {
+        ((point - ORIGIN) / BLOCK_SIZE).floor().as_ivec3()
+    }
+}